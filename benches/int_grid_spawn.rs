@@ -0,0 +1,111 @@
+//! Benchmarks spawning a level with a single large IntGrid layer, where most of the spawn time is
+//! spent resolving [LdtkIntCell] registrations for every cell.
+use bevy::prelude::*;
+use bevy_ecs_ldtk::{
+    ldtk::{IntGridValueDefinition, LayerDefinition, LdtkJson, Level, Type},
+    prelude::*,
+    test_utils::{headless_app, spawn_project_from_json},
+};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+const LAYER_SIZE: i32 = 256;
+
+const MAX_UPDATES: usize = 10;
+
+#[derive(Component, Default)]
+struct Wall;
+
+#[derive(Bundle, LdtkIntCell, Default)]
+struct WallBundle {
+    wall: Wall,
+}
+
+#[derive(Component, Default)]
+struct Water;
+
+#[derive(Bundle, LdtkIntCell, Default)]
+struct WaterBundle {
+    water: Water,
+}
+
+fn int_grid_project() -> LdtkJson {
+    let layer_definition = LayerDefinition {
+        uid: 1,
+        identifier: "Collision".to_string(),
+        purple_type: Type::IntGrid,
+        grid_size: 16,
+        int_grid_values: (1..=4)
+            .map(|value| IntGridValueDefinition { value, ..default() })
+            .collect(),
+        ..default()
+    };
+
+    let layer_instance = LayerInstance {
+        identifier: "Collision".to_string(),
+        layer_instance_type: Type::IntGrid,
+        layer_def_uid: 1,
+        c_wid: LAYER_SIZE,
+        c_hei: LAYER_SIZE,
+        grid_size: 16,
+        opacity: 1.,
+        visible: true,
+        int_grid_csv: (0..LAYER_SIZE * LAYER_SIZE).map(|i| i % 5).collect(),
+        ..default()
+    };
+
+    let mut ldtk_json = LdtkJson {
+        levels: vec![Level {
+            iid: "level".to_string(),
+            px_wid: LAYER_SIZE * 16,
+            px_hei: LAYER_SIZE * 16,
+            layer_instances: Some(vec![layer_instance]),
+            ..default()
+        }],
+        ..default()
+    };
+    ldtk_json.defs.layers.push(layer_definition);
+
+    ldtk_json
+}
+
+fn app_with_project(ldtk_json: &LdtkJson) -> App {
+    let mut app = headless_app("assets");
+    app.register_ldtk_int_cell::<WallBundle>(1)
+        .register_ldtk_int_cell_for_layer::<WaterBundle>("Collision", 2)
+        .insert_resource(LevelSelection::index(0));
+
+    spawn_project_from_json(&mut app, ldtk_json);
+
+    app
+}
+
+fn spawn_int_grid_level(c: &mut Criterion) {
+    let ldtk_json = int_grid_project();
+
+    c.bench_function("spawn 256x256 IntGrid layer", |b| {
+        b.iter_batched(
+            || app_with_project(&ldtk_json),
+            |mut app| {
+                for _ in 0..MAX_UPDATES {
+                    app.update();
+
+                    if app
+                        .world_mut()
+                        .query::<&IntGridCell>()
+                        .iter(app.world())
+                        .next()
+                        .is_some()
+                    {
+                        return app;
+                    }
+                }
+
+                panic!("level should spawn within {MAX_UPDATES} updates");
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+criterion_group!(benches, spawn_int_grid_level);
+criterion_main!(benches);
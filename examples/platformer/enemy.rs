@@ -28,8 +28,9 @@ pub struct Patrol {
 impl LdtkEntity for Patrol {
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        _: &EntityDefinition,
         layer_instance: &LayerInstance,
-        _: Option<&Handle<Image>>,
+        _: Option<&TilesetInfo>,
         _: Option<&TilesetDefinition>,
         _: &AssetServer,
         _: &mut Assets<TextureAtlasLayout>,
@@ -1,11 +1,16 @@
 use quote::quote;
 
 static SPRITE_BUNDLE_ATTRIBUTE_NAME: &str = "sprite_bundle";
+static USE_SMART_COLOR_ARGUMENT_NAME: &str = "use_smart_color";
 static SPRITE_SHEET_BUNDLE_ATTRIBUTE_NAME: &str = "sprite_sheet_bundle";
+static SPRITE_SHEET_ANIMATION_ATTRIBUTE_NAME: &str = "sprite_sheet_animation";
+static TRANSFORM_FROM_FIELDS_ATTRIBUTE_NAME: &str = "transform_from_fields";
 static WORLDLY_ATTRIBUTE_NAME: &str = "worldly";
+static Y_SORT_ATTRIBUTE_NAME: &str = "y_sort";
 static GRID_COORDS_ATTRIBUTE_NAME: &str = "grid_coords";
 static LDTK_ENTITY_ATTRIBUTE_NAME: &str = "ldtk_entity";
 static FROM_ENTITY_INSTANCE_ATTRIBUTE_NAME: &str = "from_entity_instance";
+static FROM_ENTITY_DEFINITION_ATTRIBUTE_NAME: &str = "from_entity_definition";
 static WITH_ATTRIBUTE_NAME: &str = "with";
 static DEFAULT_ATTRIBUTE_NAME: &str = "default";
 
@@ -21,6 +26,9 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
     };
 
     let mut field_constructions = Vec::new();
+    // Fields that read the entity instance prevent pooling, see LdtkEntity::POOLABLE
+    let mut uses_instance_data = false;
+    let mut nested_ldtk_entity_types = Vec::new();
     for field in fields {
         let field_name = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
@@ -33,6 +41,7 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             field_constructions.push(expand_sprite_bundle_attribute(
                 attribute, field_name, field_type,
             ));
+            uses_instance_data |= !sprite_bundle_has_asset_path(attribute);
             continue;
         }
 
@@ -44,6 +53,29 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             field_constructions.push(expand_sprite_sheet_bundle_attribute(
                 attribute, field_name, field_type,
             ));
+            uses_instance_data |= !sprite_sheet_bundle_has_asset_path(attribute);
+            continue;
+        }
+
+        let sprite_sheet_animation = field.attrs.iter().find(|a| {
+            *a.path.get_ident().as_ref().unwrap() == SPRITE_SHEET_ANIMATION_ATTRIBUTE_NAME
+        });
+        if let Some(attribute) = sprite_sheet_animation {
+            field_constructions.push(expand_sprite_sheet_animation_attribute(
+                attribute, field_name, field_type,
+            ));
+            uses_instance_data = true;
+            continue;
+        }
+
+        let transform_from_fields = field.attrs.iter().find(|a| {
+            *a.path.get_ident().as_ref().unwrap() == TRANSFORM_FROM_FIELDS_ATTRIBUTE_NAME
+        });
+        if let Some(attribute) = transform_from_fields {
+            field_constructions.push(expand_transform_from_fields_attribute(
+                attribute, field_name, field_type,
+            ));
+            uses_instance_data = true;
             continue;
         }
 
@@ -53,6 +85,16 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             .find(|a| *a.path.get_ident().as_ref().unwrap() == WORLDLY_ATTRIBUTE_NAME);
         if let Some(attribute) = worldly {
             field_constructions.push(expand_worldly_attribute(attribute, field_name, field_type));
+            uses_instance_data = true;
+            continue;
+        }
+
+        let y_sort = field
+            .attrs
+            .iter()
+            .find(|a| *a.path.get_ident().as_ref().unwrap() == Y_SORT_ATTRIBUTE_NAME);
+        if let Some(attribute) = y_sort {
+            field_constructions.push(expand_y_sort_attribute(attribute, field_name, field_type));
             continue;
         }
 
@@ -64,6 +106,7 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             field_constructions.push(expand_grid_coords_attribute(
                 attribute, field_name, field_type,
             ));
+            uses_instance_data = true;
             continue;
         }
 
@@ -75,6 +118,7 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             field_constructions.push(expand_ldtk_entity_attribute(
                 attribute, field_name, field_type,
             ));
+            nested_ldtk_entity_types.push(field_type);
             continue;
         }
 
@@ -86,6 +130,17 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             field_constructions.push(expand_from_entity_instance_attribute(
                 attribute, field_name, field_type,
             ));
+            uses_instance_data = true;
+            continue;
+        }
+
+        let from_entity_definition = field.attrs.iter().find(|a| {
+            *a.path.get_ident().as_ref().unwrap() == FROM_ENTITY_DEFINITION_ATTRIBUTE_NAME
+        });
+        if let Some(attribute) = from_entity_definition {
+            field_constructions.push(expand_from_entity_definition_attribute(
+                attribute, field_name, field_type,
+            ));
             continue;
         }
 
@@ -95,6 +150,7 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
             .find(|a| *a.path.get_ident().as_ref().unwrap() == WITH_ATTRIBUTE_NAME);
         if let Some(attribute) = with {
             field_constructions.push(expand_with_attribute(attribute, field_name, field_type));
+            uses_instance_data = true;
             continue;
         }
 
@@ -117,16 +173,28 @@ pub fn expand_ldtk_entity_derive(ast: syn::DeriveInput) -> proc_macro::TokenStre
         quote! {}
     };
 
+    let poolable = if uses_instance_data {
+        quote! { false }
+    } else {
+        quote! { true #(&& <#nested_ldtk_entity_types as bevy_ecs_ldtk::prelude::LdtkEntity>::POOLABLE)* }
+    };
+
     let gen = quote! {
         impl #impl_generics bevy_ecs_ldtk::prelude::LdtkEntity for #struct_name #ty_generics #where_clause {
+            const POOLABLE: bool = #poolable;
+
             fn bundle_entity(
                 entity_instance: &bevy_ecs_ldtk::prelude::EntityInstance,
+                entity_definition: &bevy_ecs_ldtk::prelude::EntityDefinition,
                 layer_instance: &bevy_ecs_ldtk::prelude::LayerInstance,
-                tileset: Option<&bevy::prelude::Handle<bevy::prelude::Image>>,
+                tileset_info: Option<&bevy_ecs_ldtk::prelude::TilesetInfo>,
                 tileset_definition: Option<&bevy_ecs_ldtk::prelude::TilesetDefinition>,
                 asset_server: &bevy::prelude::AssetServer,
                 texture_atlases: &mut bevy::prelude::Assets<bevy::prelude::TextureAtlasLayout>,
             ) -> Self {
+                #[allow(unused_variables)]
+                let tileset = tileset_info.and_then(|tileset_info| tileset_info.image.as_ref());
+
                 Self {
                     #(#field_constructions)*
                     #struct_update
@@ -158,19 +226,46 @@ fn expand_sprite_bundle_attribute(
         .parse_meta()
         .expect("Cannot parse #[sprite_bundle...] attribute")
     {
-        syn::Meta::List(syn::MetaList { nested, .. }) if nested.len() == 1 => {
-            match nested.first().unwrap() {
-                syn::NestedMeta::Lit(syn::Lit::Str(asset)) => {
-                    let asset_path = &asset.value();
+        syn::Meta::List(syn::MetaList { nested, .. }) if nested.len() == 1 || nested.len() == 2 => {
+            let mut asset_path = None;
+            let mut use_smart_color = false;
 
-                    quote! {
-                        #field_name: bevy::prelude::SpriteBundle {
-                            texture: asset_server.load(#asset_path),
-                            ..Default::default()
-                        },
-                    }
+            for nested_meta in nested.iter() {
+                match nested_meta {
+                    syn::NestedMeta::Lit(syn::Lit::Str(asset)) if asset_path.is_none() => {
+                        asset_path = Some(asset.value());
+                    },
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(USE_SMART_COLOR_ARGUMENT_NAME) && !use_smart_color => {
+                        use_smart_color = true;
+                    },
+                    _ => panic!("Expected asset path and/or use_smart_color as the arguments of #[sprite_bundle(...)]"),
+                }
+            }
+
+            match (asset_path, use_smart_color) {
+                (Some(asset_path), false) => quote! {
+                    #field_name: bevy::prelude::SpriteBundle {
+                        texture: bevy_ecs_ldtk::utils::load_entity_image(asset_server, #asset_path),
+                        ..Default::default()
+                    },
+                },
+                (Some(asset_path), true) => quote! {
+                    #field_name: bevy_ecs_ldtk::utils::smart_color_sprite_bundle_from_entity_info(
+                        entity_instance,
+                        Some(&bevy_ecs_ldtk::utils::load_entity_image(asset_server, #asset_path)),
+                    ),
                 },
-                _ => panic!("Expected asset path as the only argument of #[sprite_bundle(...)]"),
+                (None, true) => quote! {
+                    #field_name: bevy_ecs_ldtk::utils::smart_color_sprite_bundle_from_entity_info(
+                        entity_instance,
+                        tileset,
+                    ),
+                },
+                (None, false) => syn::Error::new_spanned(
+                    attribute,
+                    "Expected asset path and/or use_smart_color as the arguments of #[sprite_bundle(...)]",
+                )
+                .to_compile_error(),
             }
         },
         syn::Meta::Path(_) => {
@@ -178,7 +273,18 @@ fn expand_sprite_bundle_attribute(
                 #field_name: bevy_ecs_ldtk::utils::sprite_bundle_from_entity_info(tileset),
             }
         },
-        _ => panic!("#[sprite_bundle...] attribute should take the form #[sprite_bundle(\"asset/path.png\")] or #[sprite_bundle]"),
+        _ => panic!("#[sprite_bundle...] attribute should take the form #[sprite_bundle(\"asset/path.png\")], #[sprite_bundle(use_smart_color)], or #[sprite_bundle]"),
+    }
+}
+
+/// Whether a #[sprite_bundle...] attribute loads its own asset, rather than using the tileset or
+/// color of the entity instance.
+fn sprite_bundle_has_asset_path(attribute: &syn::Attribute) -> bool {
+    match attribute.parse_meta() {
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) => nested
+            .iter()
+            .all(|nested_meta| matches!(nested_meta, syn::NestedMeta::Lit(syn::Lit::Str(_)))),
+        _ => false,
     }
 }
 
@@ -240,21 +346,17 @@ fn expand_sprite_sheet_bundle_attribute(
             };
 
             quote! {
-                #field_name: LdtkSpriteSheetBundle{
-                        sprite_bundle: bevy::prelude::SpriteBundle {
-                        texture: asset_server.load(#asset_path).into(),
-                        ..Default::default()
-                    },
-                    texture_atlas: bevy::prelude::TextureAtlas {
-                        layout: texture_atlases.add(
-                            bevy::prelude::TextureAtlasLayout::from_grid(
-                                bevy::prelude::UVec2::new(#tile_width, #tile_height),
-                                #columns, #rows, Some(bevy::prelude::UVec2::splat(#padding)),
-                                Some(bevy::prelude::UVec2::splat(#offset)),
-                            )),
-                        index: #index
-                    }
-                },
+                #field_name: bevy_ecs_ldtk::utils::grid_sprite_sheet_bundle(
+                    asset_server,
+                    texture_atlases,
+                    #asset_path,
+                    bevy::prelude::UVec2::new(#tile_width, #tile_height),
+                    #columns,
+                    #rows,
+                    #padding,
+                    #offset,
+                    #index,
+                ),
             }
         },
         syn::Meta::List(syn::MetaList { nested, .. }) if nested.len() == 1 => {
@@ -278,6 +380,91 @@ fn expand_sprite_sheet_bundle_attribute(
     }
 }
 
+/// Whether a #[sprite_sheet_bundle...] attribute loads its own asset, rather than using the tile
+/// of the entity instance.
+fn sprite_sheet_bundle_has_asset_path(attribute: &syn::Attribute) -> bool {
+    matches!(
+        attribute.parse_meta(),
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) if nested.len() == 8
+    )
+}
+
+fn expand_sprite_sheet_animation_attribute(
+    attribute: &syn::Attribute,
+    field_name: &syn::Ident,
+    _: &syn::Type,
+) -> proc_macro2::TokenStream {
+    let mut frames_field = "frames".to_string();
+    let mut fps_field = "fps".to_string();
+
+    match attribute
+        .parse_meta()
+        .expect("Cannot parse #[sprite_sheet_animation...] attribute")
+    {
+        syn::Meta::Path(_) => (),
+        syn::Meta::List(syn::MetaList { nested, .. }) => {
+            for nested_meta in nested.iter() {
+                match nested_meta {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(value),
+                        ..
+                    })) if path.is_ident("frames_field") => frames_field = value.value(),
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(value),
+                        ..
+                    })) if path.is_ident("fps_field") => fps_field = value.value(),
+                    _ => panic!("Expected frames_field = \"...\" and/or fps_field = \"...\" as the arguments of #[sprite_sheet_animation(...)]"),
+                }
+            }
+        }
+        _ => panic!("#[sprite_sheet_animation...] attribute should take the form #[sprite_sheet_animation(frames_field = \"frames\", fps_field = \"fps\")] or #[sprite_sheet_animation]"),
+    }
+
+    quote! {
+        #field_name: bevy_ecs_ldtk::prelude::SpriteSheetAnimation::from_entity_fields(entity_instance, #frames_field, #fps_field),
+    }
+}
+
+fn expand_transform_from_fields_attribute(
+    attribute: &syn::Attribute,
+    field_name: &syn::Ident,
+    _: &syn::Type,
+) -> proc_macro2::TokenStream {
+    let mut rotation_field = "rotation".to_string();
+    let mut scale_field = "scale".to_string();
+
+    match attribute
+        .parse_meta()
+        .expect("Cannot parse #[transform_from_fields...] attribute")
+    {
+        syn::Meta::Path(_) => (),
+        syn::Meta::List(syn::MetaList { nested, .. }) => {
+            for nested_meta in nested.iter() {
+                match nested_meta {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(value),
+                        ..
+                    })) if path.is_ident("rotation") => rotation_field = value.value(),
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: syn::Lit::Str(value),
+                        ..
+                    })) if path.is_ident("scale") => scale_field = value.value(),
+                    _ => panic!("Expected rotation = \"...\" and/or scale = \"...\" as the arguments of #[transform_from_fields(...)]"),
+                }
+            }
+        }
+        _ => panic!("#[transform_from_fields...] attribute should take the form #[transform_from_fields(rotation = \"rotation\", scale = \"scale\")] or #[transform_from_fields]"),
+    }
+
+    quote! {
+        #field_name: bevy_ecs_ldtk::prelude::TransformFromFields::from_entity_fields(entity_instance, #rotation_field, #scale_field),
+    }
+}
+
 fn expand_worldly_attribute(
     attribute: &syn::Attribute,
     field_name: &syn::Ident,
@@ -296,6 +483,24 @@ fn expand_worldly_attribute(
     }
 }
 
+fn expand_y_sort_attribute(
+    attribute: &syn::Attribute,
+    field_name: &syn::Ident,
+    _: &syn::Type,
+) -> proc_macro2::TokenStream {
+    match attribute
+        .parse_meta()
+        .expect("Cannot parse #[y_sort] attribute")
+    {
+        syn::Meta::Path(_) => {
+            quote! {
+                #field_name: bevy_ecs_ldtk::prelude::YSort::default(),
+            }
+        }
+        _ => panic!("#[y_sort] attribute should take the form #[y_sort]"),
+    }
+}
+
 fn expand_grid_coords_attribute(
     attribute: &syn::Attribute,
     field_name: &syn::Ident,
@@ -325,7 +530,7 @@ fn expand_ldtk_entity_attribute(
     {
         syn::Meta::Path(_) => {
             quote! {
-                #field_name: <#field_type as bevy_ecs_ldtk::prelude::LdtkEntity>::bundle_entity(entity_instance, layer_instance, tileset, tileset_definition, asset_server, texture_atlases),
+                #field_name: <#field_type as bevy_ecs_ldtk::prelude::LdtkEntity>::bundle_entity(entity_instance, entity_definition, layer_instance, tileset_info, tileset_definition, asset_server, texture_atlases),
             }
         }
         _ => panic!("#[ldtk_entity] attribute should take the form #[ldtk_entity]"),
@@ -352,6 +557,26 @@ fn expand_from_entity_instance_attribute(
     }
 }
 
+fn expand_from_entity_definition_attribute(
+    attribute: &syn::Attribute,
+    field_name: &syn::Ident,
+    field_type: &syn::Type,
+) -> proc_macro2::TokenStream {
+    match attribute
+        .parse_meta()
+        .expect("Cannot parse #[from_entity_definition] attribute")
+    {
+        syn::Meta::Path(_) => {
+            quote! {
+                #field_name: <#field_type as From<&bevy_ecs_ldtk::prelude::EntityDefinition>>::from(entity_definition),
+            }
+        }
+        _ => {
+            panic!("#[from_entity_definition] attribute should take the form #[from_entity_definition]")
+        }
+    }
+}
+
 fn expand_with_attribute(
     attribute: &syn::Attribute,
     field_name: &syn::Ident,
@@ -382,15 +607,20 @@ fn expand_default_attribute(
     field_name: &syn::Ident,
     _: &syn::Type,
 ) -> proc_macro2::TokenStream {
-    match attribute
-        .parse_meta()
-        .expect("Cannot parse #[default] attribute")
-    {
-        syn::Meta::Path(_) => {
+    if attribute.tokens.is_empty() {
+        return quote! {
+            #field_name: Default::default(),
+        };
+    }
+
+    match attribute.parse_args::<syn::Expr>() {
+        Ok(expr) => {
             quote! {
-                #field_name: Default::default(),
+                #field_name: #expr,
             }
         }
-        _ => panic!("#[default] attribute should take the form #[default]"),
+        Err(_) => panic!(
+            "#[default...] attribute should take the form #[default] or #[default(expression)]"
+        ),
     }
 }
@@ -0,0 +1,149 @@
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use std::path::PathBuf;
+use syn::{parse::Parser, punctuated::Punctuated, LitStr, Token};
+
+pub fn expand_ldtk_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = Punctuated::<LitStr, Token![,]>::parse_terminated
+        .parse(input)
+        .expect("ldtk_enum! should take the form ldtk_enum!(\"path/to/project.ldtk\", \"EnumIdentifier\")");
+
+    if args.len() != 2 {
+        panic!("ldtk_enum! should take the form ldtk_enum!(\"path/to/project.ldtk\", \"EnumIdentifier\")");
+    }
+
+    let project_path = args[0].value();
+    let enum_identifier = args[1].value();
+
+    // Paths are relative to the crate using the macro, like include_str!
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("ldtk_enum! requires CARGO_MANIFEST_DIR to be set");
+    let full_path = PathBuf::from(manifest_dir).join(&project_path);
+
+    let project = std::fs::read_to_string(&full_path)
+        .unwrap_or_else(|e| panic!("unable to read LDtk project {project_path}: {e}"));
+    let project: serde_json::Value = serde_json::from_str(&project)
+        .unwrap_or_else(|e| panic!("unable to parse LDtk project {project_path}: {e}"));
+
+    let defs = &project["defs"];
+    let enum_definition = ["enums", "externalEnums"]
+        .iter()
+        .filter_map(|key| defs[key].as_array())
+        .flatten()
+        .find(|enum_definition| enum_definition["identifier"] == enum_identifier.as_str())
+        .unwrap_or_else(|| {
+            panic!("no enum definition with identifier {enum_identifier} in {project_path}")
+        });
+
+    let value_ids: Vec<String> = enum_definition["values"]
+        .as_array()
+        .unwrap_or_else(|| panic!("enum definition {enum_identifier} has no values array"))
+        .iter()
+        .map(|value| {
+            value["id"]
+                .as_str()
+                .unwrap_or_else(|| {
+                    panic!("enum definition {enum_identifier} has a value without an id")
+                })
+                .to_string()
+        })
+        .collect();
+
+    let enum_name = format_ident!("{}", enum_identifier);
+    let variants: Vec<_> = value_ids.iter().map(|id| variant_ident(id)).collect();
+
+    for (i, variant) in variants.iter().enumerate() {
+        if let Some(duplicate) = variants[..i].iter().position(|other| other == variant) {
+            return syn::Error::new(
+                args[1].span(),
+                format!(
+                    "values {} and {} of enum {enum_identifier} both become the variant {variant}",
+                    value_ids[duplicate], value_ids[i]
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+    let full_path = full_path.to_string_lossy().to_string();
+
+    let gen = quote! {
+        #[doc = concat!(" Rust counterpart of the `", #enum_identifier, "` enum in `", #project_path, "`.")]
+        ///
+        /// Generated by `ldtk_enum!`.
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            /// All values of this enum, in the order they're defined in LDtk.
+            pub const ALL: &'static [#enum_name] = &[#(#enum_name::#variants,)*];
+
+            /// The identifier of this enum value in LDtk.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(#enum_name::#variants => #value_ids,)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for #enum_name {
+            type Err = bevy_ecs_ldtk::ldtk::ParseLdtkEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#value_ids => Ok(#enum_name::#variants),)*
+                    _ => Err(bevy_ecs_ldtk::ldtk::ParseLdtkEnumError {
+                        enum_identifier: #enum_identifier,
+                        value: s.to_string(),
+                    }),
+                }
+            }
+        }
+
+        impl From<#enum_name> for &'static str {
+            fn from(value: #enum_name) -> Self {
+                value.as_str()
+            }
+        }
+
+        impl std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        // Recompile when the project changes
+        const _: &[u8] = include_bytes!(#full_path);
+    };
+    gen.into()
+}
+
+/// Creates the variant for an LDtk enum value, which may not be a valid Rust identifier.
+fn variant_ident(id: &str) -> syn::Ident {
+    let mut name: String = id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) || name == "_" {
+        name.insert(0, '_');
+    }
+
+    if syn::parse_str::<syn::Ident>(&name).is_ok() {
+        syn::Ident::new(&name, Span::call_site())
+    } else if matches!(name.as_str(), "self" | "Self" | "super" | "crate") {
+        // These keywords can't be raw identifiers
+        syn::Ident::new(&format!("{name}_"), Span::call_site())
+    } else {
+        syn::Ident::new_raw(&name, Span::call_site())
+    }
+}
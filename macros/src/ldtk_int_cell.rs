@@ -116,7 +116,7 @@ fn expand_from_int_grid_cell_attribute(
     {
         syn::Meta::Path(_) => {
             quote! {
-                #field_name: <#field_type as From<bevy_ecs_ldtk::prelude::IntGridCell>>::from(int_grid_cell),
+                #field_name: <#field_type as From<&bevy_ecs_ldtk::prelude::IntGridCell>>::from(&int_grid_cell),
             }
         }
         _ => {
@@ -155,15 +155,20 @@ fn expand_default_attribute(
     field_name: &syn::Ident,
     _: &syn::Type,
 ) -> proc_macro2::TokenStream {
-    match attribute
-        .parse_meta()
-        .expect("Cannot parse #[default] attribute")
-    {
-        syn::Meta::Path(_) => {
+    if attribute.tokens.is_empty() {
+        return quote! {
+            #field_name: Default::default(),
+        };
+    }
+
+    match attribute.parse_args::<syn::Expr>() {
+        Ok(expr) => {
             quote! {
-                #field_name: Default::default(),
+                #field_name: #expr,
             }
         }
-        _ => panic!("#[default] attribute should take the form #[default]"),
+        Err(_) => panic!(
+            "#[default...] attribute should take the form #[default] or #[default(expression)]"
+        ),
     }
 }
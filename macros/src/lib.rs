@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 
 mod ldtk_entity;
+mod ldtk_enum;
 mod ldtk_int_cell;
 
 #[proc_macro_derive(
@@ -8,10 +9,14 @@ mod ldtk_int_cell;
     attributes(
         sprite_bundle,
         sprite_sheet_bundle,
+        sprite_sheet_animation,
+        transform_from_fields,
         worldly,
+        y_sort,
         grid_coords,
         ldtk_entity,
         from_entity_instance,
+        from_entity_definition,
         with,
         default,
     )
@@ -31,3 +36,39 @@ pub fn ldtk_int_cell_derive(input: TokenStream) -> TokenStream {
 
     ldtk_int_cell::expand_ldtk_int_cell_derive(ast)
 }
+
+/// Generates a Rust enum matching an enum definition in an LDtk project.
+///
+/// This is done at compile time, so enum fields can be matched exhaustively instead of comparing
+/// strings.
+/// Takes the path to the project, relative to the crate's `Cargo.toml`, and the identifier of the
+/// enum definition.
+/// The crate is recompiled whenever the project changes.
+///
+/// The generated enum implements `FromStr`, `Display`, and `Into<&'static str>`, and has an `ALL`
+/// constant listing its values in LDtk order.
+/// Each LDtk value becomes a variant of the same name.
+/// Characters that aren't valid in Rust identifiers are replaced with `_`, values starting with a
+/// digit are prefixed with `_`, and values that are Rust keywords become raw identifiers like
+/// `r#type`.
+/// ```ignore
+/// use bevy_ecs_ldtk::prelude::*;
+///
+/// bevy_ecs_ldtk::ldtk_enum!("assets/Typical_2D_platformer_example.ldtk", "Item");
+///
+/// fn has_food(entity_instance: &EntityInstance) -> bool {
+///     entity_instance
+///         .iter_enums_field("items")
+///         .into_iter()
+///         .flatten()
+///         .any(|item| matches!(item.parse(), Ok(Item::Meat | Item::Healing_Plant)))
+/// }
+///
+/// assert_eq!(Item::Gem.as_str(), "Gem");
+/// ```
+///
+/// Requires the `derive` feature of `bevy_ecs_ldtk` to be enabled.
+#[proc_macro]
+pub fn ldtk_enum(input: TokenStream) -> TokenStream {
+    ldtk_enum::expand_ldtk_enum(input)
+}
@@ -1,6 +1,10 @@
 //! Provides [LdtkEntityAppExt] for registering bundles to spawn for given LDtk Entity identifiers.
-use crate::app::ldtk_entity::*;
+use crate::{
+    app::{ldtk_entity::*, ldtk_entity_hook::LdtkEntityHooks},
+    ldtk::EntityInstance,
+};
 use bevy::prelude::*;
+use std::sync::Arc;
 
 /// [Bundle]: bevy::prelude::Bundle
 /// [App]: bevy::prelude::App
@@ -99,6 +103,137 @@ pub trait LdtkEntityAppExt {
     fn register_default_ldtk_entity<B: LdtkEntity + Bundle>(&mut self) -> &mut Self {
         self.register_ldtk_entity_for_layer_optional::<B>(None, None)
     }
+
+    /// Used internally by the pooled LDtk entity registration functions.
+    ///
+    /// Like [LdtkEntityAppExt::register_ldtk_entity_for_layer_optional], but the registration is
+    /// pooled, see [LdtkEntityAppExt::register_pooled_ldtk_entity].
+    fn register_pooled_ldtk_entity_for_layer_optional<B: LdtkEntity + Bundle + Clone>(
+        &mut self,
+        layer_identifier: Option<String>,
+        entity_identifier: Option<String>,
+    ) -> &mut Self;
+
+    /// Like [LdtkEntityAppExt::register_ldtk_entity], except the bundle is only constructed once
+    /// per layer when a level spawns, and cloned onto every instance of the entity in that layer.
+    ///
+    /// This speeds up spawning levels with hundreds of identical decoration entities, like coins
+    /// or grass tufts, since [LdtkEntity::bundle_entity] doesn't run per instance and any
+    /// materials or texture atlas layouts it creates are shared.
+    ///
+    /// Since every instance gets the bundle constructed for the first one, only bundles that
+    /// don't depend on per-instance data, like field values, tiles or grid coordinates, can be
+    /// pooled.
+    /// The plugin still inserts the per-instance [EntityIid](crate::prelude::EntityIid), [Name]
+    /// and transform, and runs entity hooks for every instance.
+    /// Every instance also gets its instance data like unregistered entities do, according to
+    /// [EntityInstanceStorage](crate::prelude::EntityInstanceStorage).
+    ///
+    /// # Panics
+    /// Panics if [LdtkEntity::POOLABLE] is `false` for the bundle.
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_ldtk::prelude::*;
+    ///
+    /// fn main() {
+    ///     App::empty()
+    ///         .add_plugins(LdtkPlugin)
+    ///         .register_pooled_ldtk_entity::<GrassBundle>("Grass")
+    ///         // add other systems, plugins, resources...
+    ///         .run();
+    /// }
+    ///
+    /// # #[derive(Component, Clone, Default)]
+    /// # struct Decoration;
+    /// #[derive(Bundle, LdtkEntity, Clone, Default)]
+    /// pub struct GrassBundle {
+    ///     decoration: Decoration,
+    ///     #[sprite_sheet_bundle("grass.png", 16, 16, 4, 1, 0, 0, 0)]
+    ///     sprite_sheet: LdtkSpriteSheetBundle,
+    /// }
+    /// ```
+    fn register_pooled_ldtk_entity<B: LdtkEntity + Bundle + Clone>(
+        &mut self,
+        entity_identifier: &str,
+    ) -> &mut Self {
+        self.register_pooled_ldtk_entity_for_layer_optional::<B>(
+            None,
+            Some(entity_identifier.to_string()),
+        )
+    }
+
+    /// Like [LdtkEntityAppExt::register_ldtk_entity_for_layer], except the registration is
+    /// pooled, see [LdtkEntityAppExt::register_pooled_ldtk_entity].
+    fn register_pooled_ldtk_entity_for_layer<B: LdtkEntity + Bundle + Clone>(
+        &mut self,
+        layer_identifier: &str,
+        entity_identifier: &str,
+    ) -> &mut Self {
+        self.register_pooled_ldtk_entity_for_layer_optional::<B>(
+            Some(layer_identifier.to_string()),
+            Some(entity_identifier.to_string()),
+        )
+    }
+
+    /// Registers a callback with full [World] access to run for every LDtk entity with the given
+    /// identifier when it spawns.
+    ///
+    /// This is useful when spawning an entity requires more than what's available to
+    /// [LdtkEntity::bundle_entity], like custom resources or assets.
+    ///
+    /// The hook runs after any registered [LdtkEntity] bundle has been inserted, and before
+    /// systems can observe the level's [LevelEvent::Spawned].
+    /// It is given the spawned entity and its [EntityInstance].
+    /// Multiple hooks may be registered for the same identifier, and they run in order of
+    /// registration.
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_ldtk::prelude::*;
+    ///
+    /// # #[derive(Resource)]
+    /// # struct TorchParticles(Handle<Image>);
+    /// # #[derive(Component)]
+    /// # struct Emitter(Handle<Image>);
+    /// fn main() {
+    ///     App::empty()
+    ///         .add_plugins(LdtkPlugin)
+    ///         .register_ldtk_entity_hook("Torch", |world, entity, _entity_instance| {
+    ///             let particles = world.resource::<TorchParticles>().0.clone();
+    ///             world.entity_mut(entity).insert(Emitter(particles));
+    ///         })
+    ///         // add other systems, plugins, resources...
+    ///         .run();
+    /// }
+    /// ```
+    ///
+    /// [World]: bevy::prelude::World
+    /// [LevelEvent::Spawned]: crate::prelude::LevelEvent::Spawned
+    fn register_ldtk_entity_hook(
+        &mut self,
+        entity_identifier: &str,
+        hook: impl Fn(&mut World, Entity, &EntityInstance) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+fn insert_ldtk_entity_registration(
+    app: &mut App,
+    layer_identifier: Option<String>,
+    entity_identifier: Option<String>,
+    new_entry: Box<dyn PhantomLdtkEntityTrait>,
+) {
+    match app.world_mut().get_non_send_resource_mut::<LdtkEntityMap>() {
+        Some(mut entries) => {
+            entries.insert((layer_identifier, entity_identifier), new_entry);
+        }
+        None => {
+            let mut bundle_map = LdtkEntityMap::new();
+            bundle_map.insert((layer_identifier, entity_identifier), new_entry);
+            app.world_mut()
+                .insert_non_send_resource::<LdtkEntityMap>(bundle_map);
+        }
+    }
 }
 
 impl LdtkEntityAppExt for App {
@@ -107,21 +242,43 @@ impl LdtkEntityAppExt for App {
         layer_identifier: Option<String>,
         entity_identifier: Option<String>,
     ) -> &mut Self {
-        let new_entry = Box::new(PhantomLdtkEntity::<B>::new());
-        match self
-            .world_mut()
-            .get_non_send_resource_mut::<LdtkEntityMap>()
-        {
-            Some(mut entries) => {
-                entries.insert((layer_identifier, entity_identifier), new_entry);
-            }
-            None => {
-                let mut bundle_map = LdtkEntityMap::new();
-                bundle_map.insert((layer_identifier, entity_identifier), new_entry);
-                self.world_mut()
-                    .insert_non_send_resource::<LdtkEntityMap>(bundle_map);
-            }
-        }
+        insert_ldtk_entity_registration(
+            self,
+            layer_identifier,
+            entity_identifier,
+            Box::new(PhantomLdtkEntity::<B>::new()),
+        );
+        self
+    }
+
+    fn register_pooled_ldtk_entity_for_layer_optional<B: LdtkEntity + Bundle + Clone>(
+        &mut self,
+        layer_identifier: Option<String>,
+        entity_identifier: Option<String>,
+    ) -> &mut Self {
+        assert!(
+            B::POOLABLE,
+            "{} can't be pooled since it's constructed from per-instance data, see LdtkEntity::POOLABLE",
+            std::any::type_name::<B>()
+        );
+
+        insert_ldtk_entity_registration(
+            self,
+            layer_identifier,
+            entity_identifier,
+            Box::new(PooledPhantomLdtkEntity::<B>::new()),
+        );
+        self
+    }
+
+    fn register_ldtk_entity_hook(
+        &mut self,
+        entity_identifier: &str,
+        hook: impl Fn(&mut World, Entity, &EntityInstance) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(LdtkEntityHooks::default)
+            .add(entity_identifier, Arc::new(hook));
         self
     }
 }
@@ -130,27 +287,31 @@ impl LdtkEntityAppExt for App {
 mod tests {
     use super::*;
     use crate::{
-        components::EntityInstance,
-        ldtk::{LayerInstance, TilesetDefinition},
+        components::{EntityInstance, Worldly},
+        ldtk::{EntityDefinition, LayerInstance, TilesetDefinition},
+        resources::TilesetInfo,
     };
 
-    #[derive(Default, Component, Debug)]
+    #[derive(Default, Component, Debug, Clone)]
     struct ComponentA;
 
-    #[derive(Default, Component, Debug)]
+    #[derive(Default, Component, Debug, Clone)]
     struct ComponentB;
 
-    #[derive(Default, Bundle, Debug)]
+    #[derive(Default, Bundle, Debug, Clone)]
     struct LdtkEntityBundle {
         a: ComponentA,
         b: ComponentB,
     }
 
     impl LdtkEntity for LdtkEntityBundle {
+        const POOLABLE: bool = true;
+
         fn bundle_entity(
             _: &EntityInstance,
+            _: &EntityDefinition,
             _: &LayerInstance,
-            _: Option<&Handle<Image>>,
+            _: Option<&TilesetInfo>,
             _: Option<&TilesetDefinition>,
             _: &AssetServer,
             _: &mut Assets<TextureAtlasLayout>,
@@ -183,4 +344,31 @@ mod tests {
 
         assert!(ldtk_entity_map.contains_key(&(None, None)));
     }
+
+    #[derive(Default, Bundle, Debug, Clone)]
+    struct WorldlyBundle {
+        worldly: Worldly,
+    }
+
+    impl LdtkEntity for WorldlyBundle {
+        fn bundle_entity(
+            entity_instance: &EntityInstance,
+            _: &EntityDefinition,
+            _: &LayerInstance,
+            _: Option<&TilesetInfo>,
+            _: Option<&TilesetDefinition>,
+            _: &AssetServer,
+            _: &mut Assets<TextureAtlasLayout>,
+        ) -> WorldlyBundle {
+            WorldlyBundle {
+                worldly: Worldly::from_entity_info(entity_instance),
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be pooled")]
+    fn pooling_per_instance_bundles_panics() {
+        App::new().register_pooled_ldtk_entity::<WorldlyBundle>("worldly_entity");
+    }
 }
@@ -0,0 +1,146 @@
+//! Provides [LdtkEnumTagMarkerAppExt] for inserting marker components on tiles and entities with
+//! particular LDtk enum tags.
+use crate::{components::TileEnumTags, ldtk::EntityInstance};
+use bevy::prelude::*;
+use std::{collections::HashSet, marker::PhantomData};
+
+#[allow(unused_imports)]
+use crate::components::EntityInstanceRef;
+
+/// Stores the enum tags that the marker component `M` is inserted for.
+#[derive(Resource)]
+struct EnumTagMarkers<M: Component + Default> {
+    enum_tags: HashSet<String>,
+    marker: PhantomData<M>,
+}
+
+impl<M: Component + Default> Default for EnumTagMarkers<M> {
+    fn default() -> Self {
+        EnumTagMarkers {
+            enum_tags: HashSet::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Inserts the marker component `M` on newly-spawned tiles and entities with a registered tag.
+#[allow(clippy::type_complexity)]
+fn insert_enum_tag_markers<M: Component + Default>(
+    mut commands: Commands,
+    enum_tag_markers: Res<EnumTagMarkers<M>>,
+    query: Query<
+        (Entity, Option<&TileEnumTags>, Option<&EntityInstance>),
+        (Or<(Added<TileEnumTags>, Added<EntityInstance>)>, Without<M>),
+    >,
+) {
+    for (entity, tile_enum_tags, entity_instance) in query.iter() {
+        let mut tags = tile_enum_tags
+            .map(|tile_enum_tags| tile_enum_tags.tags.as_slice())
+            .into_iter()
+            .chain(entity_instance.map(|entity_instance| entity_instance.tags.as_slice()))
+            .flatten();
+
+        if tags.any(|tag| enum_tag_markers.enum_tags.contains(tag)) {
+            commands.entity(entity).insert(M::default());
+        }
+    }
+}
+
+/// [App]: bevy::prelude::App
+///
+/// Provides functions to register marker components to bevy's [App] for particular LDtk enum
+/// tags.
+///
+/// Once registered, the marker is inserted on every tile whose [TileEnumTags] contain the tag,
+/// and on every entity whose [EntityInstance] has the tag.
+/// This keeps gameplay queries like `Query<&GridCoords, With<Climbable>>` free of string
+/// comparisons.
+///
+/// Markers are inserted in [PostUpdate], the frame the tiles and entities are spawned.
+/// Entities spawned with an [EntityInstanceRef] instead of an [EntityInstance] don't receive
+/// markers.
+///
+/// Not intended for custom implementations on your own types.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_ecs_ldtk::prelude::*;
+///
+/// #[derive(Component, Default)]
+/// struct Climbable;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, LdtkPlugin))
+///         .register_enum_tag_marker::<Climbable>("Climbable")
+///         // add other systems, plugins, resources...
+///         .run();
+/// }
+/// ```
+pub trait LdtkEnumTagMarkerAppExt {
+    /// Inserts the marker component `M` on tiles and entities tagged with the given enum tag.
+    ///
+    /// The same marker can be registered for several tags.
+    fn register_enum_tag_marker<M: Component + Default>(&mut self, enum_tag: &str) -> &mut Self;
+}
+
+impl LdtkEnumTagMarkerAppExt for App {
+    fn register_enum_tag_marker<M: Component + Default>(&mut self, enum_tag: &str) -> &mut Self {
+        if !self.world().contains_resource::<EnumTagMarkers<M>>() {
+            self.init_resource::<EnumTagMarkers<M>>()
+                .add_systems(PostUpdate, insert_enum_tag_markers::<M>);
+        }
+
+        self.world_mut()
+            .resource_mut::<EnumTagMarkers<M>>()
+            .enum_tags
+            .insert(enum_tag.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Default)]
+    struct Climbable;
+
+    #[test]
+    fn markers_are_inserted_for_registered_tags() {
+        let mut app = App::new();
+        app.register_enum_tag_marker::<Climbable>("Ladder")
+            .register_enum_tag_marker::<Climbable>("Vine");
+
+        let ladder_tile = app
+            .world_mut()
+            .spawn(TileEnumTags {
+                tags: vec!["Solid".to_string(), "Ladder".to_string()],
+                source_enum_uid: None,
+            })
+            .id();
+
+        let solid_tile = app
+            .world_mut()
+            .spawn(TileEnumTags {
+                tags: vec!["Solid".to_string()],
+                source_enum_uid: None,
+            })
+            .id();
+
+        let vine_entity = app
+            .world_mut()
+            .spawn(EntityInstance {
+                tags: vec!["Vine".to_string()],
+                ..default()
+            })
+            .id();
+
+        app.update();
+
+        assert!(app.world().get::<Climbable>(ladder_tile).is_some());
+        assert!(app.world().get::<Climbable>(solid_tile).is_none());
+        assert!(app.world().get::<Climbable>(vine_entity).is_some());
+    }
+}
@@ -0,0 +1,147 @@
+//! Provides [LdtkLayerMaterialAppExt] for rendering particular layers with custom materials.
+use crate::components::LayerMetadata;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::{
+    MaterialTilemap, MaterialTilemapPlugin, StandardTilemapMaterial, TileStorage,
+};
+use std::{collections::HashMap, hash::Hash};
+
+type LayerMaterialFn<M> = Box<dyn Fn(&LayerMetadata, &mut Assets<M>) -> Handle<M> + Send + Sync>;
+
+/// Stores the material constructors registered for each layer identifier.
+#[derive(Resource)]
+struct LayerMaterials<M: MaterialTilemap> {
+    materials: HashMap<String, LayerMaterialFn<M>>,
+}
+
+impl<M: MaterialTilemap> Default for LayerMaterials<M> {
+    fn default() -> Self {
+        LayerMaterials {
+            materials: HashMap::new(),
+        }
+    }
+}
+
+/// Replaces the default tilemap material of newly-spawned layers that have a registered material.
+#[allow(clippy::type_complexity)]
+fn apply_layer_materials<M: MaterialTilemap>(
+    mut commands: Commands,
+    layer_materials: Res<LayerMaterials<M>>,
+    mut materials: ResMut<Assets<M>>,
+    layer_query: Query<
+        (Entity, &LayerMetadata),
+        (Added<LayerMetadata>, With<Handle<StandardTilemapMaterial>>),
+    >,
+) {
+    for (layer_entity, layer_metadata) in layer_query.iter() {
+        if let Some(material_fn) = layer_materials.materials.get(&layer_metadata.identifier) {
+            let material = material_fn(layer_metadata, &mut materials);
+
+            commands
+                .entity(layer_entity)
+                .remove::<Handle<StandardTilemapMaterial>>()
+                .insert(material);
+        }
+    }
+}
+
+/// Removes the custom material of layers whose [TileStorage] is removed, so the material is
+/// released with the rest of the tilemap.
+fn release_layer_material<M: MaterialTilemap>(
+    trigger: Trigger<OnRemove, TileStorage>,
+    mut commands: Commands,
+    layer_query: Query<(), (With<LayerMetadata>, With<Handle<M>>)>,
+) {
+    if layer_query.contains(trigger.entity()) {
+        let layer_entity = trigger.entity();
+
+        commands.add(move |world: &mut World| {
+            if let Some(mut layer_entity) = world.get_entity_mut(layer_entity) {
+                layer_entity.remove::<Handle<M>>();
+            }
+        });
+    }
+}
+
+/// [App]: bevy::prelude::App
+///
+/// Provides functions to register custom tilemap materials to bevy's [App] for particular LDtk
+/// layers.
+///
+/// Not intended for custom implementations on your own types.
+///
+/// Requires the `render` feature to be enabled.
+pub trait LdtkLayerMaterialAppExt {
+    /// Renders tiles of layers with the given identifier with a custom [MaterialTilemap], instead
+    /// of the default [StandardTilemapMaterial].
+    ///
+    /// `material_fn` is called once for every layer entity spawned for the layer, and should
+    /// return the handle of the material to use.
+    /// It may add a new material to the given [Assets], or return a clone of a shared handle.
+    ///
+    /// The [MaterialTilemapPlugin] for `M` is added if it hasn't been already.
+    /// Only Tile, AutoLayer and IntGrid layers are affected, since entity layers have no tiles.
+    ///
+    /// ```no_run
+    /// use bevy::{prelude::*, render::render_resource::{AsBindGroup, ShaderRef}};
+    /// use bevy_ecs_ldtk::prelude::*;
+    /// use bevy_ecs_tilemap::prelude::MaterialTilemap;
+    ///
+    /// #[derive(Asset, AsBindGroup, TypePath, Clone, Debug, Default)]
+    /// struct WaterMaterial {
+    ///     #[uniform(0)]
+    ///     wave_speed: f32,
+    /// }
+    ///
+    /// impl MaterialTilemap for WaterMaterial {
+    ///     fn fragment_shader() -> ShaderRef {
+    ///         "shaders/water.wgsl".into()
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .add_plugins((DefaultPlugins, LdtkPlugin))
+    ///         .register_layer_material::<WaterMaterial>("Water", |_, materials| {
+    ///             materials.add(WaterMaterial { wave_speed: 2. })
+    ///         })
+    ///         // add other systems, plugins, resources...
+    ///         .run();
+    /// }
+    /// ```
+    fn register_layer_material<M: MaterialTilemap>(
+        &mut self,
+        layer_identifier: &str,
+        material_fn: impl Fn(&LayerMetadata, &mut Assets<M>) -> Handle<M> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        M::Data: PartialEq + Eq + Hash + Clone;
+}
+
+impl LdtkLayerMaterialAppExt for App {
+    fn register_layer_material<M: MaterialTilemap>(
+        &mut self,
+        layer_identifier: &str,
+        material_fn: impl Fn(&LayerMetadata, &mut Assets<M>) -> Handle<M> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        M::Data: PartialEq + Eq + Hash + Clone,
+    {
+        if !self.is_plugin_added::<MaterialTilemapPlugin<M>>() {
+            self.add_plugins(MaterialTilemapPlugin::<M>::default());
+        }
+
+        if !self.world().contains_resource::<LayerMaterials<M>>() {
+            self.init_resource::<LayerMaterials<M>>()
+                .add_systems(PostUpdate, apply_layer_materials::<M>)
+                .observe(release_layer_material::<M>);
+        }
+
+        self.world_mut()
+            .resource_mut::<LayerMaterials<M>>()
+            .materials
+            .insert(layer_identifier.to_string(), Box::new(material_fn));
+
+        self
+    }
+}
@@ -1,11 +1,17 @@
 use crate::{
-    components::{EntityInstanceBundle, GridCoords, Worldly},
-    ldtk::{EntityInstance, LayerInstance, TilesetDefinition},
+    components::{EntityInstanceBundle, EntityInstanceRef, GridCoords, Worldly},
+    ldtk::{EntityDefinition, EntityInstance, LayerInstance, TilesetDefinition},
+    resources::TilesetInfo,
     utils,
 };
 use bevy::{ecs::system::EntityCommands, prelude::*};
 use std::{collections::HashMap, marker::PhantomData};
 
+#[allow(unused_imports)]
+use crate::components::{SpriteSheetAnimation, TransformFromFields, YSort};
+#[allow(unused_imports)]
+use crate::resources::{LdtkSettings, YSorting};
+
 /// [LdtkEntityAppExt]: super::LdtkEntityAppExt
 /// [Bundle]: bevy::prelude::Bundle
 /// [App]: bevy::prelude::App
@@ -72,7 +78,16 @@ use std::{collections::HashMap, marker::PhantomData};
 ///   path in the assets folder.
 /// - `#[sprite_bundle]` will create the field using its Editor Visual image in LDtk, if it has one.
 ///
+/// Either form can be given the `use_smart_color` argument, like
+/// `#[sprite_bundle("path/to/asset.png", use_smart_color)]` or `#[sprite_bundle(use_smart_color)]`,
+/// to tint the sprite with the entity's smart color in LDtk.
+/// If `#[sprite_bundle(use_smart_color)]` is used on an entity without an Editor Visual image, the
+/// sprite will be a placeholder rectangle of the entity's size and smart color.
+///
 /// Note that if your editor visual is part of a tilemap, you should use `#[sprite_sheet_bundle]` instead.
+///
+/// Without the `render` feature, no images are loaded, and this attribute and
+/// `#[sprite_sheet_bundle...]` create sprites without textures.
 /// ```
 /// # use bevy::prelude::*;
 /// # use bevy_ecs_ldtk::prelude::*;
@@ -96,12 +111,18 @@ use std::{collections::HashMap, marker::PhantomData};
 ///     #[sprite_bundle] // Uses the Editor Visual sprite in LDtk
 ///     sprite_bundle: SpriteBundle,
 /// }
+///
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct Checkpoint {
+///     #[sprite_bundle(use_smart_color)] // Placeholder in the entity's color
+///     sprite_bundle: SpriteBundle,
+/// }
 /// ```
 ///
 /// ### `#[sprite_sheet_bundle...]`
 /// Similar to `#[sprite_bundle...]`, indicates that a [LdtkSpriteSheetBundle] field should be created
 /// with an actual material/image.
-/// There are two forms for this attribute:
+/// There are three forms for this attribute:
 /// - `#[sprite_sheet_bundle("path/to/asset.png", tile_width, tile_height, columns, rows, padding,
 ///   offset, index)]` will create the field using all of the information provided.
 ///   Similar to using [TextureAtlasLayout::from_grid()].
@@ -111,6 +132,9 @@ use std::{collections::HashMap, marker::PhantomData};
 ///   Editor visual, if it has one, but without using a grid. Instead a single texture will be used.
 ///   This may be useful if the LDtk entity's visual uses a rectangle of tiles from its tileset,
 ///   but will prevent using the generated [TextureAtlasLayout] for animation purposes.
+///   It's also useful for tilesets that aren't a uniform grid, since the sprite's rectangle is
+///   taken from the exact pixel rectangle of the visual, rather than computed from columns and
+///   rows.
 /// ```
 /// # use bevy::prelude::*;
 /// # use bevy_ecs_ldtk::prelude::*;
@@ -132,6 +156,55 @@ use std::{collections::HashMap, marker::PhantomData};
 ///     #[sprite_sheet_bundle]
 ///     sprite_sheet: LdtkSpriteSheetBundle,
 /// }
+///
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct Statue {
+///     #[sprite_sheet_bundle(no_grid)] // Irregular sprite from a packed tileset
+///     sprite_sheet: LdtkSpriteSheetBundle,
+/// }
+/// ```
+///
+/// ### `#[sprite_sheet_animation...]`
+/// Indicates that a [SpriteSheetAnimation] component should be configured from the entity's
+/// fields in LDtk, so its sprite sheet cycles through frames automatically.
+/// The frames field should be an `Array<Int>` of texture atlas indices, and the fps field should
+/// be a `Float` or `Int`.
+/// There are two forms for this attribute:
+/// - `#[sprite_sheet_animation(frames_field = "frames", fps_field = "fps")]` will use the fields
+///   with the given identifiers. Either argument can be omitted.
+/// - `#[sprite_sheet_animation]` will use fields called "frames" and "fps".
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct Torch {
+///     #[sprite_sheet_bundle]
+///     sprite_sheet: LdtkSpriteSheetBundle,
+///     #[sprite_sheet_animation(frames_field = "flicker_frames", fps_field = "flicker_fps")]
+///     animation: SpriteSheetAnimation,
+/// }
+/// ```
+///
+/// ### `#[transform_from_fields...]`
+/// Indicates that a [TransformFromFields] component should be configured from the entity's fields
+/// in LDtk, so the entity is rotated and scaled per-instance when it spawns.
+/// The rotation field should be a `Float` or `Int` of clockwise degrees, and the scale field should
+/// be a `Float` or `Int` multiplying the entity's scale.
+/// Missing or null fields leave the transform unaffected.
+/// There are two forms for this attribute:
+/// - `#[transform_from_fields(rotation = "angle", scale = "scale")]` will use the fields with the
+///   given identifiers. Either argument can be omitted.
+/// - `#[transform_from_fields]` will use fields called "rotation" and "scale".
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct Crate {
+///     #[sprite_sheet_bundle]
+///     sprite_sheet: LdtkSpriteSheetBundle,
+///     #[transform_from_fields(rotation = "angle")]
+///     transform_from_fields: TransformFromFields,
+/// }
 /// ```
 ///
 /// ### `#[worldly]`
@@ -159,6 +232,21 @@ use std::{collections::HashMap, marker::PhantomData};
 /// }
 /// ```
 ///
+/// ### `#[y_sort]`
+/// Indicates that a component is [YSort], so the entity's z translation follows its y translation
+/// while [YSorting::Enabled] is set in the [LdtkSettings].
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct TreeBundle {
+///     #[sprite_sheet_bundle]
+///     sprite_sheet_bundle: LdtkSpriteSheetBundle,
+///     #[y_sort]
+///     y_sort: YSort,
+/// }
+/// ```
+///
 /// ### `#[grid_coords]`
 /// Indicates that a [GridCoords] component should be created with the entity's initial grid-based
 /// position in LDtk.
@@ -219,6 +307,9 @@ use std::{collections::HashMap, marker::PhantomData};
 /// `impl LdtkEntity` for both of them.
 /// It also allows you to have an [EntityInstance] field, since `EntityInstance` implements
 /// `From<&EntityInstance>`.
+///
+/// The conversion only borrows the [EntityInstance], so its fields are never cloned unless your
+/// `From` implementation clones them.
 /// ```
 /// # use bevy::prelude::*;
 /// # use bevy_ecs_ldtk::prelude::*;
@@ -249,6 +340,31 @@ use std::{collections::HashMap, marker::PhantomData};
 /// }
 /// ```
 ///
+/// ### `#[from_entity_definition]`
+/// Indicates that a component or bundle that implements [From<&EntityDefinition>] should be
+/// created using that conversion.
+/// This is useful for data shared by every instance of an entity, like the tags, max size, or
+/// default field values given to its definition in LDtk.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// # #[derive(Component, Default)]
+/// # struct Tags(Vec<String>);
+/// impl From<&EntityDefinition> for Tags {
+///     fn from(entity_definition: &EntityDefinition) -> Tags {
+///         Tags(entity_definition.tags.clone())
+///     }
+/// }
+///
+/// #[derive(Bundle, LdtkEntity, Default)]
+/// pub struct EnemyBundle {
+///     #[sprite_sheet_bundle]
+///     sprite_sheet: LdtkSpriteSheetBundle,
+///     #[from_entity_definition]
+///     tags: Tags,
+/// }
+/// ```
+///
 /// ### `#[with(...)]`
 ///
 /// Indicates that this component or bundle should be initialized with the given
@@ -283,12 +399,14 @@ use std::{collections::HashMap, marker::PhantomData};
 /// }
 /// ```
 ///
-/// ### `#[default]`
+/// ### `#[default...]`
 ///
 /// Indicates that this component or bundle should be initialized using
-/// [`Default::default`].
+/// [`Default::default`], or with the given expression if the attribute takes the form
+/// `#[default(expression)]`.
 /// This can be useful when implementing `Default` for the whole `LdtkEntity` is
-/// not easily possible, because some of the fields do not implement `Default`.
+/// not easily possible, because some of the fields do not implement `Default`, or when a field
+/// needs a specific value.
 ///
 /// ```
 /// # use bevy::prelude::*;
@@ -300,17 +418,35 @@ use std::{collections::HashMap, marker::PhantomData};
 /// # fn custom_constructor(_: &EntityInstance) -> ForeignComponentWithNoDefault { todo!(); }
 /// # #[derive(Component, Default)]
 /// # struct Damage;
+/// # #[derive(Component)]
+/// # struct Health(i32);
 /// use other_crate::ForeignComponentWithNoDefault;
 ///
 /// #[derive(Bundle, LdtkEntity)]
 /// pub struct MyBundle {
 ///     #[default]
 ///     damage: Damage,
+///     #[default(Health(100))]
+///     health: Health,
 ///     #[with(custom_constructor)]
 ///     foreign: ForeignComponentWithNoDefault,
 /// }
 /// ```
 pub trait LdtkEntity {
+    /// Whether the bundle only depends on data shared by every instance of an entity, so it can
+    /// be constructed once and cloned onto every instance, see
+    /// [LdtkEntityAppExt::register_pooled_ldtk_entity].
+    ///
+    /// `false` by default.
+    /// The derive macro sets it to `true` unless a field is constructed from the entity instance,
+    /// i.e. with `#[sprite_bundle]` or `#[sprite_sheet_bundle]` without an asset path,
+    /// `#[sprite_sheet_animation]`, `#[transform_from_fields]`, `#[worldly]`, `#[grid_coords]`,
+    /// `#[from_entity_instance]`, `#[with(...)]`, or `#[ldtk_entity]` for a bundle that isn't
+    /// poolable itself.
+    ///
+    /// [LdtkEntityAppExt::register_pooled_ldtk_entity]: super::LdtkEntityAppExt::register_pooled_ldtk_entity
+    const POOLABLE: bool = false;
+
     /// The constructor used by the plugin when spawning entities from an LDtk file.
     /// Has access to resources/assets most commonly used for spawning 2d objects.
     /// If you need access to more of the [World](bevy::prelude::World), you can create a system that queries for
@@ -323,10 +459,16 @@ pub trait LdtkEntity {
     /// [SpatialBundle](bevy::prelude::SpatialBundle) to the entity **after** this bundle is
     /// inserted.
     /// So, any custom implementations of these components within this trait will be overwritten.
+    ///
+    /// `entity_definition` is the definition of the entity, for components built from data shared
+    /// by all of its instances, like the definition's max size, tags, or default field values.
+    /// `tileset_info` and `tileset_definition` belong to the tileset of the entity's tile, if it
+    /// has one.
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        entity_definition: &EntityDefinition,
         layer_instance: &LayerInstance,
-        tileset: Option<&Handle<Image>>,
+        tileset_info: Option<&TilesetInfo>,
         tileset_definition: Option<&TilesetDefinition>,
         asset_server: &AssetServer,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
@@ -336,8 +478,9 @@ pub trait LdtkEntity {
 impl LdtkEntity for EntityInstanceBundle {
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        _: &EntityDefinition,
         _: &LayerInstance,
-        _: Option<&Handle<Image>>,
+        _: Option<&TilesetInfo>,
         _: Option<&TilesetDefinition>,
         _: &AssetServer,
         _: &mut Assets<TextureAtlasLayout>,
@@ -348,24 +491,42 @@ impl LdtkEntity for EntityInstanceBundle {
     }
 }
 
+impl LdtkEntity for EntityInstanceRef {
+    fn bundle_entity(
+        entity_instance: &EntityInstance,
+        _: &EntityDefinition,
+        layer_instance: &LayerInstance,
+        _: Option<&TilesetInfo>,
+        _: Option<&TilesetDefinition>,
+        _: &AssetServer,
+        _: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        EntityInstanceRef::from_entity_info(entity_instance, layer_instance)
+    }
+}
+
 impl LdtkEntity for SpriteBundle {
     fn bundle_entity(
         _: &EntityInstance,
+        _: &EntityDefinition,
         _: &LayerInstance,
-        tileset: Option<&Handle<Image>>,
+        tileset_info: Option<&TilesetInfo>,
         _: Option<&TilesetDefinition>,
         _: &AssetServer,
         _: &mut Assets<TextureAtlasLayout>,
     ) -> Self {
-        utils::sprite_bundle_from_entity_info(tileset)
+        utils::sprite_bundle_from_entity_info(
+            tileset_info.and_then(|tileset_info| tileset_info.image.as_ref()),
+        )
     }
 }
 
 impl LdtkEntity for Worldly {
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        _: &EntityDefinition,
         _: &LayerInstance,
-        _: Option<&Handle<Image>>,
+        _: Option<&TilesetInfo>,
         _: Option<&TilesetDefinition>,
         _: &AssetServer,
         _: &mut Assets<TextureAtlasLayout>,
@@ -377,8 +538,9 @@ impl LdtkEntity for Worldly {
 impl LdtkEntity for GridCoords {
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        _: &EntityDefinition,
         layer_instance: &LayerInstance,
-        _: Option<&Handle<Image>>,
+        _: Option<&TilesetInfo>,
         _: Option<&TilesetDefinition>,
         _: &AssetServer,
         _: &mut Assets<TextureAtlasLayout>,
@@ -406,12 +568,44 @@ pub trait PhantomLdtkEntityTrait {
         &self,
         commands: &'b mut EntityCommands<'a>,
         entity_instance: &EntityInstance,
+        entity_definition: &EntityDefinition,
         layer_instance: &LayerInstance,
-        tileset: Option<&Handle<Image>>,
+        tileset_info: Option<&TilesetInfo>,
         tileset_definition: Option<&TilesetDefinition>,
         asset_server: &AssetServer,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
     ) -> &'b mut EntityCommands<'a>;
+
+    /// Constructs the bundle to clone onto every instance spawned with this registration, if it
+    /// is pooled.
+    ///
+    /// Returns `None` by default, so every instance is evaluated separately.
+    #[allow(clippy::too_many_arguments)]
+    fn pooled_bundle(
+        &self,
+        _entity_instance: &EntityInstance,
+        _entity_definition: &EntityDefinition,
+        _layer_instance: &LayerInstance,
+        _tileset_info: Option<&TilesetInfo>,
+        _tileset_definition: Option<&TilesetDefinition>,
+        _asset_server: &AssetServer,
+        _texture_atlases: &mut Assets<TextureAtlasLayout>,
+    ) -> Option<Box<dyn PooledLdtkEntityBundle>> {
+        None
+    }
+}
+
+/// A bundle constructed once and cloned onto many LDtk entities, see
+/// [LdtkEntityAppExt::register_pooled_ldtk_entity](super::LdtkEntityAppExt::register_pooled_ldtk_entity).
+pub trait PooledLdtkEntityBundle {
+    /// Inserts a clone of this bundle on the given entity.
+    fn insert_clone(&self, entity_commands: &mut EntityCommands);
+}
+
+impl<B: Bundle + Clone> PooledLdtkEntityBundle for B {
+    fn insert_clone(&self, entity_commands: &mut EntityCommands) {
+        entity_commands.insert(self.clone());
+    }
 }
 
 impl<B: LdtkEntity + Bundle> PhantomLdtkEntityTrait for PhantomLdtkEntity<B> {
@@ -419,16 +613,18 @@ impl<B: LdtkEntity + Bundle> PhantomLdtkEntityTrait for PhantomLdtkEntity<B> {
         &self,
         entity_commands: &'b mut EntityCommands<'a>,
         entity_instance: &EntityInstance,
+        entity_definition: &EntityDefinition,
         layer_instance: &LayerInstance,
-        tileset: Option<&Handle<Image>>,
+        tileset_info: Option<&TilesetInfo>,
         tileset_definition: Option<&TilesetDefinition>,
         asset_server: &AssetServer,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
     ) -> &'b mut EntityCommands<'a> {
         entity_commands.insert(B::bundle_entity(
             entity_instance,
+            entity_definition,
             layer_instance,
-            tileset,
+            tileset_info,
             tileset_definition,
             asset_server,
             texture_atlases,
@@ -436,5 +632,64 @@ impl<B: LdtkEntity + Bundle> PhantomLdtkEntityTrait for PhantomLdtkEntity<B> {
     }
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash)]
+pub struct PooledPhantomLdtkEntity<B: LdtkEntity + Bundle + Clone> {
+    ldtk_entity: PhantomData<B>,
+}
+
+impl<B: LdtkEntity + Bundle + Clone> PooledPhantomLdtkEntity<B> {
+    pub fn new() -> Self {
+        PooledPhantomLdtkEntity::<B> {
+            ldtk_entity: PhantomData,
+        }
+    }
+}
+
+impl<B: LdtkEntity + Bundle + Clone> PhantomLdtkEntityTrait for PooledPhantomLdtkEntity<B> {
+    fn evaluate<'a, 'b>(
+        &self,
+        entity_commands: &'b mut EntityCommands<'a>,
+        entity_instance: &EntityInstance,
+        entity_definition: &EntityDefinition,
+        layer_instance: &LayerInstance,
+        tileset_info: Option<&TilesetInfo>,
+        tileset_definition: Option<&TilesetDefinition>,
+        asset_server: &AssetServer,
+        texture_atlases: &mut Assets<TextureAtlasLayout>,
+    ) -> &'b mut EntityCommands<'a> {
+        PhantomLdtkEntity::<B>::new().evaluate(
+            entity_commands,
+            entity_instance,
+            entity_definition,
+            layer_instance,
+            tileset_info,
+            tileset_definition,
+            asset_server,
+            texture_atlases,
+        )
+    }
+
+    fn pooled_bundle(
+        &self,
+        entity_instance: &EntityInstance,
+        entity_definition: &EntityDefinition,
+        layer_instance: &LayerInstance,
+        tileset_info: Option<&TilesetInfo>,
+        tileset_definition: Option<&TilesetDefinition>,
+        asset_server: &AssetServer,
+        texture_atlases: &mut Assets<TextureAtlasLayout>,
+    ) -> Option<Box<dyn PooledLdtkEntityBundle>> {
+        Some(Box::new(B::bundle_entity(
+            entity_instance,
+            entity_definition,
+            layer_instance,
+            tileset_info,
+            tileset_definition,
+            asset_server,
+            texture_atlases,
+        )))
+    }
+}
+
 /// Used by [LdtkEntityAppExt](super::LdtkEntityAppExt) to associate Ldtk entity identifiers with [LdtkEntity]s.
 pub type LdtkEntityMap = HashMap<(Option<String>, Option<String>), Box<dyn PhantomLdtkEntityTrait>>;
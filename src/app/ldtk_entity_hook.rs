@@ -0,0 +1,61 @@
+use crate::ldtk::EntityInstance;
+use bevy::{ecs::system::EntityCommand, prelude::*};
+use std::{collections::HashMap, sync::Arc};
+
+/// Callback registered with [LdtkEntityAppExt::register_ldtk_entity_hook].
+///
+/// [LdtkEntityAppExt::register_ldtk_entity_hook]: crate::app::LdtkEntityAppExt::register_ldtk_entity_hook
+pub type LdtkEntityHook = Arc<dyn Fn(&mut World, Entity, &EntityInstance) + Send + Sync>;
+
+/// [Resource] storing [LdtkEntityHook]s by LDtk entity identifier.
+///
+/// Hooks are registered with [LdtkEntityAppExt::register_ldtk_entity_hook].
+///
+/// [Resource]: bevy::prelude::Resource
+/// [LdtkEntityAppExt::register_ldtk_entity_hook]: crate::app::LdtkEntityAppExt::register_ldtk_entity_hook
+#[derive(Clone, Default, Resource)]
+pub struct LdtkEntityHooks {
+    hooks: HashMap<String, Vec<LdtkEntityHook>>,
+}
+
+impl LdtkEntityHooks {
+    /// Adds a hook for LDtk entities with the given identifier.
+    ///
+    /// Hooks for the same identifier run in the order they were added.
+    pub fn add(&mut self, entity_identifier: impl Into<String>, hook: LdtkEntityHook) {
+        self.hooks
+            .entry(entity_identifier.into())
+            .or_default()
+            .push(hook);
+    }
+
+    /// Returns the hooks for LDtk entities with the given identifier.
+    pub fn get(&self, entity_identifier: &str) -> &[LdtkEntityHook] {
+        self.hooks
+            .get(entity_identifier)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns an [EntityCommand] running the hooks for the given LDtk entity, if it has any.
+    ///
+    /// The entity instance is only cloned if there are hooks to run.
+    pub(crate) fn entity_command(
+        &self,
+        entity_instance: &EntityInstance,
+    ) -> Option<impl EntityCommand> {
+        let hooks = self.get(&entity_instance.identifier).to_vec();
+
+        if hooks.is_empty() {
+            return None;
+        }
+
+        let entity_instance = entity_instance.clone();
+
+        Some(move |entity: Entity, world: &mut World| {
+            for hook in hooks {
+                hook(world, entity, &entity_instance);
+            }
+        })
+    }
+}
@@ -89,12 +89,13 @@ use std::{collections::HashMap, marker::PhantomData};
 /// ```
 ///
 /// ### `#[from_int_grid_cell]`
-/// Indicates that a component or bundle that implements [`From<IntGridCell>`] should be created
+/// Indicates that a component or bundle that implements [`From<&IntGridCell>`] should be created
 /// using that conversion.
 /// This allows for more modular and custom component construction, and for different structs that
 /// contain the same component to have different constructions of that component, without having to
 /// `impl LdtkIntCell` for both of them.
-/// It also allows you to have an [IntGridCell] field, since all types `T` implement `From<T>`.
+/// It also allows you to have an [IntGridCell] field, since `IntGridCell` implements
+/// `From<&IntGridCell>`.
 /// ```
 /// # use bevy::prelude::*;
 /// # use bevy_ecs_ldtk::prelude::*;
@@ -102,8 +103,8 @@ use std::{collections::HashMap, marker::PhantomData};
 /// # struct Fluid { viscosity: i32 }
 /// # #[derive(Component, Default)]
 /// # struct Damage;
-/// impl From<IntGridCell> for Fluid {
-///     fn from(int_grid_cell: IntGridCell) -> Fluid {
+/// impl From<&IntGridCell> for Fluid {
+///     fn from(int_grid_cell: &IntGridCell) -> Fluid {
 ///         let viscosity = match int_grid_cell.value {
 ///             1 => 5,
 ///             2 => 20,
@@ -155,12 +156,14 @@ use std::{collections::HashMap, marker::PhantomData};
 /// }
 /// ```
 ///
-/// ### `#[default]`
+/// ### `#[default...]`
 ///
 /// Indicates that this component or bundle should be initialized using
-/// [`Default::default`].
+/// [`Default::default`], or with the given expression if the attribute takes the form
+/// `#[default(expression)]`.
 /// This can be useful when implementing `Default` for the whole `IntGridCell` is
-/// not easily possible, because some of the fields do not implement `Default`.
+/// not easily possible, because some of the fields do not implement `Default`, or when a field
+/// needs a specific value.
 ///
 /// ```
 /// # use bevy::prelude::*;
@@ -172,12 +175,16 @@ use std::{collections::HashMap, marker::PhantomData};
 /// # fn custom_constructor(_: IntGridCell) -> ForeignComponentWithNoDefault { todo!(); }
 /// # #[derive(Component, Default)]
 /// # struct Damage;
+/// # #[derive(Component)]
+/// # struct Health(i32);
 /// use other_crate::ForeignComponentWithNoDefault;
 ///
 /// #[derive(Bundle, LdtkIntCell)]
 /// pub struct MyBundle {
 ///     #[default]
 ///     damage: Damage,
+///     #[default(Health(100))]
+///     health: Health,
 ///     #[with(custom_constructor)]
 ///     foreign: ForeignComponentWithNoDefault,
 /// }
@@ -1,11 +1,19 @@
 //! Types and traits for hooking into the ldtk loading process via [bevy::app::App].
 
 mod entity_app_ext;
+mod enum_tag_marker_app_ext;
 mod int_cell_app_ext;
+#[cfg(feature = "render")]
+mod layer_material_app_ext;
 mod ldtk_entity;
+mod ldtk_entity_hook;
 mod ldtk_int_cell;
 
 pub use entity_app_ext::*;
+pub use enum_tag_marker_app_ext::*;
 pub use int_cell_app_ext::*;
+#[cfg(feature = "render")]
+pub use layer_material_app_ext::*;
 pub use ldtk_entity::*;
+pub use ldtk_entity_hook::*;
 pub use ldtk_int_cell::*;
@@ -1,7 +1,8 @@
 use crate::{
     assets::{level_locale::LevelLocale, LevelIndices, LevelMetadata, LevelMetadataAccessor},
     ldtk::{
-        loaded_level::LoadedLevel, raw_level_accessor::RawLevelAccessor, LdtkJson, Level, World,
+        loaded_level::LoadedLevel, raw_level_accessor::RawLevelAccessor, EntityInstance, LdtkJson,
+        Level, World,
     },
     resources::LevelSelection,
 };
@@ -23,6 +24,14 @@ fn expect_level_loaded(level: &Level) -> LoadedLevel {
         .expect("LdtkProject construction should guarantee that internal levels are loaded")
 }
 
+/// Location of an entity instance in the raw levels of an [`LdtkJsonWithMetadata`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct EntityInstanceIndices {
+    level: LevelIndices,
+    layer: usize,
+    entity: usize,
+}
+
 /// LDtk json data and level metadata produced when loading an [`LdtkProject`] asset.
 ///
 /// Generic over a level-locale marker type, `L`.
@@ -45,6 +54,10 @@ where
     json_data: LdtkJson,
     /// Map from level iids to level metadata.
     level_map: HashMap<String, L::Metadata>,
+    /// Map from entity instance iids to their location in raw levels with layer data.
+    #[getter(skip)]
+    #[reflect(ignore)]
+    entity_instance_map: HashMap<String, EntityInstanceIndices>,
 }
 
 impl<L> LdtkJsonWithMetadata<L>
@@ -59,11 +72,54 @@ where
         json_data: LdtkJson,
         level_map: HashMap<String, L::Metadata>,
     ) -> LdtkJsonWithMetadata<L> {
+        let mut entity_instance_map = HashMap::new();
+
+        for (level, raw_level) in json_data.iter_raw_levels_with_indices() {
+            for (layer, layer_instance) in raw_level.layer_instances.iter().flatten().enumerate() {
+                for (entity, entity_instance) in layer_instance.entity_instances.iter().enumerate()
+                {
+                    entity_instance_map.insert(
+                        entity_instance.iid.clone(),
+                        EntityInstanceIndices {
+                            level,
+                            layer,
+                            entity,
+                        },
+                    );
+                }
+            }
+        }
+
         LdtkJsonWithMetadata {
             json_data,
             level_map,
+            entity_instance_map,
         }
     }
+
+    /// Immutable access to the entity instance with the given iid, along with its level.
+    ///
+    /// This lookup is constant, since entity instances are indexed by iid on construction.
+    ///
+    /// Only entity instances of raw levels with layer data can be found, so the entities of
+    /// external levels are not.
+    pub fn get_entity_instance_by_iid(&self, iid: &str) -> Option<(&Level, &EntityInstance)> {
+        let EntityInstanceIndices {
+            level,
+            layer,
+            entity,
+        } = self.entity_instance_map.get(iid)?;
+
+        let raw_level = self.get_raw_level_at_indices(level)?;
+        let entity_instance = raw_level
+            .layer_instances
+            .as_ref()?
+            .get(*layer)?
+            .entity_instances
+            .get(*entity)?;
+
+        Some((raw_level, entity_instance))
+    }
 }
 
 impl<L> RawLevelAccessor for LdtkJsonWithMetadata<L>
@@ -269,10 +325,7 @@ pub mod tests {
                     })
                     .collect();
 
-                LdtkJsonWithMetadata {
-                    json_data,
-                    level_map,
-                }
+                LdtkJsonWithMetadata::new(json_data, level_map)
             }
         }
 
@@ -297,10 +350,10 @@ pub mod tests {
         fn level_metadata_accessor_implementation_is_transparent() {
             let basic = BasicLevelMetadataAccessor::sample_with_root_levels();
 
-            let ldtk_json_with_metadata = LdtkJsonWithMetadata::<InternalLevels> {
-                json_data: basic.data.clone(),
-                level_map: basic.level_metadata.clone(),
-            };
+            let ldtk_json_with_metadata = LdtkJsonWithMetadata::<InternalLevels>::new(
+                basic.data.clone(),
+                basic.level_metadata.clone(),
+            );
 
             for level in &basic.data.levels {
                 assert_eq!(
@@ -481,10 +534,7 @@ pub mod tests {
                     })
                     .collect();
 
-                LdtkJsonWithMetadata {
-                    json_data,
-                    level_map,
-                }
+                LdtkJsonWithMetadata::new(json_data, level_map)
             }
         }
 
@@ -533,10 +583,7 @@ pub mod tests {
                 })
                 .collect();
 
-            LdtkJsonWithMetadata {
-                json_data,
-                level_map,
-            }
+            LdtkJsonWithMetadata::new(json_data, level_map)
         }
 
         #[test]
@@ -554,9 +601,9 @@ pub mod tests {
         fn external_level_metadata_accessor_is_transparent() {
             let basic = BasicLevelMetadataAccessor::sample_with_root_levels();
 
-            let ldtk_json_with_metadata = LdtkJsonWithMetadata::<ExternalLevels> {
-                json_data: basic.data.clone(),
-                level_map: basic
+            let ldtk_json_with_metadata = LdtkJsonWithMetadata::<ExternalLevels>::new(
+                basic.data.clone(),
+                basic
                     .level_metadata
                     .clone()
                     .into_iter()
@@ -567,7 +614,7 @@ pub mod tests {
                         )
                     })
                     .collect(),
-            };
+            );
 
             for level in &basic.data.levels {
                 assert_eq!(
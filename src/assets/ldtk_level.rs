@@ -0,0 +1,32 @@
+use crate::components::LevelIid;
+use bevy::prelude::*;
+use derive_getters::Getters;
+
+/// Sub-asset of [`LdtkProject`] referring to a single level, for spawning levels on their own.
+///
+/// Every level in a project is available as a labeled asset, using the level's identifier as the
+/// label.
+/// E.g., `asset_server.load("my_project.ldtk#Level_0")` will load the level with identifier
+/// `Level_0`.
+///
+/// Spawn it with an [`LdtkLevelBundle`] to spawn the level without an [`LdtkWorldBundle`] or
+/// [`LevelSelection`].
+///
+/// [`LdtkProject`]: crate::assets::LdtkProject
+/// [`LdtkLevelBundle`]: crate::prelude::LdtkLevelBundle
+/// [`LdtkWorldBundle`]: crate::prelude::LdtkWorldBundle
+/// [`LevelSelection`]: crate::prelude::LevelSelection
+#[derive(Clone, Debug, Default, Eq, PartialEq, Getters, Reflect, Asset)]
+pub struct LdtkLevel {
+    /// `iid` of the level.
+    iid: LevelIid,
+    /// User-defined identifier of the level.
+    identifier: String,
+}
+
+impl LdtkLevel {
+    /// Construct a new [`LdtkLevel`].
+    pub(crate) fn new(iid: LevelIid, identifier: String) -> LdtkLevel {
+        LdtkLevel { iid, identifier }
+    }
+}
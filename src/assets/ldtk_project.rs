@@ -1,13 +1,22 @@
-use std::{io, path::Path};
+use std::{borrow::Cow, io, path::Path};
 
 use crate::{
     assets::{
-        LdtkJsonWithMetadata, LdtkProjectData, LevelIndices, LevelMetadata, LevelMetadataAccessor,
+        LdtkJsonWithMetadata, LdtkLevel, LdtkProjectData, LevelIndices, LevelMetadata,
+        LevelMetadataAccessor,
     },
-    ldtk::{raw_level_accessor::RawLevelAccessor, LdtkJson, Level},
+    components::LevelIid,
+    ldtk::{
+        int_grid_value_defs::IntGridValueDefs, raw_level_accessor::RawLevelAccessor,
+        EntityDefinition, EntityInstance, EnumDefinition, LayerDefinition, LdtkJson,
+        LdtkTocInstanceData, Level, TilesetDefinition,
+    },
+    resources::LdtkLoadErrorKind,
 };
 use bevy::{
-    asset::{io::Reader, AssetLoader, AssetPath, AsyncReadExt, LoadContext},
+    asset::{
+        io::Reader, AssetLoader, AssetPath, AsyncReadExt, LoadContext, LoadState, UntypedAssetId,
+    },
     prelude::*,
     reflect::Reflect,
     utils::ConditionalSendFuture,
@@ -15,6 +24,7 @@ use bevy::{
 use derive_getters::Getters;
 use derive_more::From;
 use path_clean::PathClean;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -102,11 +112,121 @@ impl LdtkProject {
         }
     }
 
+    /// Construct an [`LdtkProject`] from in-memory LDtk json data, without the asset server.
+    ///
+    /// Useful for projects embedded with `include_bytes!` or downloaded at runtime.
+    /// The result can be added to `Assets<LdtkProject>` and spawned like any other project.
+    ///
+    /// Since there's no asset path to resolve tileset paths against, tileset images need to be
+    /// provided separately, keyed by tileset identifier.
+    /// This includes LDtk's built-in icons tileset, whose identifier is usually `Internal_Icons`.
+    /// Tilesets without a provided image are skipped when spawning, and level background images
+    /// are not loaded.
+    /// The image for rendering IntGrid colors is added to `images`.
+    ///
+    /// Only projects with internal levels are supported, since external level files can't be
+    /// loaded without the asset server.
+    #[cfg(feature = "internal_levels")]
+    pub fn from_bytes(
+        bytes: &[u8],
+        tileset_images: &HashMap<String, Handle<Image>>,
+        images: &mut Assets<Image>,
+    ) -> Result<LdtkProject, LdtkProjectLoaderError> {
+        let data: LdtkJson = serde_json::from_slice(bytes)?;
+
+        if data.external_levels {
+            Err(LdtkProjectLoaderError::ExternalLevelsFromBytes)?;
+        }
+
+        let tileset_map = data
+            .defs
+            .tilesets
+            .iter()
+            .filter_map(|tileset| {
+                tileset_images
+                    .get(&tileset.identifier)
+                    .map(|handle| (tileset.uid, handle.clone()))
+            })
+            .collect();
+
+        let int_grid_image_handle = data
+            .defs
+            .create_int_grid_image()
+            .map(|image| images.add(image));
+
+        let mut level_map = HashMap::new();
+
+        for (level_indices, level) in data.iter_raw_levels_with_indices() {
+            if level.layer_instances.is_none() {
+                Err(LdtkProjectLoaderError::InternalLevelWithNullLayers)?;
+            }
+
+            level_map.insert(level.iid.clone(), LevelMetadata::new(None, level_indices));
+        }
+
+        Ok(LdtkProject::new(
+            LdtkProjectData::Standalone(LdtkJsonWithMetadata::new(data, level_map)),
+            tileset_map,
+            int_grid_image_handle,
+        ))
+    }
+
     /// Raw ldtk json data.
     pub fn json_data(&self) -> &LdtkJson {
         self.data.json_data()
     }
 
+    /// Returns the layer definition with the given identifier.
+    pub fn get_layer_definition(&self, identifier: &str) -> Option<&LayerDefinition> {
+        self.json_data().defs.get_layer_definition(identifier)
+    }
+
+    /// Returns the entity definition with the given identifier.
+    pub fn get_entity_definition(&self, identifier: &str) -> Option<&EntityDefinition> {
+        self.json_data().defs.get_entity_definition(identifier)
+    }
+
+    /// Immutable access to the entity instance with the given iid, along with its level.
+    ///
+    /// This lookup is constant, but only finds entity instances of internal levels.
+    pub fn get_entity_instance_by_iid(&self, iid: &str) -> Option<(&Level, &EntityInstance)> {
+        self.data.get_entity_instance_by_iid(iid)
+    }
+
+    /// Returns the tileset definition with the given identifier.
+    pub fn get_tileset_definition(&self, identifier: &str) -> Option<&TilesetDefinition> {
+        self.json_data().defs.get_tileset_definition(identifier)
+    }
+
+    /// Returns the enum definition with the given identifier, including external enums.
+    pub fn get_enum_definition(&self, identifier: &str) -> Option<&EnumDefinition> {
+        self.json_data().defs.get_enum_definition(identifier)
+    }
+
+    /// Returns a lookup of the IntGrid values of every IntGrid layer by their editor-defined
+    /// identifiers and colors.
+    pub fn int_grid_value_defs(&self) -> IntGridValueDefs {
+        self.json_data().defs.int_grid_value_defs()
+    }
+
+    /// Returns every instance of the entity with the given identifier across the whole project,
+    /// from the project's table of contents.
+    ///
+    /// Only entities with "Add to table of content" enabled in LDtk (1.3+) are listed, along with
+    /// their size, world position, and the fields that are also flagged for the table of content.
+    /// This allows enumerating instances (e.g. for map screens or fast-travel lists) without
+    /// loading or spawning every level.
+    ///
+    /// Returns an empty slice if the entity is not in the table of contents.
+    pub fn toc_instances(&self, entity_identifier: &str) -> &[LdtkTocInstanceData] {
+        self.json_data()
+            .toc
+            .iter()
+            .find(|toc_entry| toc_entry.identifier == entity_identifier)
+            .map(|toc_entry| toc_entry.instances_data.as_slice())
+            .unwrap_or_default()
+    }
+
     /// Unwrap as a [`LdtkJsonWithMetadata<InternalLevels>`].
     /// For use on internal-levels ldtk projects only.
     ///
@@ -132,6 +252,130 @@ impl LdtkProject {
     pub fn as_parent(&self) -> &LdtkJsonWithMetadata<ExternalLevels> {
         self.data.as_parent()
     }
+
+    /// Ids of the assets this project depends on.
+    ///
+    /// These are tileset images, level background images, and external level files.
+    pub fn dependency_ids(&self) -> Vec<UntypedAssetId> {
+        let mut dependency_ids: Vec<UntypedAssetId> = self
+            .tileset_map
+            .values()
+            .map(|handle| handle.id().untyped())
+            .collect();
+
+        match &self.data {
+            #[cfg(feature = "internal_levels")]
+            LdtkProjectData::Standalone(project) => {
+                dependency_ids.extend(
+                    project
+                        .level_map()
+                        .values()
+                        .filter_map(|metadata| metadata.bg_image().as_ref())
+                        .map(|handle| handle.id().untyped()),
+                );
+            }
+            #[cfg(feature = "external_levels")]
+            LdtkProjectData::Parent(project) => {
+                for metadata in project.level_map().values() {
+                    dependency_ids.extend(
+                        metadata
+                            .metadata()
+                            .bg_image()
+                            .as_ref()
+                            .map(|handle| handle.id().untyped()),
+                    );
+                    dependency_ids.push(metadata.external_handle().id().untyped());
+                }
+            }
+        }
+
+        dependency_ids
+    }
+
+    /// Describes what the given dependency is used for, for reporting [`LdtkLoadError`]s.
+    ///
+    /// Returns `None` if the asset isn't one of this project's [dependencies].
+    ///
+    /// [`LdtkLoadError`]: crate::resources::LdtkLoadError
+    /// [dependencies]: LdtkProject::dependency_ids
+    pub(crate) fn dependency_kind(&self, id: UntypedAssetId) -> Option<LdtkLoadErrorKind> {
+        if let Some((uid, _)) = self
+            .tileset_map
+            .iter()
+            .find(|(_, handle)| handle.id().untyped() == id)
+        {
+            let defs = &self.json_data().defs;
+
+            return Some(LdtkLoadErrorKind::Tileset {
+                identifier: defs
+                    .tilesets
+                    .iter()
+                    .find(|tileset_definition| tileset_definition.uid == *uid)
+                    .map(|tileset_definition| tileset_definition.identifier.clone())
+                    .unwrap_or_default(),
+                layer_identifiers: defs
+                    .layers
+                    .iter()
+                    .filter(|layer_definition| layer_definition.tileset_def_uid == Some(*uid))
+                    .map(|layer_definition| layer_definition.identifier.clone())
+                    .collect(),
+            });
+        }
+
+        let is_bg_image = |metadata: &LevelMetadata| {
+            metadata
+                .bg_image()
+                .as_ref()
+                .is_some_and(|handle| handle.id().untyped() == id)
+        };
+
+        match &self.data {
+            #[cfg(feature = "internal_levels")]
+            LdtkProjectData::Standalone(project) => project
+                .level_map()
+                .iter()
+                .find(|(_, metadata)| is_bg_image(metadata))
+                .map(|(iid, _)| LdtkLoadErrorKind::LevelBackground {
+                    level_iid: LevelIid::new(iid.clone()),
+                }),
+            #[cfg(feature = "external_levels")]
+            LdtkProjectData::Parent(project) => {
+                project.level_map().iter().find_map(|(iid, metadata)| {
+                    let level_iid = LevelIid::new(iid.clone());
+
+                    if is_bg_image(metadata.metadata()) {
+                        Some(LdtkLoadErrorKind::LevelBackground { level_iid })
+                    } else if metadata.external_handle().id().untyped() == id {
+                        Some(LdtkLoadErrorKind::ExternalLevel { level_iid })
+                    } else {
+                        None
+                    }
+                })
+            }
+        }
+    }
+
+    /// Returns true if every asset this project depends on has finished loading.
+    ///
+    /// See [`LdtkProject::dependency_ids`] for the assets that are checked.
+    /// Assets that aren't tracked by the [`AssetServer`] (e.g., added to their [`Assets`]
+    /// collection directly) are considered loaded.
+    ///
+    /// This is equivalent to the project's [`RecursiveDependencyLoadState`] being `Loaded`, but
+    /// only requires access to the project itself, which is convenient for state-based loading
+    /// flows that need to wait for tileset images before entering gameplay.
+    ///
+    /// [`AssetServer`]: https://docs.rs/bevy/latest/bevy/asset/struct.AssetServer.html
+    /// [`Assets`]: https://docs.rs/bevy/latest/bevy/asset/struct.Assets.html
+    /// [`RecursiveDependencyLoadState`]: https://docs.rs/bevy/latest/bevy/asset/enum.RecursiveDependencyLoadState.html
+    pub fn is_fully_loaded(&self, asset_server: &AssetServer) -> bool {
+        self.dependency_ids().into_iter().all(|dependency_id| {
+            matches!(
+                asset_server.get_load_state(dependency_id),
+                Some(LoadState::Loaded) | None
+            )
+        })
+    }
 }
 
 impl RawLevelAccessor for LdtkProject {
@@ -172,24 +416,117 @@ pub enum LdtkProjectLoaderError {
     /// LDtk project uses external levels, but some level's `external_rel_path` is null.
     #[error("LDtk project uses external levels, but some level's external_rel_path is null")]
     ExternalLevelWithNullPath,
+    /// LDtk projects with external levels can't be constructed from bytes.
+    #[error("LDtk projects with external levels can't be constructed from bytes")]
+    ExternalLevelsFromBytes,
 }
 
 /// AssetLoader for [`LdtkProject`].
 #[derive(Default)]
 pub struct LdtkProjectLoader;
 
+/// Settings for loading an [`LdtkProject`] with [`AssetServer::load_with_settings`].
+///
+/// [`AssetServer::load_with_settings`]: https://docs.rs/bevy/latest/bevy/asset/struct.AssetServer.html#method.load_with_settings
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct LdtkProjectLoaderSettings {
+    /// Asset path of the image to use for LDtk's built-in icons tileset (`Internal_Icons`).
+    ///
+    /// These icons can't be distributed with this plugin due to their license.
+    /// However, the sheet is included with LDtk itself (e.g. `finalbossblues-icons_full_16.png`
+    /// in LDtk's installation files), so you may copy it into your assets and provide its path
+    /// here, relative to the assets folder.
+    /// If this is `None`, entities using these icons are spawned without a texture.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_ldtk::{assets::LdtkProjectLoaderSettings, prelude::*};
+    ///
+    /// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    ///     commands.spawn(LdtkWorldBundle {
+    ///         ldtk_handle: asset_server.load_with_settings(
+    ///             "my_project.ldtk",
+    ///             |settings: &mut LdtkProjectLoaderSettings| {
+    ///                 settings.internal_icons_path =
+    ///                     Some("ldtk/finalbossblues-icons_full_16.png".to_string());
+    ///             },
+    ///         ),
+    ///         ..default()
+    ///     });
+    /// }
+    /// ```
+    pub internal_icons_path: Option<String>,
+    /// Replacements for the file extensions of tileset images, from LDtk's extension to the one
+    /// that should be loaded instead.
+    ///
+    /// LDtk can use non-PNG files as tilesets, such as `.aseprite` files.
+    /// If you have registered an asset loader that produces an [`Image`] for these files, they
+    /// can be loaded as-is.
+    /// Otherwise, you may export them to an image format that bevy supports alongside the source
+    /// file, and remap the extension here (e.g. `"aseprite"` to `"png"`).
+    pub tileset_extensions: HashMap<String, String>,
+    /// Directory, relative to the assets folder, that tileset and level background image paths
+    /// are resolved against.
+    ///
+    /// LDtk stores these paths relative to the project file, so by default they are resolved
+    /// against the project's directory.
+    /// This can be used if the images are kept somewhere else in your assets, e.g. after
+    /// reorganizing them or processing them with an asset pipeline.
+    pub image_directory: Option<String>,
+}
+
+impl LdtkProjectLoaderSettings {
+    /// Applies [`LdtkProjectLoaderSettings::tileset_extensions`] to a tileset's relative path.
+    #[cfg_attr(not(feature = "render"), allow(dead_code))]
+    fn tileset_rel_path<'a>(&self, rel_path: &'a str) -> Cow<'a, str> {
+        Path::new(rel_path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| self.tileset_extensions.get(extension))
+            .map(|replacement| {
+                Path::new(rel_path)
+                    .with_extension(replacement)
+                    .to_string_lossy()
+                    .into_owned()
+                    .into()
+            })
+            .unwrap_or(Cow::Borrowed(rel_path))
+    }
+
+    /// Resolves the asset path of an image referenced by the project at `project_path`,
+    /// according to [`LdtkProjectLoaderSettings::image_directory`].
+    #[cfg_attr(not(feature = "render"), allow(dead_code))]
+    fn image_asset_path<'b>(&self, project_path: &Path, rel_path: &str) -> AssetPath<'b> {
+        match &self.image_directory {
+            Some(image_directory) => {
+                // ldtk_path_to_asset_path resolves against the parent of the given path
+                ldtk_path_to_asset_path(&Path::new(image_directory).join("_"), rel_path)
+            }
+            None => ldtk_path_to_asset_path(project_path, rel_path),
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "render"), allow(unused_variables))]
 fn load_level_metadata(
     load_context: &mut LoadContext,
+    settings: &LdtkProjectLoaderSettings,
     level_indices: LevelIndices,
     level: &Level,
     expect_level_loaded: bool,
 ) -> Result<LevelMetadata, LdtkProjectLoaderError> {
+    // Images are only needed for rendering, so headless apps skip loading them.
+    #[cfg(feature = "render")]
     let bg_image = level.bg_rel_path.as_ref().map(|rel_path| {
-        let asset_path = ldtk_path_to_asset_path(load_context.path(), rel_path);
+        let asset_path = settings.image_asset_path(load_context.path(), rel_path);
 
         load_context.load(asset_path)
     });
 
+    #[cfg(not(feature = "render"))]
+    let bg_image = None;
+
     if expect_level_loaded && level.layer_instances.is_none() {
         Err(LdtkProjectLoaderError::InternalLevelWithNullLayers)?;
     }
@@ -202,10 +539,11 @@ fn load_level_metadata(
 #[cfg(feature = "external_levels")]
 fn load_external_level_metadata(
     load_context: &mut LoadContext,
+    settings: &LdtkProjectLoaderSettings,
     level_indices: LevelIndices,
     level: &Level,
 ) -> Result<ExternalLevelMetadata, LdtkProjectLoaderError> {
-    let level_metadata = load_level_metadata(load_context, level_indices, level, false)?;
+    let level_metadata = load_level_metadata(load_context, settings, level_indices, level, false)?;
 
     let external_level_path = ldtk_path_to_asset_path(
         load_context.path(),
@@ -222,13 +560,13 @@ fn load_external_level_metadata(
 
 impl AssetLoader for LdtkProjectLoader {
     type Asset = LdtkProject;
-    type Settings = ();
+    type Settings = LdtkProjectLoaderSettings;
     type Error = LdtkProjectLoaderError;
 
     fn load<'a>(
         &'a self,
         reader: &'a mut Reader,
-        _settings: &'a Self::Settings,
+        settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> impl ConditionalSendFuture<
         Output = Result<<Self as AssetLoader>::Asset, <Self as AssetLoader>::Error>,
@@ -238,24 +576,51 @@ impl AssetLoader for LdtkProjectLoader {
             reader.read_to_end(&mut bytes).await?;
             let data: LdtkJson = serde_json::from_slice(&bytes)?;
 
-            let mut tileset_map: HashMap<i32, Handle<Image>> = HashMap::new();
-            for tileset in &data.defs.tilesets {
-                if let Some(tileset_path) = &tileset.rel_path {
-                    let asset_path = ldtk_path_to_asset_path(load_context.path(), tileset_path);
-
-                    tileset_map.insert(tileset.uid, load_context.load(asset_path));
-                } else if tileset.embed_atlas.is_some() {
-                    warn!("Ignoring LDtk's Internal_Icons. They cannot be displayed due to their license.");
-                } else {
-                    let identifier = &tileset.identifier;
-                    warn!("{identifier} tileset cannot be loaded, it has a null relative path.");
+            #[cfg(feature = "render")]
+            let (tileset_map, int_grid_image_handle) = {
+                let mut tileset_map: HashMap<i32, Handle<Image>> = HashMap::new();
+                for tileset in &data.defs.tilesets {
+                    if let Some(tileset_path) = &tileset.rel_path {
+                        let asset_path = settings.image_asset_path(
+                            load_context.path(),
+                            &settings.tileset_rel_path(tileset_path),
+                        );
+
+                        tileset_map.insert(tileset.uid, load_context.load(asset_path));
+                    } else if tileset.embed_atlas.is_some() {
+                        match &settings.internal_icons_path {
+                            Some(internal_icons_path) => {
+                                tileset_map.insert(
+                                    tileset.uid,
+                                    load_context.load(internal_icons_path.clone()),
+                                );
+                            }
+                            None => warn!("Ignoring LDtk's Internal_Icons. They cannot be distributed with this plugin due to their license, but you may provide them with LdtkProjectLoaderSettings::internal_icons_path."),
+                        }
+                    } else {
+                        let identifier = &tileset.identifier;
+                        warn!(
+                            "{identifier} tileset cannot be loaded, it has a null relative path."
+                        );
+                    }
                 }
-            }
 
-            let int_grid_image_handle = data
-                .defs
-                .create_int_grid_image()
-                .map(|image| load_context.add_labeled_asset("int_grid_image".to_string(), image));
+                let int_grid_image_handle = data.defs.create_int_grid_image().map(|image| {
+                    load_context.add_labeled_asset("int_grid_image".to_string(), image)
+                });
+
+                (tileset_map, int_grid_image_handle)
+            };
+
+            #[cfg(not(feature = "render"))]
+            let (tileset_map, int_grid_image_handle) = (HashMap::new(), None);
+
+            for level in data.iter_raw_levels() {
+                load_context.add_labeled_asset(
+                    level.identifier.clone(),
+                    LdtkLevel::new(LevelIid::new(level.iid.clone()), level.identifier.clone()),
+                );
+            }
 
             let ldtk_project = if data.external_levels {
                 #[cfg(feature = "external_levels")]
@@ -263,8 +628,12 @@ impl AssetLoader for LdtkProjectLoader {
                     let mut level_map = HashMap::new();
 
                     for (level_indices, level) in data.iter_raw_levels_with_indices() {
-                        let level_metadata =
-                            load_external_level_metadata(load_context, level_indices, level)?;
+                        let level_metadata = load_external_level_metadata(
+                            load_context,
+                            settings,
+                            level_indices,
+                            level,
+                        )?;
 
                         level_map.insert(level.iid.clone(), level_metadata);
                     }
@@ -286,8 +655,13 @@ impl AssetLoader for LdtkProjectLoader {
                     let mut level_map = HashMap::new();
 
                     for (level_indices, level) in data.iter_raw_levels_with_indices() {
-                        let level_metadata =
-                            load_level_metadata(load_context, level_indices, level, true)?;
+                        let level_metadata = load_level_metadata(
+                            load_context,
+                            settings,
+                            level_indices,
+                            level,
+                            true,
+                        )?;
 
                         level_map.insert(level.iid.clone(), level_metadata);
                     }
@@ -372,6 +746,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remaps_tileset_extensions() {
+        let settings = LdtkProjectLoaderSettings {
+            tileset_extensions: HashMap::from([("aseprite".to_string(), "png".to_string())]),
+            ..default()
+        };
+
+        assert_eq!(
+            settings.tileset_rel_path("images/tiles.aseprite"),
+            "images/tiles.png"
+        );
+        assert_eq!(
+            settings.tileset_rel_path("images/tiles.png"),
+            "images/tiles.png"
+        );
+        assert_eq!(settings.tileset_rel_path("images/tiles"), "images/tiles");
+    }
+
+    #[test]
+    fn resolves_images_against_image_directory() {
+        let settings = LdtkProjectLoaderSettings {
+            image_directory: Some("processed/images".to_string()),
+            ..default()
+        };
+
+        let resolve_path = |project_path, rel_path| {
+            let asset_path = settings.image_asset_path(Path::new(project_path), rel_path);
+            asset_path.path().to_owned()
+        };
+
+        assert_eq!(
+            resolve_path("levels/project.ldtk", "tiles.png"),
+            Path::new("processed/images/tiles.png")
+        );
+        assert_eq!(
+            resolve_path("levels/project.ldtk", "../bg/sky.png"),
+            Path::new("processed/bg/sky.png")
+        );
+
+        let default_settings = LdtkProjectLoaderSettings::default();
+        assert_eq!(
+            default_settings
+                .image_asset_path(Path::new("levels/project.ldtk"), "tiles.png")
+                .path(),
+            Path::new("levels/tiles.png")
+        );
+    }
+
     #[cfg(target_os = "windows")]
     #[test]
     fn normalizes_windows_asset_paths() {
@@ -415,6 +837,116 @@ mod tests {
             }
         }
 
+        #[test]
+        fn project_from_bytes_uses_provided_tileset_images() {
+            let data = LdtkJson {
+                defs: crate::ldtk::Definitions {
+                    layers: vec![
+                        LayerDefinition {
+                            identifier: "Ground".to_string(),
+                            tileset_def_uid: Some(1),
+                            ..default()
+                        },
+                        LayerDefinition {
+                            identifier: "Entities".to_string(),
+                            ..default()
+                        },
+                    ],
+                    tilesets: vec![
+                        TilesetDefinition {
+                            identifier: "Provided".to_string(),
+                            uid: 1,
+                            ..default()
+                        },
+                        TilesetDefinition {
+                            identifier: "Missing".to_string(),
+                            uid: 2,
+                            ..default()
+                        },
+                    ],
+                    ..default()
+                },
+                levels: vec![Level {
+                    iid: "level".to_string(),
+                    layer_instances: Some(Vec::new()),
+                    ..default()
+                }],
+                ..default()
+            };
+
+            let tileset_image = Handle::weak_from_u128(Faker.fake());
+            let tileset_images = HashMap::from([("Provided".to_string(), tileset_image.clone())]);
+
+            let project = LdtkProject::from_bytes(
+                &serde_json::to_vec(&data).unwrap(),
+                &tileset_images,
+                &mut Assets::default(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                project.tileset_map(),
+                &HashMap::from([(1, tileset_image.clone())])
+            );
+            assert_eq!(
+                project.dependency_kind(tileset_image.id().untyped()),
+                Some(LdtkLoadErrorKind::Tileset {
+                    identifier: "Provided".to_string(),
+                    layer_identifiers: vec!["Ground".to_string()],
+                })
+            );
+            assert_eq!(
+                project
+                    .dependency_kind(Handle::<Image>::weak_from_u128(Faker.fake()).id().untyped()),
+                None
+            );
+            assert!(project
+                .get_level_metadata_by_iid(&"level".to_string())
+                .is_some());
+
+            let data = LdtkJson {
+                levels: vec![Level::default()],
+                ..default()
+            };
+
+            assert!(matches!(
+                LdtkProject::from_bytes(
+                    &serde_json::to_vec(&data).unwrap(),
+                    &tileset_images,
+                    &mut Assets::default(),
+                ),
+                Err(LdtkProjectLoaderError::InternalLevelWithNullLayers)
+            ));
+        }
+
+        #[test]
+        fn toc_instances_are_found_by_identifier() {
+            let toc_instance = LdtkTocInstanceData {
+                world_x: 32,
+                world_y: 64,
+                ..default()
+            };
+
+            let data = LdtkJson {
+                toc: vec![crate::ldtk::LdtkTableOfContentEntry {
+                    identifier: "Checkpoint".to_string(),
+                    instances: None,
+                    instances_data: vec![toc_instance.clone()],
+                }],
+                ..default()
+            };
+
+            let project = LdtkProject::from_bytes(
+                &serde_json::to_vec(&data).unwrap(),
+                &HashMap::new(),
+                &mut Assets::default(),
+            )
+            .unwrap();
+
+            assert_eq!(project.toc_instances("Checkpoint"), &[toc_instance]);
+            assert!(project.toc_instances("Door").is_empty());
+        }
+
         #[test]
         fn json_data_accessor_is_transparent() {
             let project: LdtkProject = InternalLevels.fake();
@@ -1,6 +1,6 @@
 use crate::{
     assets::{LdtkJsonWithMetadata, LevelMetadata, LevelMetadataAccessor},
-    ldtk::{LdtkJson, Level},
+    ldtk::{EntityInstance, LdtkJson, Level},
     prelude::RawLevelAccessor,
 };
 use bevy::reflect::Reflect;
@@ -53,6 +53,18 @@ impl LdtkProjectData {
         }
     }
 
+    /// Immutable access to the entity instance with the given iid, along with its level.
+    ///
+    /// See [`LdtkJsonWithMetadata::get_entity_instance_by_iid`] for more details.
+    pub fn get_entity_instance_by_iid(&self, iid: &str) -> Option<(&Level, &EntityInstance)> {
+        match self {
+            #[cfg(feature = "internal_levels")]
+            LdtkProjectData::Standalone(project) => project.get_entity_instance_by_iid(iid),
+            #[cfg(feature = "external_levels")]
+            LdtkProjectData::Parent(project) => project.get_entity_instance_by_iid(iid),
+        }
+    }
+
     /// Unwrap as a [`LdtkJsonWithMetadata<InternalLevels>`].
     /// For use on internal-levels ldtk projects only.
     ///
@@ -40,9 +40,9 @@ pub trait LevelMetadataAccessor: RawLevelAccessor {
         match level_selection {
             LevelSelection::Iid(iid) => self.get_raw_level_by_iid(iid.get()),
             LevelSelection::Indices(indices) => self.get_raw_level_at_indices(indices),
-            LevelSelection::Identifier(selected_identifier) => self
-                .iter_raw_levels()
-                .find(|Level { identifier, .. }| identifier == selected_identifier),
+            LevelSelection::Identifier(selected_identifier) => {
+                self.find_raw_level_by_identifier(selected_identifier)
+            }
             LevelSelection::Uid(selected_uid) => self
                 .iter_raw_levels()
                 .find(|Level { uid, .. }| uid == selected_uid),
@@ -26,6 +26,9 @@ mod ldtk_external_level;
 #[cfg(feature = "external_levels")]
 pub use ldtk_external_level::LdtkExternalLevel;
 
+mod ldtk_level;
+pub use ldtk_level::LdtkLevel;
+
 mod ldtk_json_with_metadata;
 pub use ldtk_json_with_metadata::LdtkJsonWithMetadata;
 
@@ -33,7 +36,13 @@ mod ldtk_project_data;
 pub use ldtk_project_data::LdtkProjectData;
 
 mod ldtk_project;
-pub use ldtk_project::LdtkProject;
+pub use ldtk_project::{LdtkProject, LdtkProjectLoaderSettings};
 
 mod level_indices;
 pub use level_indices::LevelIndices;
+
+#[cfg(feature = "save")]
+mod save;
+
+#[cfg(feature = "save")]
+pub use save::LdtkSaveError;
@@ -0,0 +1,151 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    assets::LdtkProject,
+    components::LevelIid,
+    ldtk::{LdtkJson, Level},
+    resources::LevelPatches,
+};
+use thiserror::Error;
+
+#[cfg(feature = "external_levels")]
+use crate::assets::LdtkExternalLevel;
+
+/// Errors that can occur when saving LDtk data to disk.
+///
+/// Requires the `save` feature to be enabled.
+#[derive(Debug, Error)]
+pub enum LdtkSaveError {
+    /// Encountered IO error writing LDtk file
+    #[error("encountered IO error writing LDtk file: {0}")]
+    Io(#[from] io::Error),
+    /// Unable to serialize LDtk data
+    #[error("unable to serialize LDtk data: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+fn apply_level_patches(level: &mut Level, level_patches: &LevelPatches) {
+    if let Some(patch) = level_patches.get(&LevelIid::new(level.iid.clone())) {
+        patch.apply_to_level(level);
+    }
+}
+
+impl LdtkProject {
+    /// Serializes the project data to LDtk JSON, with the given [`LevelPatches`] applied.
+    ///
+    /// Patches are applied to the IntGrid and entity layers of levels stored in this project.
+    /// For projects with external levels, the level files must be saved separately with
+    /// [`LdtkExternalLevel::save`].
+    ///
+    /// Note that the `autoLayerTiles` baked into the project by LDtk are left untouched, so LDtk
+    /// will recompute them when the saved file is opened in the editor.
+    ///
+    /// Requires the `save` feature to be enabled.
+    pub fn to_json_string(
+        &self,
+        level_patches: &LevelPatches,
+    ) -> Result<String, serde_json::Error> {
+        let mut json_data: LdtkJson = self.json_data().clone();
+
+        json_data
+            .levels
+            .iter_mut()
+            .chain(
+                json_data
+                    .worlds
+                    .iter_mut()
+                    .flat_map(|world| world.levels.iter_mut()),
+            )
+            .for_each(|level| apply_level_patches(level, level_patches));
+
+        serde_json::to_string_pretty(&json_data)
+    }
+
+    /// Writes the project data to an `.ldtk` file, with the given [`LevelPatches`] applied.
+    ///
+    /// See [`LdtkProject::to_json_string`] for more details.
+    ///
+    /// Requires the `save` feature to be enabled.
+    pub fn save(
+        &self,
+        path: impl AsRef<Path>,
+        level_patches: &LevelPatches,
+    ) -> Result<(), LdtkSaveError> {
+        fs::write(path, self.to_json_string(level_patches)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "external_levels")]
+impl LdtkExternalLevel {
+    /// Serializes the level data to LDtk JSON, with the given [`LevelPatches`] applied.
+    ///
+    /// Requires the `save` and `external_levels` features to be enabled.
+    pub fn to_json_string(
+        &self,
+        level_patches: &LevelPatches,
+    ) -> Result<String, serde_json::Error> {
+        let mut level = self.data().raw().clone();
+        apply_level_patches(&mut level, level_patches);
+
+        serde_json::to_string_pretty(&level)
+    }
+
+    /// Writes the level data to an `.ldtkl` file, with the given [`LevelPatches`] applied.
+    ///
+    /// Requires the `save` and `external_levels` features to be enabled.
+    pub fn save(
+        &self,
+        path: impl AsRef<Path>,
+        level_patches: &LevelPatches,
+    ) -> Result<(), LdtkSaveError> {
+        fs::write(path, self.to_json_string(level_patches)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::GridCoords, ldtk::LayerInstance};
+
+    #[test]
+    fn patches_are_applied_to_matching_levels_only() {
+        let layer_instance = LayerInstance {
+            identifier: "Walls".to_string(),
+            c_wid: 2,
+            c_hei: 1,
+            int_grid_csv: vec![0, 0],
+            ..Default::default()
+        };
+
+        let mut patched_level = Level {
+            iid: "patched".to_string(),
+            layer_instances: Some(vec![layer_instance.clone()]),
+            ..Default::default()
+        };
+
+        let mut other_level = Level {
+            iid: "other".to_string(),
+            layer_instances: Some(vec![layer_instance]),
+            ..Default::default()
+        };
+
+        let mut level_patches = LevelPatches::default();
+        level_patches
+            .get_or_default(&LevelIid::new("patched"))
+            .set_int_grid_cell("Walls", GridCoords::new(1, 0), 3);
+
+        apply_level_patches(&mut patched_level, &level_patches);
+        apply_level_patches(&mut other_level, &level_patches);
+
+        assert_eq!(
+            patched_level.layer_instances.unwrap()[0].int_grid_csv,
+            vec![0, 3]
+        );
+        assert_eq!(
+            other_level.layer_instances.unwrap()[0].int_grid_csv,
+            vec![0, 0]
+        );
+    }
+}
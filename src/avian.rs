@@ -0,0 +1,362 @@
+//! Provides [LdtkAvianAppExt] for generating static [avian2d] colliders from IntGrid values and
+//! tile enum tags.
+//!
+//! Requires the `avian` feature to be enabled.
+use crate::components::{GridCoords, IntGridCell, LayerMetadata, TileEnumTags};
+use avian2d::prelude::{Collider, RigidBody};
+use bevy::prelude::*;
+use bevy_ecs_tilemap::tiles::TilePos;
+use std::collections::{HashMap, HashSet};
+
+/// [Component] marking the collider entities spawned for [LdtkAvianAppExt] registrations.
+///
+/// These are spawned as children of the layer entity their tiles belong to, so they are despawned
+/// along with their level.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LdtkStaticCollider;
+
+/// Stores the IntGrid values and tile enum tags that colliders are generated for.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Resource)]
+struct StaticColliders {
+    int_grid_values: HashMap<String, HashSet<i32>>,
+    tile_enum_tags: HashSet<String>,
+}
+
+impl StaticColliders {
+    fn is_solid(
+        &self,
+        layer_metadata: &LayerMetadata,
+        int_grid_cell: Option<&IntGridCell>,
+        tile_enum_tags: Option<&TileEnumTags>,
+    ) -> bool {
+        let solid_int_grid_value = int_grid_cell.is_some_and(|int_grid_cell| {
+            self.int_grid_values
+                .get(&layer_metadata.identifier)
+                .is_some_and(|values| values.contains(&int_grid_cell.value))
+        });
+
+        let solid_enum_tag = tile_enum_tags.is_some_and(|tile_enum_tags| {
+            tile_enum_tags
+                .tags
+                .iter()
+                .any(|tag| self.tile_enum_tags.contains(tag))
+        });
+
+        solid_int_grid_value || solid_enum_tag
+    }
+}
+
+/// Inclusive rectangle of grid cells.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct GridRect {
+    min: GridCoords,
+    max: GridCoords,
+}
+
+/// Merges cells into a small number of rectangles covering exactly the same cells.
+///
+/// Cells are first combined into horizontal runs in each row, and then runs with the same extent
+/// in consecutive rows are combined into rectangles.
+fn merge_cells_into_rects(cells: &HashSet<GridCoords>) -> Vec<GridRect> {
+    let mut rows: HashMap<i32, Vec<i32>> = HashMap::new();
+    for cell in cells {
+        rows.entry(cell.y).or_default().push(cell.x);
+    }
+
+    let mut row_indices: Vec<i32> = rows.keys().copied().collect();
+    row_indices.sort_unstable();
+
+    let mut open_rects: HashMap<(i32, i32), GridRect> = HashMap::new();
+    let mut rects = Vec::new();
+
+    for y in row_indices {
+        let xs = rows.get_mut(&y).expect("row should exist");
+        xs.sort_unstable();
+
+        let mut runs = Vec::new();
+        let mut run_start = xs[0];
+        for window in xs.windows(2) {
+            if window[1] != window[0] + 1 {
+                runs.push((run_start, window[0]));
+                run_start = window[1];
+            }
+        }
+        runs.push((run_start, *xs.last().expect("row should have cells")));
+
+        // Rectangles that don't continue into this row are finished
+        let (continued, finished): (HashMap<_, _>, HashMap<_, _>) = open_rects
+            .drain()
+            .partition(|(run, rect)| rect.max.y == y - 1 && runs.contains(run));
+        rects.extend(finished.into_values());
+        open_rects = continued;
+
+        for run in runs {
+            open_rects
+                .entry(run)
+                .and_modify(|rect| rect.max.y = y)
+                .or_insert(GridRect {
+                    min: GridCoords::new(run.0, y),
+                    max: GridCoords::new(run.1, y),
+                });
+        }
+    }
+
+    rects.extend(open_rects.into_values());
+    rects
+}
+
+/// Rebuilds the merged colliders of layers whose tiles may have changed, according to the
+/// [StaticColliders] registrations.
+///
+/// Whenever a tile of a layer is added, changed or removed, all of the layer's colliders are
+/// despawned and generated again from its current tiles.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn rebuild_static_colliders(
+    mut commands: Commands,
+    static_colliders: Res<StaticColliders>,
+    changed_tile_query: Query<&Parent, Or<(Changed<IntGridCell>, Changed<TileEnumTags>)>>,
+    mut removed_int_grid_cells: RemovedComponents<IntGridCell>,
+    mut removed_tile_enum_tags: RemovedComponents<TileEnumTags>,
+    collider_query: Query<(Entity, &Parent), With<LdtkStaticCollider>>,
+    layer_query: Query<(&LayerMetadata, &Children)>,
+    tile_query: Query<(&TilePos, Option<&IntGridCell>, Option<&TileEnumTags>)>,
+) {
+    let mut dirty_layers: HashSet<Entity> = changed_tile_query.iter().map(Parent::get).collect();
+
+    // Removed tiles can't be traced back to their layer, but removing tiles can only remove
+    // colliders, so only layers that already have colliders need to be rebuilt.
+    let removed_int_grid_cells = removed_int_grid_cells.read().count();
+    let removed_tile_enum_tags = removed_tile_enum_tags.read().count();
+    if removed_int_grid_cells + removed_tile_enum_tags > 0 {
+        dirty_layers.extend(collider_query.iter().map(|(_, parent)| parent.get()));
+    }
+
+    for (collider_entity, parent) in collider_query.iter() {
+        if dirty_layers.contains(&parent.get()) {
+            commands.entity(collider_entity).despawn_recursive();
+        }
+    }
+
+    for layer_entity in dirty_layers {
+        let Ok((layer_metadata, children)) = layer_query.get(layer_entity) else {
+            continue;
+        };
+
+        let solid_cells: HashSet<GridCoords> = children
+            .iter()
+            .filter_map(|child| tile_query.get(*child).ok())
+            .filter(|(_, int_grid_cell, tile_enum_tags)| {
+                static_colliders.is_solid(layer_metadata, *int_grid_cell, *tile_enum_tags)
+            })
+            .map(|(tile_pos, ..)| GridCoords::from(*tile_pos))
+            .collect();
+
+        if solid_cells.is_empty() {
+            continue;
+        }
+
+        let grid_size = layer_metadata.grid_size as f32;
+
+        commands.entity(layer_entity).with_children(|layer| {
+            for rect in merge_cells_into_rects(&solid_cells) {
+                let size = Vec2::new(
+                    (rect.max.x - rect.min.x + 1) as f32,
+                    (rect.max.y - rect.min.y + 1) as f32,
+                ) * grid_size;
+
+                // Tiles are centered on their grid coordinates in their layer's space
+                let center = Vec2::new(
+                    (rect.min.x + rect.max.x) as f32,
+                    (rect.min.y + rect.max.y) as f32,
+                ) * grid_size
+                    / 2.;
+
+                layer.spawn((
+                    LdtkStaticCollider,
+                    RigidBody::Static,
+                    Collider::rectangle(size.x, size.y),
+                    TransformBundle::from_transform(Transform::from_translation(center.extend(0.))),
+                ));
+            }
+        });
+    }
+}
+
+/// [App]: bevy::prelude::App
+///
+/// Provides functions to register IntGrid values and tile enum tags that should get static
+/// [avian2d] colliders.
+///
+/// Colliders of adjacent tiles in the same layer are merged into rectangles, to minimize the
+/// number of colliders.
+/// They are spawned as children of the layer entity with the [LdtkStaticCollider] marker, and are
+/// rebuilt whenever tiles in the layer are added, changed or removed, e.g. with
+/// [`LdtkCommandsExt::set_int_grid_cell`].
+///
+/// Not intended for custom implementations on your own types.
+///
+/// Requires the `avian` feature to be enabled.
+///
+/// [`LdtkCommandsExt::set_int_grid_cell`]: crate::prelude::LdtkCommandsExt::set_int_grid_cell
+///
+/// ```no_run
+/// use avian2d::prelude::*;
+/// use bevy::prelude::*;
+/// use bevy_ecs_ldtk::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default(), LdtkPlugin))
+///         .register_int_grid_colliders("Walls", [1, 3])
+///         .register_tile_enum_tag_colliders("Solid")
+///         // add other systems, plugins, resources...
+///         .run();
+/// }
+/// ```
+pub trait LdtkAvianAppExt {
+    /// Generates static colliders for tiles in IntGrid layers with the given identifier that have
+    /// any of the given values.
+    fn register_int_grid_colliders(
+        &mut self,
+        layer_identifier: &str,
+        values: impl IntoIterator<Item = i32>,
+    ) -> &mut Self;
+
+    /// Generates static colliders for tiles tagged with the given enum value in their tileset.
+    fn register_tile_enum_tag_colliders(&mut self, enum_tag: &str) -> &mut Self;
+}
+
+fn static_colliders_mut(app: &mut App) -> Mut<'_, StaticColliders> {
+    if !app.world().contains_resource::<StaticColliders>() {
+        app.init_resource::<StaticColliders>()
+            .register_type::<LdtkStaticCollider>()
+            .add_systems(PostUpdate, rebuild_static_colliders);
+    }
+
+    app.world_mut().resource_mut::<StaticColliders>()
+}
+
+impl LdtkAvianAppExt for App {
+    fn register_int_grid_colliders(
+        &mut self,
+        layer_identifier: &str,
+        values: impl IntoIterator<Item = i32>,
+    ) -> &mut Self {
+        static_colliders_mut(self)
+            .int_grid_values
+            .entry(layer_identifier.to_string())
+            .or_default()
+            .extend(values);
+
+        self
+    }
+
+    fn register_tile_enum_tag_colliders(&mut self, enum_tag: &str) -> &mut Self {
+        static_colliders_mut(self)
+            .tile_enum_tags
+            .insert(enum_tag.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn covered_cells(rects: &[GridRect]) -> Vec<GridCoords> {
+        let mut cells: Vec<GridCoords> = rects
+            .iter()
+            .flat_map(|rect| {
+                (rect.min.y..=rect.max.y).flat_map(move |y| {
+                    (rect.min.x..=rect.max.x).map(move |x| GridCoords::new(x, y))
+                })
+            })
+            .collect();
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+        cells
+    }
+
+    #[test]
+    fn cells_are_merged_into_rects() {
+        // ###.
+        // ###.
+        // #..#
+        let cells: HashSet<GridCoords> = [
+            (0, 0),
+            (3, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ]
+        .into_iter()
+        .map(|(x, y)| GridCoords::new(x, y))
+        .collect();
+
+        let rects = merge_cells_into_rects(&cells);
+
+        assert_eq!(rects.len(), 3);
+        assert!(rects.contains(&GridRect {
+            min: GridCoords::new(0, 1),
+            max: GridCoords::new(2, 2),
+        }));
+
+        let mut expected: Vec<GridCoords> = cells.into_iter().collect();
+        expected.sort_by_key(|cell| (cell.y, cell.x));
+        assert_eq!(covered_cells(&rects), expected);
+    }
+
+    #[test]
+    fn colliders_are_rebuilt_when_tiles_change() {
+        let mut app = App::new();
+        app.add_plugins(HierarchyPlugin)
+            .register_int_grid_colliders("Walls", [1]);
+
+        let layer = app
+            .world_mut()
+            .spawn(LayerMetadata {
+                identifier: "Walls".to_string(),
+                grid_size: 16,
+                ..default()
+            })
+            .id();
+
+        let tiles: Vec<Entity> = (0..3)
+            .map(|x| {
+                app.world_mut()
+                    .spawn((TilePos::new(x, 0), IntGridCell { value: 1 }))
+                    .set_parent(layer)
+                    .id()
+            })
+            .collect();
+
+        let collider_count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<LdtkStaticCollider>>()
+                .iter(app.world())
+                .count()
+        };
+
+        app.update();
+        assert_eq!(collider_count(&mut app), 1);
+
+        // Splitting the wall in two
+        app.world_mut()
+            .entity_mut(tiles[1])
+            .insert(IntGridCell { value: 2 });
+        app.update();
+        assert_eq!(collider_count(&mut app), 2);
+
+        app.world_mut().entity_mut(tiles[0]).despawn_recursive();
+        app.update();
+        assert_eq!(collider_count(&mut app), 1);
+
+        // Nothing changed, so the colliders are kept
+        app.update();
+        assert_eq!(collider_count(&mut app), 1);
+    }
+}
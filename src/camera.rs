@@ -0,0 +1,252 @@
+//! Provides [LdtkCameraPlugin] for framing orthographic cameras on spawned levels.
+//!
+//! Requires the `camera` feature to be enabled.
+use crate::{
+    components::{LevelBounds, LevelIid, LevelInfo},
+    resources::LevelSelection,
+    utils::level_containing_point,
+};
+use bevy::{prelude::*, transform::TransformSystem};
+
+/// Option in [LdtkCamera] that determines how the camera is framed on the active level.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Reflect)]
+pub enum CameraFit {
+    /// The camera is centered on the level, and its projection is scaled so that the whole level
+    /// is visible.
+    #[default]
+    Fit,
+    /// The camera keeps its scale and may move freely (e.g. following a player), but its
+    /// translation is clamped so that it doesn't show anything outside the level.
+    ///
+    /// If the level is smaller than the visible area along an axis, the camera is centered on the
+    /// level along that axis.
+    Clamp,
+}
+
+/// [Component] for orthographic cameras that should be framed on the active level.
+///
+/// The active level is the spawned level matching the [LevelSelection] of its world, which is the
+/// world's own [LevelSelection] component if it has one, or the resource otherwise.
+/// If the selection uses [LevelSelection::Indices], or there is no selection, the level containing
+/// the camera's translation is used instead.
+///
+/// The camera is expected to have no parent, so that its [Transform] is in world space.
+///
+/// Requires the [LdtkCameraPlugin] to be added, and the `camera` feature to be enabled.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LdtkCamera {
+    /// How the camera is framed on the level.
+    pub fit: CameraFit,
+    /// Extra space around the level, in pixels, that is considered part of the level's bounds.
+    pub padding: f32,
+    /// Keeps the projection scale at a whole number of screen pixels per level pixel (when
+    /// zooming in), and rounds the camera translation to whole pixels.
+    pub pixel_perfect: bool,
+}
+
+/// Adds the [LdtkCamera] framing system.
+///
+/// Requires the `camera` feature to be enabled.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LdtkCameraPlugin;
+
+impl Plugin for LdtkCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LdtkCamera>().add_systems(
+            PostUpdate,
+            fit_cameras_to_levels.before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Finds a spawned level matching the [LevelSelection] of its world, which is the world's own
+/// [LevelSelection] component if it has one, or the resource otherwise.
+#[allow(clippy::type_complexity)]
+fn selected_level(
+    level_selection: Option<&LevelSelection>,
+    world_level_selection_query: &Query<&LevelSelection>,
+    level_query: &Query<(
+        Entity,
+        Option<&Parent>,
+        &LevelIid,
+        &LevelInfo,
+        &LevelBounds,
+        &GlobalTransform,
+    )>,
+) -> Option<Entity> {
+    level_query
+        .iter()
+        .find(|(_, parent, level_iid, level_info, ..)| {
+            let level_selection = parent
+                .and_then(|parent| world_level_selection_query.get(parent.get()).ok())
+                .or(level_selection);
+
+            match level_selection {
+                Some(LevelSelection::Iid(iid)) => *level_iid == iid,
+                Some(LevelSelection::Identifier(identifier)) => {
+                    level_info.identifier == *identifier
+                }
+                Some(LevelSelection::Uid(uid)) => level_info.uid == *uid,
+                Some(LevelSelection::Indices(_)) | None => false,
+            }
+        })
+        .map(|(level_entity, ..)| level_entity)
+}
+
+/// Returns the area covered by a level in world space, ignoring rotation.
+fn level_world_rect(level_bounds: &LevelBounds, level_transform: &GlobalTransform) -> Rect {
+    let local_rect = level_bounds.local_rect();
+
+    Rect::from_corners(
+        level_transform
+            .transform_point(local_rect.min.extend(0.))
+            .truncate(),
+        level_transform
+            .transform_point(local_rect.max.extend(0.))
+            .truncate(),
+    )
+}
+
+/// Clamps a camera's center along one axis so that its visible extent stays within the level.
+fn clamp_axis(center: f32, half_extent: f32, level_min: f32, level_max: f32) -> f32 {
+    if level_max - level_min <= half_extent * 2. {
+        (level_min + level_max) / 2.
+    } else {
+        center.clamp(level_min + half_extent, level_max - half_extent)
+    }
+}
+
+/// Frames [LdtkCamera]s on the active level, according to their settings.
+#[allow(clippy::type_complexity)]
+pub fn fit_cameras_to_levels(
+    level_selection: Option<Res<LevelSelection>>,
+    world_level_selection_query: Query<&LevelSelection>,
+    level_query: Query<(
+        Entity,
+        Option<&Parent>,
+        &LevelIid,
+        &LevelInfo,
+        &LevelBounds,
+        &GlobalTransform,
+    )>,
+    level_point_query: Query<(Entity, &LevelBounds, &GlobalTransform)>,
+    mut camera_query: Query<(&LdtkCamera, &mut Transform, &mut OrthographicProjection)>,
+) {
+    for (ldtk_camera, mut camera_transform, mut projection) in camera_query.iter_mut() {
+        let Some(level_entity) = selected_level(
+            level_selection.as_deref(),
+            &world_level_selection_query,
+            &level_query,
+        )
+        .or_else(|| {
+            level_containing_point(&level_point_query, camera_transform.translation.truncate())
+        }) else {
+            continue;
+        };
+
+        let Ok((.., level_bounds, level_transform)) = level_query.get(level_entity) else {
+            continue;
+        };
+
+        let level_rect =
+            level_world_rect(level_bounds, level_transform).inflate(ldtk_camera.padding);
+
+        // The projection's area is the visible area at the current scale
+        let visible_size = projection.area.size();
+        if projection.scale <= 0. || visible_size.x <= 0. || visible_size.y <= 0. {
+            continue;
+        }
+
+        if ldtk_camera.fit == CameraFit::Fit {
+            let unscaled_size = visible_size / projection.scale;
+            let mut scale = (level_rect.size() / unscaled_size).max_element();
+
+            if ldtk_camera.pixel_perfect && scale < 1. {
+                scale = 1. / (1. / scale).floor();
+            }
+
+            projection.scale = scale;
+
+            let center = level_rect.center();
+            camera_transform.translation.x = center.x;
+            camera_transform.translation.y = center.y;
+        } else {
+            let half_extent = visible_size / 2.;
+
+            camera_transform.translation.x = clamp_axis(
+                camera_transform.translation.x,
+                half_extent.x,
+                level_rect.min.x,
+                level_rect.max.x,
+            );
+            camera_transform.translation.y = clamp_axis(
+                camera_transform.translation.y,
+                half_extent.y,
+                level_rect.min.y,
+                level_rect.max.y,
+            );
+        }
+
+        if ldtk_camera.pixel_perfect {
+            camera_transform.translation.x = camera_transform.translation.x.round();
+            camera_transform.translation.y = camera_transform.translation.y.round();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn clamp_axis_keeps_view_inside_level() {
+        assert_eq!(clamp_axis(0., 10., 0., 100.), 10.);
+        assert_eq!(clamp_axis(50., 10., 0., 100.), 50.);
+        assert_eq!(clamp_axis(95., 10., 0., 100.), 90.);
+        // levels smaller than the view are centered
+        assert_eq!(clamp_axis(95., 60., 0., 100.), 50.);
+    }
+
+    #[test]
+    fn world_level_selection_overrides_the_resource() {
+        let mut world = World::new();
+        world.insert_resource(LevelSelection::Identifier("Level_A".to_string()));
+
+        let ldtk_world = world
+            .spawn(LevelSelection::Identifier("Level_B".to_string()))
+            .id();
+
+        for (identifier, x) in [("Level_A", 0.), ("Level_B", 1000.)] {
+            let level = world
+                .spawn((
+                    LevelIid::new(identifier),
+                    LevelInfo {
+                        identifier: identifier.to_string(),
+                        ..default()
+                    },
+                    LevelBounds {
+                        size: Vec2::splat(100.),
+                        ..default()
+                    },
+                    GlobalTransform::from_xyz(x, 0., 0.),
+                ))
+                .id();
+            world.entity_mut(ldtk_world).add_child(level);
+        }
+
+        let camera = world
+            .spawn((
+                LdtkCamera::default(),
+                Transform::default(),
+                OrthographicProjection::default(),
+            ))
+            .id();
+
+        world.run_system_once(fit_cameras_to_levels);
+
+        let camera_x = world.get::<Transform>(camera).unwrap().translation.x;
+        assert!(camera_x > 1000. && camera_x < 1100., "camera x: {camera_x}");
+    }
+}
@@ -0,0 +1,1206 @@
+//! Provides [LdtkCommandsExt] for editing spawned levels and spawning LDtk entities at runtime.
+use crate::{
+    app::{
+        LdtkEntityHooks, LdtkEntityMap, LdtkIntCellMap, PhantomLdtkIntCell, PhantomLdtkIntCellTrait,
+    },
+    assets::{LdtkProject, LdtkProjectData},
+    components::{
+        EntityIid, GridCoords, IntGridCell, IntGridCellBundle, IntGridCsv, LayerMetadata,
+        LevelExit, LevelIid, LevelProject, LevelSet, Respawn, TileEnumTags, TileMetadata,
+    },
+    ldtk::{loaded_level::LoadedLevel, LayerInstance, Type},
+    level::{
+        default_ldtk_entity, insert_metadata_to_tile, layer_grid_tiles, spatial_bundle_for_tiles,
+        tile_in_layer_bounds, tile_metadata_maps, tile_to_grid_coords,
+    },
+    resources::{
+        IntGridRendering, LdtkSettings, LevelEvent, LevelPatches, LevelSelection, LevelTransition,
+        TilesetInfo,
+    },
+    tile_makers::{
+        tile_pos_to_int_grid_with_grid_tiles_tile_maker, tile_pos_to_invisible_tile,
+        tile_pos_to_tile_grid_bundle_maker, tile_pos_to_tile_maker, tile_pos_to_tinted_tile_maker,
+        tile_pos_to_transparent_tile_maker,
+    },
+    utils::{ldtk_map_get_or_default, ldtk_name},
+};
+use bevy::{
+    ecs::world::{Command, CommandQueue},
+    prelude::*,
+};
+use bevy_ecs_tilemap::{
+    map::TilemapId,
+    tiles::{TileBundle, TileColor, TilePos, TileStorage},
+};
+
+#[cfg(feature = "external_levels")]
+use crate::assets::LdtkExternalLevel;
+
+/// Returns the [`LdtkProject`] that the given level entity belongs to.
+///
+/// This is usually the project of the level's world, unless the level was spawned on its own.
+pub(crate) fn project_for_level(world: &World, level_entity: Entity) -> Option<&LdtkProject> {
+    let project_handle = match world.get::<LevelProject>(level_entity) {
+        Some(LevelProject(project_handle)) => project_handle,
+        None => {
+            let world_entity = world.get::<Parent>(level_entity)?.get();
+            world.get::<Handle<LdtkProject>>(world_entity)?
+        }
+    };
+
+    world.resource::<Assets<LdtkProject>>().get(project_handle)
+}
+
+/// Returns the complete level data of the given level entity.
+pub(crate) fn loaded_level_for_level<'w>(
+    world: &'w World,
+    project: &'w LdtkProject,
+    level_entity: Entity,
+) -> Option<LoadedLevel<'w>> {
+    let level_iid = world.get::<LevelIid>(level_entity)?;
+
+    match project.data() {
+        #[cfg(feature = "internal_levels")]
+        LdtkProjectData::Standalone(project) => project.get_loaded_level_by_iid(level_iid.get()),
+        #[cfg(feature = "external_levels")]
+        LdtkProjectData::Parent(project) => project.get_external_level_by_iid(
+            world.get_resource::<Assets<LdtkExternalLevel>>()?,
+            level_iid.get(),
+        ),
+    }
+}
+
+/// [`Command`] that changes the value of a single IntGrid cell in a spawned level.
+///
+/// See [`LdtkCommandsExt::set_int_grid_cell`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct SetIntGridCell {
+    /// The level entity containing the IntGrid layer.
+    pub level_entity: Entity,
+    /// Identifier of the IntGrid layer to edit.
+    pub layer_identifier: String,
+    /// Position of the cell in the layer.
+    pub grid_coords: GridCoords,
+    /// New IntGrid value of the cell, where `0` means "empty cell".
+    pub value: i32,
+}
+
+impl Command for SetIntGridCell {
+    fn apply(self, world: &mut World) {
+        let SetIntGridCell {
+            level_entity,
+            layer_identifier,
+            grid_coords,
+            value,
+        } = self;
+
+        let Some(layer_entity) = world.get::<Children>(level_entity).and_then(|children| {
+            children.iter().copied().find(|child| {
+                world.get::<IntGridCsv>(*child).is_some()
+                    && world
+                        .get::<LayerMetadata>(*child)
+                        .is_some_and(|metadata| metadata.identifier == layer_identifier)
+            })
+        }) else {
+            warn!("could not find IntGrid layer {layer_identifier} in level {level_entity:?}");
+            return;
+        };
+
+        let Some(previous_value) = world
+            .get::<IntGridCsv>(layer_entity)
+            .and_then(|int_grid_csv| int_grid_csv.get(grid_coords))
+        else {
+            warn!("{grid_coords:?} is out of the bounds of IntGrid layer {layer_identifier}");
+            return;
+        };
+
+        if previous_value == value {
+            return;
+        }
+
+        let tile_pos = TilePos::from(grid_coords);
+
+        // The new tile is prepared before anything is changed, so that invalid values leave the
+        // layer untouched.
+        let mut queue = CommandQueue::default();
+
+        let new_tile = if value == 0 {
+            None
+        } else {
+            let Some(project) = project_for_level(world, level_entity) else {
+                warn!("could not find the project of level {level_entity:?}");
+                return;
+            };
+
+            let Some(level) = loaded_level_for_level(world, project, level_entity) else {
+                warn!("could not find the level data of level {level_entity:?}");
+                return;
+            };
+
+            let Some(layer_instance) =
+                world
+                    .get::<LayerMetadata>(layer_entity)
+                    .and_then(|metadata| {
+                        level
+                            .layer_instances()
+                            .iter()
+                            .find(|layer_instance| layer_instance.iid == metadata.iid)
+                    })
+            else {
+                warn!("could not find the layer data of IntGrid layer {layer_identifier}");
+                return;
+            };
+
+            let Some(int_grid_value_definition) = project
+                .json_data()
+                .defs
+                .layers
+                .iter()
+                .find(|layer_definition| layer_definition.uid == layer_instance.layer_def_uid)
+                .and_then(|layer_definition| {
+                    layer_definition
+                        .int_grid_values
+                        .iter()
+                        .find(|definition| definition.value == value)
+                })
+            else {
+                warn!("IntGrid value {value} has no definition in layer {layer_identifier}");
+                return;
+            };
+
+            let int_grid_rendering = world.resource::<LdtkSettings>().int_grid_rendering;
+
+            // IntGrid layers with AutoTile functionality get an invisible tile, which is given its
+            // auto-tile visuals by refresh_auto_tiles below.
+            let tile_bundle = match (layer_instance.tileset_def_uid, int_grid_rendering) {
+                (None, IntGridRendering::Colorful) => Some(TileBundle {
+                    color: TileColor(int_grid_value_definition.color),
+                    ..default()
+                }),
+                _ => tile_pos_to_invisible_tile(tile_pos),
+            };
+
+            let mut tile_maker = tile_pos_to_tile_grid_bundle_maker(
+                tile_pos_to_transparent_tile_maker(move |_| tile_bundle, layer_instance.opacity),
+            );
+
+            let Some(tile_grid_bundle) = tile_maker(tile_pos) else {
+                warn!(
+                    "unable to create a tile for IntGrid value {value} in layer {layer_identifier}"
+                );
+                return;
+            };
+
+            let ldtk_int_cell_map = world.non_send_resource::<LdtkIntCellMap>();
+
+            let mut commands = Commands::new(&mut queue, world);
+
+            let mut entity_commands = commands.spawn(tile_grid_bundle);
+            entity_commands.insert(TilemapId(layer_entity));
+
+            let default_ldtk_int_cell: Box<dyn PhantomLdtkIntCellTrait> =
+                Box::new(PhantomLdtkIntCell::<IntGridCellBundle>::new());
+
+            ldtk_map_get_or_default(
+                layer_instance.identifier.clone(),
+                value,
+                &default_ldtk_int_cell,
+                ldtk_int_cell_map,
+            )
+            .evaluate(&mut entity_commands, IntGridCell { value }, layer_instance);
+
+            entity_commands.insert(spatial_bundle_for_tiles(
+                grid_coords,
+                layer_instance.grid_size,
+            ));
+
+            let new_tile = entity_commands.id();
+
+            commands.entity(layer_entity).add_child(new_tile);
+
+            Some(new_tile)
+        };
+
+        if let Some(mut int_grid_csv) = world.get_mut::<IntGridCsv>(layer_entity) {
+            int_grid_csv.set(grid_coords, value);
+        }
+
+        if let Some(level_iid) = world.get::<LevelIid>(level_entity).cloned() {
+            if let Some(mut level_patches) = world.get_resource_mut::<LevelPatches>() {
+                level_patches.get_or_default(&level_iid).set_int_grid_cell(
+                    layer_identifier.clone(),
+                    grid_coords,
+                    value,
+                );
+            }
+        }
+
+        let previous_tile = world
+            .get_mut::<TileStorage>(layer_entity)
+            .and_then(|mut storage| {
+                let previous_tile = storage.get(&tile_pos);
+                storage.remove(&tile_pos);
+                previous_tile
+            });
+
+        if let Some(previous_tile) = previous_tile {
+            world.entity_mut(previous_tile).despawn_recursive();
+        }
+
+        queue.apply(world);
+
+        if let (Some(new_tile), Some(mut storage)) =
+            (new_tile, world.get_mut::<TileStorage>(layer_entity))
+        {
+            storage.set(&tile_pos, new_tile);
+        }
+
+        refresh_auto_tiles(world, level_entity, layer_entity, grid_coords);
+    }
+}
+
+/// Re-evaluates the auto-layer rules of every layer auto-tiled from the given IntGrid layer, and
+/// replaces the tiles around the edited cell in their [`TileStorage`]s.
+///
+/// Only cells within the radius of a layer's rules are refreshed, since the patterns of the other
+/// cells' rules don't cover the edited cell.
+fn refresh_auto_tiles(
+    world: &mut World,
+    level_entity: Entity,
+    int_grid_layer_entity: Entity,
+    grid_coords: GridCoords,
+) {
+    let mut queue = CommandQueue::default();
+    // Tile storages are updated once the tiles are spawned
+    let mut storage_changes: Vec<(Entity, TilePos, Option<Entity>)> = Vec::new();
+
+    {
+        let world: &World = world;
+
+        let (Some(project), Some(source_metadata), Some(int_grid_csv), Some(children)) = (
+            project_for_level(world, level_entity),
+            world.get::<LayerMetadata>(int_grid_layer_entity),
+            world.get::<IntGridCsv>(int_grid_layer_entity),
+            world.get::<Children>(level_entity),
+        ) else {
+            return;
+        };
+
+        let Some(level) = loaded_level_for_level(world, project, level_entity) else {
+            return;
+        };
+
+        let defs = &project.json_data().defs;
+        let ldtk_settings = world.resource::<LdtkSettings>();
+
+        let mut commands = Commands::new(&mut queue, world);
+
+        for layer_instance in level.layer_instances() {
+            let Some(layer_definition) = defs
+                .layers
+                .iter()
+                .find(|layer_definition| layer_definition.uid == layer_instance.layer_def_uid)
+            else {
+                continue;
+            };
+
+            if layer_definition
+                .auto_source_layer_def_uid
+                .unwrap_or(layer_definition.uid)
+                != source_metadata.layer_def_uid
+            {
+                continue;
+            }
+
+            let Some(radius) = layer_definition
+                .auto_rule_groups
+                .iter()
+                .flat_map(|group| group.rules.iter())
+                .map(|rule| rule.size / 2)
+                .max()
+            else {
+                continue;
+            };
+
+            let Some(auto_layer_tiles) =
+                defs.auto_layer_tiles(layer_instance, &int_grid_csv.int_grid_csv)
+            else {
+                continue;
+            };
+
+            let in_radius = |cell: GridCoords| {
+                (cell.x - grid_coords.x).abs() <= radius && (cell.y - grid_coords.y).abs() <= radius
+            };
+
+            let layered_grid_tiles = layer_grid_tiles(
+                auto_layer_tiles
+                    .into_iter()
+                    .filter(|tile| {
+                        tile_in_layer_bounds(tile, layer_instance)
+                            && in_radius(tile_to_grid_coords(
+                                tile,
+                                layer_instance.c_hei,
+                                layer_instance.grid_size,
+                            ))
+                    })
+                    .collect(),
+            );
+
+            // A layer is spawned as one layer entity per sub-layer of overlapping tiles
+            let layer_entities: Vec<Entity> = children
+                .iter()
+                .copied()
+                .filter(|child| {
+                    world
+                        .get::<LayerMetadata>(*child)
+                        .is_some_and(|metadata| metadata.iid == layer_instance.iid)
+                })
+                .collect();
+
+            if layered_grid_tiles.len() > layer_entities.len() {
+                warn!(
+                    "auto-tiles of layer {} overlap more than when it was spawned, so some are not shown",
+                    layer_instance.identifier
+                );
+            }
+
+            let tileset_definition = layer_instance.tileset_def_uid.and_then(|uid| {
+                defs.tilesets
+                    .iter()
+                    .find(|tileset_definition| tileset_definition.uid == uid)
+            });
+            let (metadata_map, enum_tags_map) = tile_metadata_maps(tileset_definition);
+
+            let layer_tint = ldtk_settings.layer_tints.tint(layer_definition);
+
+            for (i, layer_entity) in layer_entities.into_iter().enumerate() {
+                let Some(storage) = world.get::<TileStorage>(layer_entity) else {
+                    continue;
+                };
+
+                let grid_tiles = layered_grid_tiles
+                    .get(i)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+
+                let tile_maker: Box<dyn FnMut(TilePos) -> Option<TileBundle>> =
+                    if layer_instance.layer_instance_type == Type::IntGrid {
+                        Box::new(tile_pos_to_int_grid_with_grid_tiles_tile_maker(
+                            grid_tiles,
+                            &int_grid_csv.int_grid_csv,
+                            layer_instance.c_wid,
+                            layer_instance.c_hei,
+                            layer_instance.grid_size,
+                            i,
+                        ))
+                    } else {
+                        Box::new(tile_pos_to_tile_maker(
+                            grid_tiles,
+                            layer_instance.c_hei,
+                            layer_instance.grid_size,
+                        ))
+                    };
+
+                let mut tile_maker =
+                    tile_pos_to_tile_grid_bundle_maker(tile_pos_to_tinted_tile_maker(
+                        tile_pos_to_transparent_tile_maker(tile_maker, layer_instance.opacity),
+                        layer_tint,
+                    ));
+
+                for y in (grid_coords.y - radius).max(0)
+                    ..=(grid_coords.y + radius).min(layer_instance.c_hei - 1)
+                {
+                    for x in (grid_coords.x - radius).max(0)
+                        ..=(grid_coords.x + radius).min(layer_instance.c_wid - 1)
+                    {
+                        let cell = GridCoords::new(x, y);
+                        let tile_pos = TilePos::from(cell);
+
+                        let tile_entity = match (storage.get(&tile_pos), tile_maker(tile_pos)) {
+                            (Some(tile_entity), Some(tile_grid_bundle)) => {
+                                // Existing tiles are kept, so IntGrid cells keep their components
+                                commands
+                                    .entity(tile_entity)
+                                    .insert(tile_grid_bundle)
+                                    .insert(TilemapId(layer_entity))
+                                    .remove::<(TileMetadata, TileEnumTags)>();
+                                tile_entity
+                            }
+                            (Some(tile_entity), None) => {
+                                commands.entity(tile_entity).despawn_recursive();
+                                storage_changes.push((layer_entity, tile_pos, None));
+                                continue;
+                            }
+                            (None, Some(tile_grid_bundle)) => {
+                                let tile_entity = commands
+                                    .spawn(tile_grid_bundle)
+                                    .insert(TilemapId(layer_entity))
+                                    .insert(spatial_bundle_for_tiles(
+                                        cell,
+                                        layer_instance.grid_size,
+                                    ))
+                                    .id();
+                                commands.entity(layer_entity).add_child(tile_entity);
+                                storage_changes.push((layer_entity, tile_pos, Some(tile_entity)));
+                                tile_entity
+                            }
+                            (None, None) => continue,
+                        };
+
+                        if let Some(tile_instance) = grid_tiles.iter().find(|tile| {
+                            tile_to_grid_coords(
+                                tile,
+                                layer_instance.c_hei,
+                                layer_instance.grid_size,
+                            ) == cell
+                        }) {
+                            insert_metadata_to_tile(
+                                &mut commands,
+                                tile_instance,
+                                tile_entity,
+                                &metadata_map,
+                                &enum_tags_map,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    queue.apply(world);
+
+    for (layer_entity, tile_pos, tile_entity) in storage_changes {
+        if let Some(mut storage) = world.get_mut::<TileStorage>(layer_entity) {
+            match tile_entity {
+                Some(tile_entity) => storage.set(&tile_pos, tile_entity),
+                None => storage.remove(&tile_pos),
+            }
+        }
+    }
+}
+
+/// [`Command`] that replaces all IntGrid values of a layer in a spawned level, and respawns it.
+///
+/// See [`LdtkCommandsExt::import_int_grid_csv`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ImportIntGridCsv {
+    /// The level entity containing the IntGrid layer.
+    pub level_entity: Entity,
+    /// Identifier of the IntGrid layer to replace.
+    pub layer_identifier: String,
+    /// New IntGrid values of the layer.
+    pub int_grid_csv: IntGridCsv,
+}
+
+impl Command for ImportIntGridCsv {
+    fn apply(self, world: &mut World) {
+        let ImportIntGridCsv {
+            level_entity,
+            layer_identifier,
+            int_grid_csv,
+        } = self;
+
+        let Some(layer_entity) = world.get::<Children>(level_entity).and_then(|children| {
+            children.iter().copied().find(|child| {
+                world.get::<IntGridCsv>(*child).is_some()
+                    && world
+                        .get::<LayerMetadata>(*child)
+                        .is_some_and(|metadata| metadata.identifier == layer_identifier)
+            })
+        }) else {
+            warn!("could not find IntGrid layer {layer_identifier} in level {level_entity:?}");
+            return;
+        };
+
+        let Some(mut current) = world.get_mut::<IntGridCsv>(layer_entity) else {
+            return;
+        };
+
+        if (current.c_wid, current.c_hei) != (int_grid_csv.c_wid, int_grid_csv.c_hei) {
+            warn!(
+                "attempted to import a {}x{} IntGrid into layer {layer_identifier}, which is {}x{}",
+                int_grid_csv.c_wid, int_grid_csv.c_hei, current.c_wid, current.c_hei
+            );
+            return;
+        }
+
+        let changed_cells: Vec<(GridCoords, i32)> = (0..int_grid_csv.c_hei)
+            .flat_map(|y| (0..int_grid_csv.c_wid).map(move |x| GridCoords::new(x, y)))
+            .filter_map(|grid_coords| {
+                let value = int_grid_csv.get(grid_coords)?;
+                (current.get(grid_coords)? != value).then_some((grid_coords, value))
+            })
+            .collect();
+
+        if changed_cells.is_empty() {
+            return;
+        }
+
+        *current = int_grid_csv;
+
+        if let Some(level_iid) = world.get::<LevelIid>(level_entity).cloned() {
+            if let Some(mut level_patches) = world.get_resource_mut::<LevelPatches>() {
+                let level_patch = level_patches.get_or_default(&level_iid);
+
+                for (grid_coords, value) in changed_cells {
+                    level_patch.set_int_grid_cell(layer_identifier.clone(), grid_coords, value);
+                }
+            }
+        }
+
+        world.entity_mut(level_entity).insert(Respawn);
+    }
+}
+
+/// [`Command`] that despawns an LDtk entity and records its removal.
+///
+/// See [`LdtkCommandsExt::despawn_ldtk_entity`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct DespawnLdtkEntity {
+    /// The LDtk entity to despawn.
+    pub entity: Entity,
+}
+
+impl Command for DespawnLdtkEntity {
+    fn apply(self, world: &mut World) {
+        let DespawnLdtkEntity { entity } = self;
+
+        if world.get_entity(entity).is_none() {
+            warn!("attempted to despawn LDtk entity {entity:?}, but it doesn't exist");
+            return;
+        }
+
+        let entity_iid = world.get::<EntityIid>(entity).cloned();
+
+        let mut level_iid = None;
+        let mut ancestor = world.get::<Parent>(entity).map(Parent::get);
+        while let Some(ancestor_entity) = ancestor {
+            if let Some(iid) = world.get::<LevelIid>(ancestor_entity) {
+                level_iid = Some(iid.clone());
+                break;
+            }
+            ancestor = world.get::<Parent>(ancestor_entity).map(Parent::get);
+        }
+
+        if let (Some(entity_iid), Some(level_iid)) = (entity_iid, level_iid) {
+            if let Some(mut level_patches) = world.get_resource_mut::<LevelPatches>() {
+                level_patches
+                    .get_or_default(&level_iid)
+                    .remove_entity(entity_iid.as_str());
+            }
+        }
+
+        world.entity_mut(entity).despawn_recursive();
+    }
+}
+
+/// [`Command`] that despawns a level entity, along with all of its layers, tiles and entities.
+///
+/// See [`LdtkCommandsExt::despawn_ldtk_level`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct DespawnLdtkLevel {
+    /// The level entity to despawn.
+    pub level_entity: Entity,
+}
+
+impl Command for DespawnLdtkLevel {
+    fn apply(self, world: &mut World) {
+        let DespawnLdtkLevel { level_entity } = self;
+
+        if world.get_entity(level_entity).is_none() {
+            warn!("attempted to despawn LDtk level {level_entity:?}, but it doesn't exist");
+            return;
+        }
+
+        if let Some(level_iid) = world.get::<LevelIid>(level_entity).cloned() {
+            // Levels belonging to a world would be respawned according to its LevelSet otherwise
+            if let Some(world_entity) = world.get::<Parent>(level_entity).map(Parent::get) {
+                if let Some(mut level_set) = world.get_mut::<LevelSet>(world_entity) {
+                    level_set.iids.remove(&level_iid);
+                }
+            }
+
+            world.send_event(LevelEvent::Despawned(level_iid));
+        }
+
+        world.entity_mut(level_entity).despawn_recursive();
+    }
+}
+
+/// [`Command`] that spawns an LDtk entity outside of any level, like a prefab.
+///
+/// See [`LdtkCommandsExt::spawn_ldtk_entity`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpawnLdtkEntity {
+    /// The entity to insert the LDtk entity's components on.
+    pub entity: Entity,
+    /// The world entity, with the [`LdtkProject`] handle, whose project defines the LDtk entity.
+    pub world_entity: Entity,
+    /// Identifier of the LDtk entity definition.
+    pub identifier: String,
+    /// Transform of the spawned entity.
+    pub transform: Transform,
+}
+
+impl Command for SpawnLdtkEntity {
+    fn apply(self, world: &mut World) {
+        let SpawnLdtkEntity {
+            entity,
+            world_entity,
+            identifier,
+            transform,
+        } = self;
+
+        let mut queue = CommandQueue::default();
+
+        let spawned = world.resource_scope(
+            |world, mut texture_atlases: Mut<Assets<TextureAtlasLayout>>| {
+                let Some(project) = world
+                    .get::<Handle<LdtkProject>>(world_entity)
+                    .and_then(|handle| world.resource::<Assets<LdtkProject>>().get(handle))
+                else {
+                    warn!("unable to spawn LDtk entity {identifier}, the world's project is not loaded");
+                    return false;
+                };
+
+                let Some(entity_definition) = project
+                    .json_data()
+                    .defs
+                    .entities
+                    .iter()
+                    .find(|entity_definition| entity_definition.identifier == identifier)
+                else {
+                    warn!("could not find LDtk entity definition {identifier} in the world's project");
+                    return false;
+                };
+
+                let entity_instance = match entity_definition.create_default_instance() {
+                    Ok(entity_instance) => entity_instance,
+                    Err(e) => {
+                        warn!("unable to create LDtk entity {identifier}: {e}");
+                        return false;
+                    }
+                };
+
+                // Use the first entity layer that accepts this entity, so layer-specific
+                // registrations can apply.
+                let layer_instance = project
+                    .json_data()
+                    .defs
+                    .layers
+                    .iter()
+                    .find(|layer_definition| {
+                        layer_definition.purple_type == Type::Entities
+                            && layer_definition
+                                .required_tags
+                                .iter()
+                                .all(|tag| entity_instance.tags.contains(tag))
+                            && !layer_definition
+                                .excluded_tags
+                                .iter()
+                                .any(|tag| entity_instance.tags.contains(tag))
+                    })
+                    .map(|layer_definition| LayerInstance {
+                        identifier: layer_definition.identifier.clone(),
+                        layer_def_uid: layer_definition.uid,
+                        grid_size: layer_definition.grid_size,
+                        layer_instance_type: Type::Entities,
+                        opacity: 1.,
+                        visible: true,
+                        ..default()
+                    })
+                    .unwrap_or_default();
+
+                let tileset_definition = entity_instance.tile.as_ref().and_then(|tile| {
+                    project
+                        .json_data()
+                        .defs
+                        .tilesets
+                        .iter()
+                        .find(|tileset_definition| tileset_definition.uid == tile.tileset_uid)
+                });
+                let tileset_info = tileset_definition.map(|tileset_definition| {
+                    TilesetInfo::new(
+                        tileset_definition,
+                        project.tileset_map().get(&tileset_definition.uid).cloned(),
+                    )
+                });
+
+                let asset_server = world.resource::<AssetServer>();
+                let ldtk_entity_map = world.non_send_resource::<LdtkEntityMap>();
+
+                let mut commands = Commands::new(&mut queue, world);
+                let mut entity_commands = commands.entity(entity);
+
+                // insert Name before evaluating LdtkEntitys so that user-provided names aren't
+                // overwritten
+                entity_commands.insert(ldtk_name(&identifier, &entity_instance.iid));
+
+                let default_ldtk_entity =
+                    default_ldtk_entity(world.resource::<LdtkSettings>().entity_instance_storage);
+
+                let ldtk_entity_registration = ldtk_map_get_or_default(
+                    layer_instance.identifier.clone(),
+                    identifier.clone(),
+                    &default_ldtk_entity,
+                    ldtk_entity_map,
+                );
+
+                // Pooled bundles can't carry per-instance data, so the instance data is stored
+                // like for unregistered entities.
+                let ldtk_entity_registration = match ldtk_entity_registration.pooled_bundle(
+                    &entity_instance,
+                    entity_definition,
+                    &layer_instance,
+                    tileset_info.as_ref(),
+                    tileset_definition,
+                    asset_server,
+                    &mut texture_atlases,
+                ) {
+                    Some(pooled_bundle) => {
+                        pooled_bundle.insert_clone(&mut entity_commands);
+                        &default_ldtk_entity
+                    }
+                    None => ldtk_entity_registration,
+                };
+
+                ldtk_entity_registration.evaluate(
+                    &mut entity_commands,
+                    &entity_instance,
+                    entity_definition,
+                    &layer_instance,
+                    tileset_info.as_ref(),
+                    tileset_definition,
+                    asset_server,
+                    &mut texture_atlases,
+                );
+
+                entity_commands.insert(SpatialBundle {
+                    transform,
+                    ..default()
+                });
+
+                if let Some(hook_command) =
+                    world
+                        .get_resource::<LdtkEntityHooks>()
+                        .and_then(|ldtk_entity_hooks| {
+                            ldtk_entity_hooks.entity_command(&entity_instance)
+                        })
+                {
+                    entity_commands.add(hook_command);
+                }
+
+                true
+            },
+        );
+
+        if spawned {
+            queue.apply(world);
+        } else if let Some(entity) = world.get_entity_mut(entity) {
+            entity.despawn_recursive();
+        }
+    }
+}
+
+/// [`Command`] that selects a level and moves [`LevelTraveler`] entities to a spawn point in it.
+///
+/// See [`LdtkCommandsExt::transition_to_level`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+/// [`LevelTraveler`]: crate::prelude::LevelTraveler
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct TransitionToLevel {
+    /// The exit being followed.
+    pub level_exit: LevelExit,
+}
+
+impl Command for TransitionToLevel {
+    fn apply(self, world: &mut World) {
+        let LevelExit {
+            target_level_iid,
+            target_spawn_point,
+        } = self.level_exit;
+
+        world.insert_resource(LevelSelection::Iid(target_level_iid.clone()));
+
+        // Without a target spawn point, the level's SpawnPoint can only be used if it is marked
+        let uses_spawn_point = target_spawn_point.is_some()
+            || world
+                .get_resource::<LdtkSettings>()
+                .is_some_and(|ldtk_settings| ldtk_settings.spawn_point_identifier.is_some());
+
+        *world.get_resource_or_insert_with(LevelTransition::default) = if uses_spawn_point {
+            LevelTransition {
+                pending_level: Some(target_level_iid),
+                pending_spawn_point: target_spawn_point,
+            }
+        } else {
+            LevelTransition::default()
+        };
+    }
+}
+
+/// [`Command`] that sets the [`Visibility`] of every layer entity spawned for an LDtk layer.
+///
+/// See [`LdtkCommandsExt::set_layer_visibility`] for more details.
+///
+/// [`Command`]: https://docs.rs/bevy/latest/bevy/ecs/world/trait.Command.html
+/// [`Visibility`]: https://docs.rs/bevy/latest/bevy/render/view/enum.Visibility.html
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SetLayerVisibility {
+    /// The level entity containing the layer.
+    pub level_entity: Entity,
+    /// Identifier of the layer.
+    pub layer_identifier: String,
+    /// The new visibility of the layer.
+    pub visibility: Visibility,
+}
+
+impl Command for SetLayerVisibility {
+    fn apply(self, world: &mut World) {
+        let SetLayerVisibility {
+            level_entity,
+            layer_identifier,
+            visibility,
+        } = self;
+
+        let Some(children) = world.get::<Children>(level_entity) else {
+            warn!("attempted to set layer visibility of level {level_entity:?}, but it has no spawned layers");
+            return;
+        };
+
+        let layer_entities = children
+            .iter()
+            .copied()
+            .filter(|child| {
+                world
+                    .get::<LayerMetadata>(*child)
+                    .is_some_and(|layer_metadata| layer_metadata.identifier == layer_identifier)
+            })
+            .collect::<Vec<_>>();
+
+        if layer_entities.is_empty() {
+            warn!("attempted to set visibility of layer \"{layer_identifier}\" in level {level_entity:?}, but no such layer is spawned");
+        }
+
+        for layer_entity in layer_entities {
+            world.entity_mut(layer_entity).insert(visibility);
+        }
+    }
+}
+
+/// Provides functions for editing spawned LDtk levels at runtime via [`Commands`].
+///
+/// Not intended for custom implementations on your own types.
+///
+/// [`Commands`]: https://docs.rs/bevy/latest/bevy/ecs/system/struct.Commands.html
+pub trait LdtkCommandsExt {
+    /// Changes the value of an IntGrid cell in a spawned level.
+    ///
+    /// The cell's value in the layer's [`IntGridCsv`] component is updated, and the cell's tile
+    /// entity is despawned.
+    /// If the new value is nonzero, a new tile is spawned in its place, with the [`LdtkIntCell`]
+    /// bundle registered for the new value inserted, as if the level had been spawned with it.
+    ///
+    /// Tile colors are refreshed for IntGrid layers that are rendered by color.
+    /// For the IntGrid layer, if it has AutoTile functionality, and for any AutoLayer using it as
+    /// a source, auto-layer rules are re-evaluated, and the tiles of cells whose rules cover the
+    /// edited cell are replaced.
+    ///
+    /// The edit is also recorded in the [`LevelPatches`] resource, so it is re-applied if the level
+    /// respawns.
+    /// Edits are not written to the [`LdtkProject`] asset.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ecs_ldtk::prelude::*;
+    /// fn destroy_walls(
+    ///     mut commands: Commands,
+    ///     level_query: Query<Entity, With<LevelIid>>,
+    /// ) {
+    ///     for level_entity in level_query.iter() {
+    ///         commands.set_int_grid_cell(level_entity, "Walls", GridCoords::new(3, 4), 0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`LdtkIntCell`]: crate::prelude::LdtkIntCell
+    fn set_int_grid_cell(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        grid_coords: GridCoords,
+        value: i32,
+    );
+
+    /// Replaces all values of an IntGrid layer in a spawned level, and respawns the level.
+    ///
+    /// The [`IntGridCsv`] must have the same dimensions as the layer.
+    /// It can be built from CSV text or rows with [`IntGridCsv::from_csv`] and
+    /// [`IntGridCsv::from_rows`], so collision data can be generated procedurally or edited by
+    /// external tools.
+    /// To export a layer in the same format, use [`IntGridCsv::to_csv`] or
+    /// [`IntGridCsv::to_rows`] on the layer entity's [`IntGridCsv`] component.
+    ///
+    /// Changed cells are recorded in the [`LevelPatches`] resource, just like with
+    /// [`LdtkCommandsExt::set_int_grid_cell`], and [`Respawn`] is inserted on the level entity so
+    /// tiles, auto-tiles and [`LdtkIntCell`] bundles are rebuilt from the new values.
+    /// Nothing happens if the values are unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ecs_ldtk::prelude::*;
+    /// fn load_generated_walls(
+    ///     mut commands: Commands,
+    ///     level_query: Query<Entity, With<LevelIid>>,
+    /// ) {
+    ///     let csv = "1,1,1\n1,0,1\n1,1,1\n";
+    ///
+    ///     if let Ok(int_grid_csv) = IntGridCsv::from_csv(csv) {
+    ///         for level_entity in level_query.iter() {
+    ///             commands.import_int_grid_csv(level_entity, "Walls", int_grid_csv.clone());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`LdtkIntCell`]: crate::prelude::LdtkIntCell
+    fn import_int_grid_csv(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        int_grid_csv: IntGridCsv,
+    );
+
+    /// Despawns an LDtk entity (recursively) and records its removal in the [`LevelPatches`]
+    /// resource, so it stays removed if its level respawns.
+    ///
+    /// The removal is only recorded if the entity has an [`EntityIid`] and is still a descendant
+    /// of its level, so [`Worldly`] entities are simply despawned.
+    ///
+    /// [`Worldly`]: crate::prelude::Worldly
+    fn despawn_ldtk_entity(&mut self, entity: Entity);
+
+    /// Despawns a level entity (recursively), including all of its layers, tiles and entities.
+    ///
+    /// Tiles of the level's tilemaps are despawned even if they aren't descendants of the level,
+    /// so the tilemap data is released completely.
+    /// If the level belongs to an [`LdtkWorldBundle`], it is also removed from the world's
+    /// [`LevelSet`] so it isn't respawned.
+    /// Note that the [`LevelSelection`] resource may still add it back if it changes.
+    ///
+    /// A [`LevelEvent::Despawned`] is sent for the level.
+    ///
+    /// [`LdtkWorldBundle`]: crate::prelude::LdtkWorldBundle
+    fn despawn_ldtk_level(&mut self, level_entity: Entity);
+
+    /// Spawns an LDtk entity outside of any level, using its definition like a prefab.
+    ///
+    /// An [`EntityInstance`] is created from the definition with the given `identifier`, with
+    /// default field values and the definition's editor visual.
+    /// Then, the [`LdtkEntity`] bundle registered for that identifier is inserted, just like when
+    /// spawning levels.
+    /// The first entity layer in the project that accepts the entity's tags is used for
+    /// layer-specific registrations.
+    ///
+    /// The definition is looked up in the [`LdtkProject`] of the given `world_entity`, so the
+    /// project must be loaded first.
+    ///
+    /// Unregistered and pooled entities get their instance data according to the
+    /// [`EntityInstanceStorage`] setting.
+    /// Since the entity doesn't belong to any level, an [`EntityInstanceRef`] to it doesn't
+    /// resolve.
+    ///
+    /// The spawned entity has no parent and no [`EntityIid`].
+    /// Like in levels, the requested `transform` is inserted after the [`LdtkEntity`] bundle, so
+    /// it replaces any [`Transform`] of the bundle.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ecs_ldtk::prelude::*;
+    /// fn spawn_enemy(mut commands: Commands, ldtk_worlds: Query<Entity, With<Handle<LdtkProject>>>) {
+    ///     let world_entity = ldtk_worlds.single();
+    ///     commands.spawn_ldtk_entity(world_entity, "Enemy", Transform::from_xyz(32., 64., 5.));
+    /// }
+    /// ```
+    ///
+    /// [`EntityInstance`]: crate::ldtk::EntityInstance
+    /// [`EntityInstanceStorage`]: crate::resources::EntityInstanceStorage
+    /// [`EntityInstanceRef`]: crate::components::EntityInstanceRef
+    /// [`LdtkEntity`]: crate::prelude::LdtkEntity
+    fn spawn_ldtk_entity(
+        &mut self,
+        world_entity: Entity,
+        identifier: impl Into<String>,
+        transform: Transform,
+    ) -> Entity;
+
+    /// Follows a [`LevelExit`], switching the [`LevelSelection`] to its target level.
+    ///
+    /// Once the spawn point entity in the target level has spawned, all [`LevelTraveler`]
+    /// entities are moved to its position, keeping their z translation.
+    /// If the exit has no target spawn point, the [`SpawnPoint`] of the target level is used.
+    /// Travelers are moved before transforms are propagated, in the same update that the spawn
+    /// point spawns, or in the next update if it is already spawned, e.g. when the exit leads to
+    /// the current level.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ecs_ldtk::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// fn enter_doors(
+    ///     mut commands: Commands,
+    ///     keys: Res<ButtonInput<KeyCode>>,
+    ///     player_query: Query<&GridCoords, With<Player>>,
+    ///     door_query: Query<(&GridCoords, &LevelExit)>,
+    /// ) {
+    ///     if !keys.just_pressed(KeyCode::KeyW) {
+    ///         return;
+    ///     }
+    ///
+    ///     for player_coords in player_query.iter() {
+    ///         for (door_coords, level_exit) in door_query.iter() {
+    ///             if player_coords == door_coords {
+    ///                 commands.transition_to_level(level_exit);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`LevelTraveler`]: crate::prelude::LevelTraveler
+    /// [`SpawnPoint`]: crate::prelude::SpawnPoint
+    fn transition_to_level(&mut self, level_exit: &LevelExit);
+
+    /// Sets the [`Visibility`] of a layer in a spawned level.
+    ///
+    /// Worlds, levels, layers, tiles and entities are all spawned with visibility components, so
+    /// hiding any world, level or layer entity also hides all of its descendants.
+    /// However, a single LDtk layer may be spawned as several layer entities, e.g. when auto-tiles
+    /// overlap.
+    /// This sets the visibility of all of them at once.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_ecs_ldtk::prelude::*;
+    /// fn toggle_debug_layer(
+    ///     mut commands: Commands,
+    ///     keys: Res<ButtonInput<KeyCode>>,
+    ///     level_query: Query<Entity, With<LevelIid>>,
+    ///     mut shown: Local<bool>,
+    /// ) {
+    ///     if keys.just_pressed(KeyCode::F1) {
+    ///         *shown = !*shown;
+    ///         let visibility = if *shown {
+    ///             Visibility::Inherited
+    ///         } else {
+    ///             Visibility::Hidden
+    ///         };
+    ///
+    ///         for level_entity in level_query.iter() {
+    ///             commands.set_layer_visibility(level_entity, "Debug", visibility);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Visibility`]: https://docs.rs/bevy/latest/bevy/render/view/enum.Visibility.html
+    fn set_layer_visibility(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        visibility: Visibility,
+    );
+}
+
+impl LdtkCommandsExt for Commands<'_, '_> {
+    fn set_int_grid_cell(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        grid_coords: GridCoords,
+        value: i32,
+    ) {
+        self.add(SetIntGridCell {
+            level_entity,
+            layer_identifier: layer_identifier.into(),
+            grid_coords,
+            value,
+        });
+    }
+
+    fn import_int_grid_csv(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        int_grid_csv: IntGridCsv,
+    ) {
+        self.add(ImportIntGridCsv {
+            level_entity,
+            layer_identifier: layer_identifier.into(),
+            int_grid_csv,
+        });
+    }
+
+    fn despawn_ldtk_entity(&mut self, entity: Entity) {
+        self.add(DespawnLdtkEntity { entity });
+    }
+
+    fn despawn_ldtk_level(&mut self, level_entity: Entity) {
+        self.add(DespawnLdtkLevel { level_entity });
+    }
+
+    fn spawn_ldtk_entity(
+        &mut self,
+        world_entity: Entity,
+        identifier: impl Into<String>,
+        transform: Transform,
+    ) -> Entity {
+        let entity = self.spawn_empty().id();
+
+        self.add(SpawnLdtkEntity {
+            entity,
+            world_entity,
+            identifier: identifier.into(),
+            transform,
+        });
+
+        entity
+    }
+
+    fn transition_to_level(&mut self, level_exit: &LevelExit) {
+        self.add(TransitionToLevel {
+            level_exit: level_exit.clone(),
+        });
+    }
+
+    fn set_layer_visibility(
+        &mut self,
+        level_entity: Entity,
+        layer_identifier: impl Into<String>,
+        visibility: Visibility,
+    ) {
+        self.add(SetLayerVisibility {
+            level_entity,
+            layer_identifier: layer_identifier.into(),
+            visibility,
+        });
+    }
+}
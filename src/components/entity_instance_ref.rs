@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::LdtkProject,
+    components::LevelIid,
+    ldtk::{EntityInstance, LayerInstance, Level},
+    resources::LevelPatches,
+};
+
+#[allow(unused_imports)]
+use crate::{
+    app::LdtkEntity,
+    resources::{EntityInstanceStorage, LdtkSettings},
+};
+
+/// [`Component`] referring to the [`EntityInstance`] an LDtk entity was spawned from, without
+/// cloning its data.
+///
+/// An [`EntityInstance`] component carries a full copy of the entity's field instances, which adds
+/// up for levels with thousands of entities.
+/// This component only stores enough to look the instance up again in the project data, so field
+/// values are only read when needed.
+///
+/// Inserted on entities without an [`LdtkEntity`] registration, instead of an [`EntityInstance`],
+/// when [`LdtkSettings::entity_instance_storage`] is [`EntityInstanceStorage::Reference`].
+/// Implements [`LdtkEntity`], so it can also be added to an [`LdtkEntity`] bundle with the
+/// `#[ldtk_entity]` attribute, in place of an `#[from_entity_instance]` [`EntityInstance`].
+///
+/// Resolving a reference applies the [`LevelPatches`], so entities removed at runtime no longer
+/// resolve.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// fn read_health(
+///     enemies: Query<&EntityInstanceRef, Added<EntityInstanceRef>>,
+///     projects: Query<&Handle<LdtkProject>>,
+///     project_assets: Res<Assets<LdtkProject>>,
+///     level_patches: Res<LevelPatches>,
+/// ) {
+///     let Some(project) = projects.iter().next().and_then(|h| project_assets.get(h)) else {
+///         return;
+///     };
+///
+///     for entity_instance_ref in &enemies {
+///         if let Some(entity_instance) = entity_instance_ref.resolve(project, &level_patches) {
+///             let _health = entity_instance.get_int_field("health");
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct EntityInstanceRef {
+    /// Uid of the level the entity instance belongs to.
+    pub level_uid: i32,
+    /// Iid of the layer instance the entity instance belongs to.
+    pub layer_iid: String,
+    /// Iid of the entity instance.
+    pub entity_iid: String,
+}
+
+impl EntityInstanceRef {
+    /// Creates an [`EntityInstanceRef`] to the given entity instance of the given layer instance.
+    pub fn from_entity_info(
+        entity_instance: &EntityInstance,
+        layer_instance: &LayerInstance,
+    ) -> EntityInstanceRef {
+        EntityInstanceRef {
+            level_uid: layer_instance.level_id,
+            layer_iid: layer_instance.iid.clone(),
+            entity_iid: entity_instance.iid.clone(),
+        }
+    }
+
+    /// Returns true if the referenced entity instance has been removed from the given level by
+    /// its [`LevelPatches`].
+    fn is_removed_from(&self, level: &Level, level_patches: &LevelPatches) -> bool {
+        level_patches
+            .get(&LevelIid::new(level.iid.clone()))
+            .is_some_and(|level_patch| level_patch.is_entity_removed(&self.entity_iid))
+    }
+
+    /// Looks up the referenced [`EntityInstance`] in the given level.
+    ///
+    /// Returns `None` if the level is a different level or has no layer instances, as is the case
+    /// for the raw levels of projects with external levels.
+    /// Also returns `None` if the entity instance has been removed by the [`LevelPatches`].
+    pub fn resolve_in_level<'a>(
+        &self,
+        level: &'a Level,
+        level_patches: &LevelPatches,
+    ) -> Option<&'a EntityInstance> {
+        if level.uid != self.level_uid || self.is_removed_from(level, level_patches) {
+            return None;
+        }
+
+        level
+            .layer_instances
+            .as_ref()?
+            .iter()
+            .find(|layer_instance| layer_instance.iid == self.layer_iid)?
+            .entity_instances
+            .iter()
+            .find(|entity_instance| entity_instance.iid == self.entity_iid)
+    }
+
+    /// Looks up the referenced [`EntityInstance`] in the given project.
+    ///
+    /// The lookup is constant, see [`LdtkProject::get_entity_instance_by_iid`].
+    /// Returns `None` if the entity instance has been removed by the [`LevelPatches`].
+    ///
+    /// Only finds entity instances of internal levels.
+    /// For external levels, use [`EntityInstanceRef::resolve_in_level`] with the level's
+    /// [`LdtkExternalLevel`] data instead.
+    ///
+    /// [`LdtkExternalLevel`]: crate::assets::LdtkExternalLevel
+    pub fn resolve<'a>(
+        &self,
+        project: &'a LdtkProject,
+        level_patches: &LevelPatches,
+    ) -> Option<&'a EntityInstance> {
+        let (level, entity_instance) = project.get_entity_instance_by_iid(&self.entity_iid)?;
+
+        (level.uid == self.level_uid && !self.is_removed_from(level, level_patches))
+            .then_some(entity_instance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::LdtkJson;
+    use std::collections::HashMap;
+
+    #[cfg(feature = "internal_levels")]
+    #[test]
+    fn entity_instance_refs_resolve_into_project_data() {
+        let entity_instance = EntityInstance {
+            iid: "entity".to_string(),
+            identifier: "Enemy".to_string(),
+            ..default()
+        };
+
+        let layer_instance = LayerInstance {
+            iid: "layer".to_string(),
+            level_id: 2,
+            entity_instances: vec![
+                EntityInstance {
+                    iid: "other".to_string(),
+                    ..default()
+                },
+                entity_instance.clone(),
+            ],
+            ..default()
+        };
+
+        let project = LdtkJson {
+            levels: vec![
+                Level {
+                    uid: 1,
+                    layer_instances: Some(Vec::new()),
+                    ..default()
+                },
+                Level {
+                    iid: "level".to_string(),
+                    uid: 2,
+                    layer_instances: Some(vec![layer_instance.clone()]),
+                    ..default()
+                },
+            ],
+            ..default()
+        };
+        let project = LdtkProject::from_bytes(
+            &serde_json::to_vec(&project).unwrap(),
+            &HashMap::new(),
+            &mut Assets::default(),
+        )
+        .unwrap();
+
+        let entity_instance_ref =
+            EntityInstanceRef::from_entity_info(&entity_instance, &layer_instance);
+        let mut level_patches = LevelPatches::default();
+
+        assert_eq!(
+            entity_instance_ref
+                .resolve(&project, &level_patches)
+                .map(|entity_instance| entity_instance.identifier.as_str()),
+            Some("Enemy")
+        );
+        assert_eq!(
+            entity_instance_ref.resolve_in_level(&project.json_data().levels[0], &level_patches),
+            None
+        );
+
+        let missing_ref = EntityInstanceRef {
+            entity_iid: "missing".to_string(),
+            ..entity_instance_ref.clone()
+        };
+        assert_eq!(missing_ref.resolve(&project, &level_patches), None);
+
+        level_patches
+            .get_or_default(&LevelIid::new("level"))
+            .remove_entity("entity");
+
+        assert_eq!(entity_instance_ref.resolve(&project, &level_patches), None);
+        assert_eq!(
+            entity_instance_ref.resolve_in_level(&project.json_data().levels[1], &level_patches),
+            None
+        );
+    }
+}
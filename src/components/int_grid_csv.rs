@@ -0,0 +1,245 @@
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::{components::GridCoords, ldtk::LayerInstance, utils::grid_coords_to_ldtk_grid_coords};
+
+/// Errors that can occur when building an [`IntGridCsv`] from CSV text or rows.
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum IntGridCsvError {
+    /// A value in the CSV text is not an integer.
+    #[error("unable to parse IntGrid value {value:?} in row {row}: {error}")]
+    ParseValue {
+        /// Zero-based index of the row containing the value, from the top.
+        row: usize,
+        /// The text that could not be parsed.
+        value: String,
+        /// The underlying parse error.
+        error: std::num::ParseIntError,
+    },
+    /// A row has a different number of values than the first row.
+    #[error("row {row} has {len} values, expected {expected}")]
+    RaggedRow {
+        /// Zero-based index of the offending row, from the top.
+        row: usize,
+        /// Number of values in the offending row.
+        len: usize,
+        /// Number of values in the first row.
+        expected: usize,
+    },
+}
+
+/// [`Component`] storing the current IntGrid values of an IntGrid layer.
+///
+/// Automatically inserted on IntGrid layer entities.
+/// It starts out as a copy of the layer's `int_grid_csv`, and is kept up to date by runtime edits
+/// made via [`LdtkCommandsExt::set_int_grid_cell`].
+///
+/// Values are stored in LDtk's order (left to right, top to bottom), but can be accessed by
+/// [`GridCoords`].
+///
+/// The values can be exported with [`IntGridCsv::to_rows`] and [`IntGridCsv::to_csv`], and
+/// imported with [`IntGridCsv::from_rows`] and [`IntGridCsv::from_csv`], so collision data can be
+/// exchanged with external tools.
+/// Use [`LdtkCommandsExt::import_int_grid_csv`] to apply an imported grid to a spawned level.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+/// [`LdtkCommandsExt::set_int_grid_cell`]: crate::LdtkCommandsExt::set_int_grid_cell
+/// [`LdtkCommandsExt::import_int_grid_csv`]: crate::LdtkCommandsExt::import_int_grid_csv
+#[derive(Clone, Eq, PartialEq, Debug, Default, Hash, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct IntGridCsv {
+    /// Grid-based width of the layer.
+    pub c_wid: i32,
+    /// Grid-based height of the layer.
+    pub c_hei: i32,
+    /// IntGrid values of the layer, where `0` means "empty cell".
+    pub int_grid_csv: Vec<i32>,
+}
+
+impl From<&LayerInstance> for IntGridCsv {
+    fn from(layer_instance: &LayerInstance) -> Self {
+        IntGridCsv {
+            c_wid: layer_instance.c_wid,
+            c_hei: layer_instance.c_hei,
+            int_grid_csv: layer_instance.int_grid_csv.clone(),
+        }
+    }
+}
+
+impl IntGridCsv {
+    fn index(&self, grid_coords: GridCoords) -> Option<usize> {
+        if grid_coords.x < 0
+            || grid_coords.y < 0
+            || grid_coords.x >= self.c_wid
+            || grid_coords.y >= self.c_hei
+        {
+            return None;
+        }
+
+        let ldtk_grid_coords = grid_coords_to_ldtk_grid_coords(grid_coords, self.c_hei);
+
+        Some((ldtk_grid_coords.y * self.c_wid + ldtk_grid_coords.x) as usize)
+    }
+
+    /// Get the IntGrid value at the given coordinates.
+    ///
+    /// Returns `None` if the coordinates are out of the bounds of the layer.
+    pub fn get(&self, grid_coords: GridCoords) -> Option<i32> {
+        self.int_grid_csv.get(self.index(grid_coords)?).copied()
+    }
+
+    /// Set the IntGrid value at the given coordinates, returning the previous value.
+    ///
+    /// Returns `None` and does nothing if the coordinates are out of the bounds of the layer.
+    pub fn set(&mut self, grid_coords: GridCoords, value: i32) -> Option<i32> {
+        let index = self.index(grid_coords)?;
+        let cell = self.int_grid_csv.get_mut(index)?;
+
+        Some(std::mem::replace(cell, value))
+    }
+
+    /// Returns the IntGrid values as rows, from top to bottom.
+    pub fn to_rows(&self) -> Vec<Vec<i32>> {
+        if self.c_wid <= 0 {
+            return Vec::new();
+        }
+
+        self.int_grid_csv
+            .chunks(self.c_wid as usize)
+            .map(<[i32]>::to_vec)
+            .collect()
+    }
+
+    /// Creates an [`IntGridCsv`] from rows of IntGrid values, from top to bottom.
+    ///
+    /// All rows must have the same length.
+    pub fn from_rows(rows: Vec<Vec<i32>>) -> Result<IntGridCsv, IntGridCsvError> {
+        let expected = rows.first().map(Vec::len).unwrap_or_default();
+
+        if let Some((row, values)) = rows
+            .iter()
+            .enumerate()
+            .find(|(_, values)| values.len() != expected)
+        {
+            return Err(IntGridCsvError::RaggedRow {
+                row,
+                len: values.len(),
+                expected,
+            });
+        }
+
+        Ok(IntGridCsv {
+            c_wid: expected as i32,
+            c_hei: rows.len() as i32,
+            int_grid_csv: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Returns the IntGrid values as CSV text, with one line per row from top to bottom.
+    pub fn to_csv(&self) -> String {
+        self.to_rows()
+            .iter()
+            .map(|row| row.iter().map(i32::to_string).collect::<Vec<_>>().join(","))
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    /// Parses an [`IntGridCsv`] from CSV text, with one line per row from top to bottom.
+    ///
+    /// Whitespace around values, blank lines, and trailing commas are ignored, so the output of
+    /// LDtk's "Super simple export" can be parsed too.
+    pub fn from_csv(csv: &str) -> Result<IntGridCsv, IntGridCsvError> {
+        let rows = csv
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(row, line)| {
+                line.trim()
+                    .trim_end_matches(',')
+                    .split(',')
+                    .map(|value| {
+                        value
+                            .trim()
+                            .parse()
+                            .map_err(|error| IntGridCsvError::ParseValue {
+                                row,
+                                value: value.to_string(),
+                                error,
+                            })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        IntGridCsv::from_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accesses_values_by_grid_coords() {
+        let mut int_grid_csv = IntGridCsv {
+            c_wid: 3,
+            c_hei: 2,
+            int_grid_csv: vec![1, 2, 3, 4, 5, 6],
+        };
+
+        assert_eq!(int_grid_csv.get(GridCoords::new(0, 1)), Some(1));
+        assert_eq!(int_grid_csv.get(GridCoords::new(2, 1)), Some(3));
+        assert_eq!(int_grid_csv.get(GridCoords::new(0, 0)), Some(4));
+        assert_eq!(int_grid_csv.get(GridCoords::new(2, 0)), Some(6));
+        assert_eq!(int_grid_csv.get(GridCoords::new(3, 0)), None);
+        assert_eq!(int_grid_csv.get(GridCoords::new(0, -1)), None);
+
+        assert_eq!(int_grid_csv.set(GridCoords::new(1, 0), 0), Some(5));
+        assert_eq!(int_grid_csv.int_grid_csv, vec![1, 2, 3, 4, 0, 6]);
+
+        assert_eq!(int_grid_csv.set(GridCoords::new(1, 2), 7), None);
+        assert_eq!(int_grid_csv.int_grid_csv, vec![1, 2, 3, 4, 0, 6]);
+    }
+
+    #[test]
+    fn csv_round_trips() {
+        let int_grid_csv = IntGridCsv {
+            c_wid: 3,
+            c_hei: 2,
+            int_grid_csv: vec![1, 0, 3, 0, 5, 0],
+        };
+
+        assert_eq!(int_grid_csv.to_rows(), vec![vec![1, 0, 3], vec![0, 5, 0]]);
+        assert_eq!(int_grid_csv.to_csv(), "1,0,3\n0,5,0\n");
+
+        assert_eq!(
+            IntGridCsv::from_csv(&int_grid_csv.to_csv()),
+            Ok(int_grid_csv.clone())
+        );
+        assert_eq!(
+            IntGridCsv::from_csv(" 1, 0, 3,\r\n0,5,0,\n\n"),
+            Ok(int_grid_csv.clone())
+        );
+        assert_eq!(
+            IntGridCsv::from_rows(int_grid_csv.to_rows()),
+            Ok(int_grid_csv)
+        );
+    }
+
+    #[test]
+    fn invalid_csv_is_rejected() {
+        assert_eq!(
+            IntGridCsv::from_csv("1,0\n0"),
+            Err(IntGridCsvError::RaggedRow {
+                row: 1,
+                len: 1,
+                expected: 2,
+            })
+        );
+
+        assert!(matches!(
+            IntGridCsv::from_csv("1,0\n0,x"),
+            Err(IntGridCsvError::ParseValue { row: 1, ref value, .. }) if value == "x"
+        ));
+    }
+}
@@ -1,12 +1,11 @@
 use bevy::{
-    asset::{AssetServer, Assets, Handle},
+    asset::{AssetServer, Assets},
     prelude::Bundle,
-    render::texture::Image,
     sprite::{SpriteBundle, TextureAtlas, TextureAtlasLayout},
 };
 
 use crate::{
-    prelude::{LayerInstance, LdtkEntity, TilesetDefinition},
+    prelude::{EntityDefinition, LayerInstance, LdtkEntity, TilesetDefinition, TilesetInfo},
     utils, EntityInstance,
 };
 
@@ -26,15 +25,16 @@ pub struct LdtkSpriteSheetBundle {
 impl LdtkEntity for LdtkSpriteSheetBundle {
     fn bundle_entity(
         entity_instance: &EntityInstance,
+        _: &EntityDefinition,
         _: &LayerInstance,
-        tileset: Option<&Handle<Image>>,
+        tileset_info: Option<&TilesetInfo>,
         tileset_definition: Option<&TilesetDefinition>,
         _: &AssetServer,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
     ) -> Self {
         utils::sprite_sheet_bundle_from_entity_info(
             entity_instance,
-            tileset,
+            tileset_info.and_then(|tileset_info| tileset_info.image.as_ref()),
             tileset_definition,
             texture_atlases,
             true,
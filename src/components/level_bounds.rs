@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+use crate::{ldtk::Level, resources::LevelOrigin};
+
+/// [`Component`] storing the dimensions of a level, inserted on spawned level entities.
+///
+/// Useful for clamping cameras, drawing minimaps, or finding the level at a point without
+/// re-deriving sizes from the raw level data.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LevelBounds {
+    /// Width and height of the level in pixels.
+    pub size: Vec2,
+    /// Area the level occupies in its LDtk world, in Bevy's coordinate system (i.e., y-up).
+    ///
+    /// When levels are spawned with [`LevelSpawnBehavior::UseWorldTranslation`], this is the area
+    /// the level occupies relative to its world entity.
+    /// Otherwise, levels are spawned at the origin of their world, so see
+    /// [`LevelBounds::local_rect`] instead.
+    ///
+    /// [`LevelSpawnBehavior::UseWorldTranslation`]: crate::prelude::LevelSpawnBehavior::UseWorldTranslation
+    pub world_rect: Rect,
+    /// Corner of the level that the level entity is placed at, see [`LdtkSettings::level_origin`].
+    ///
+    /// [`LdtkSettings::level_origin`]: crate::resources::LdtkSettings::level_origin
+    pub origin: LevelOrigin,
+}
+
+impl From<&Level> for LevelBounds {
+    fn from(level: &Level) -> Self {
+        let size = Vec2::new(level.px_wid as f32, level.px_hei as f32);
+        let min = Vec2::new(level.world_x as f32, -(level.world_y + level.px_hei) as f32);
+
+        LevelBounds {
+            size,
+            world_rect: Rect::from_corners(min, min + size),
+            origin: LevelOrigin::default(),
+        }
+    }
+}
+
+impl LevelBounds {
+    /// The level's own area relative to the level entity, which depends on its [`origin`].
+    ///
+    /// [`origin`]: LevelBounds::origin
+    pub fn local_rect(&self) -> Rect {
+        self.origin.local_rect(self.size)
+    }
+
+    /// Returns the [`GlobalTransform`] of the level's bottom-left corner, given the level
+    /// entity's [`GlobalTransform`].
+    ///
+    /// This is the level entity's transform unless the level was spawned with
+    /// [`LevelOrigin::TopLeft`].
+    ///
+    /// [`GlobalTransform`]: https://docs.rs/bevy/latest/bevy/prelude/struct.GlobalTransform.html
+    pub fn bottom_left_transform(&self, level_transform: &GlobalTransform) -> GlobalTransform {
+        level_transform.mul_transform(Transform::from_translation(
+            self.origin
+                .bottom_left_offset(self.size.y as i32)
+                .extend(0.),
+        ))
+    }
+
+    /// Returns true if the given point in world space lies within the level, given the level
+    /// entity's [`GlobalTransform`].
+    ///
+    /// [`GlobalTransform`]: https://docs.rs/bevy/latest/bevy/prelude/struct.GlobalTransform.html
+    pub fn contains_point(&self, level_transform: &GlobalTransform, point: Vec2) -> bool {
+        let local_point = level_transform
+            .affine()
+            .inverse()
+            .transform_point3(point.extend(level_transform.translation().z));
+
+        self.local_rect().contains(local_point.truncate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_are_flipped_into_bevy_coordinates() {
+        let level = Level {
+            px_wid: 64,
+            px_hei: 32,
+            world_x: 128,
+            world_y: 16,
+            ..Default::default()
+        };
+
+        let level_bounds = LevelBounds::from(&level);
+
+        assert_eq!(level_bounds.size, Vec2::new(64., 32.));
+        assert_eq!(level_bounds.world_rect.min, Vec2::new(128., -48.));
+        assert_eq!(level_bounds.world_rect.max, Vec2::new(192., -16.));
+        assert_eq!(level_bounds.local_rect().max, Vec2::new(64., 32.));
+    }
+
+    #[test]
+    fn contains_point_respects_level_transform() {
+        let level_bounds = LevelBounds {
+            size: Vec2::new(64., 32.),
+            ..Default::default()
+        };
+
+        let level_transform =
+            GlobalTransform::from(Transform::from_xyz(100., 50., 3.).with_scale(Vec3::splat(2.)));
+
+        assert!(level_bounds.contains_point(&level_transform, Vec2::new(101., 51.)));
+        assert!(level_bounds.contains_point(&level_transform, Vec2::new(227., 113.)));
+        assert!(!level_bounds.contains_point(&level_transform, Vec2::new(99., 51.)));
+        assert!(!level_bounds.contains_point(&level_transform, Vec2::new(229., 51.)));
+        assert!(!level_bounds.contains_point(&level_transform, Vec2::new(101., 115.)));
+
+        let top_left_bounds = LevelBounds {
+            origin: LevelOrigin::TopLeft,
+            ..level_bounds
+        };
+
+        assert!(top_left_bounds.contains_point(&level_transform, Vec2::new(101., 49.)));
+        assert!(!top_left_bounds.contains_point(&level_transform, Vec2::new(101., 51.)));
+        assert_eq!(
+            top_left_bounds
+                .bottom_left_transform(&level_transform)
+                .translation(),
+            Vec3::new(100., -14., 3.)
+        );
+    }
+}
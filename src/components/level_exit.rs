@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{EntityIid, LevelIid},
+    ldtk::{
+        ldtk_fields::{LdtkFields, LdtkFieldsError},
+        EntityInstance,
+    },
+};
+
+#[allow(unused_imports)]
+use crate::{
+    commands::LdtkCommandsExt,
+    components::{LevelTraveler, SpawnPoint},
+};
+
+/// [`Component`] for doors, portals, and other exits that lead to another level.
+///
+/// Use [`LdtkCommandsExt::transition_to_level`] to follow an exit.
+/// This selects the target level and, once it has spawned, moves [`LevelTraveler`] entities to
+/// the target spawn point.
+///
+/// In LDtk, exits are most easily authored with an `EntityRef` field pointing to the destination
+/// entity, see [`LevelExit::from_entity_ref_field`].
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LevelExit {
+    /// Level that this exit leads to.
+    pub target_level_iid: LevelIid,
+    /// `iid` of the entity in the target level that [`LevelTraveler`] entities are moved to.
+    ///
+    /// If `None`, they are moved to the [`SpawnPoint`] of the target level, if it has one.
+    pub target_spawn_point: Option<EntityIid>,
+}
+
+impl LevelExit {
+    /// Creates a [`LevelExit`] leading to the given level and spawn point.
+    pub fn new(target_level_iid: LevelIid, target_spawn_point: Option<EntityIid>) -> LevelExit {
+        LevelExit {
+            target_level_iid,
+            target_spawn_point,
+        }
+    }
+
+    /// Creates a [`LevelExit`] from a non-null `EntityRef` field of an LDtk entity.
+    ///
+    /// The referenced entity is used as the spawn point, and its level as the target level.
+    ///
+    /// # Errors
+    /// Returns an error under the same conditions as [`LdtkFields::get_entity_ref_field`].
+    pub fn from_entity_ref_field(
+        entity_instance: &EntityInstance,
+        identifier: &str,
+    ) -> Result<LevelExit, LdtkFieldsError> {
+        let entity_ref = entity_instance.get_entity_ref_field(identifier)?;
+
+        Ok(LevelExit {
+            target_level_iid: LevelIid::new(entity_ref.level_iid.clone()),
+            target_spawn_point: Some(EntityIid::new(entity_ref.entity_iid.clone())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::{
+        ldtk_fields::field_instance_from_value, FieldValue, ReferenceToAnEntityInstance,
+    };
+
+    #[test]
+    fn level_exit_from_entity_ref_field() {
+        let entity_instance = EntityInstance {
+            field_instances: vec![field_instance_from_value(
+                "destination",
+                FieldValue::EntityRef(Some(ReferenceToAnEntityInstance {
+                    entity_iid: "spawn-iid".to_string(),
+                    level_iid: "level-iid".to_string(),
+                    ..default()
+                })),
+            )],
+            ..default()
+        };
+
+        assert_eq!(
+            LevelExit::from_entity_ref_field(&entity_instance, "destination"),
+            Ok(LevelExit::new(
+                LevelIid::new("level-iid"),
+                Some(EntityIid::new("spawn-iid"))
+            ))
+        );
+        assert!(LevelExit::from_entity_ref_field(&entity_instance, "missing").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::ldtk::Level;
+
+#[allow(unused_imports)]
+use crate::components::LevelBounds;
+
+/// [`Component`] storing general information about a level, inserted on spawned level entities.
+///
+/// Together with the level entity's [`LevelIid`], [`LevelBounds`] and [`GlobalTransform`], this
+/// is enough to answer questions like "which level is the player standing in" without accessing
+/// the [`LdtkProject`] asset.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+/// [`GlobalTransform`]: https://docs.rs/bevy/latest/bevy/prelude/struct.GlobalTransform.html
+/// [`LevelIid`]: crate::prelude::LevelIid
+/// [`LdtkProject`]: crate::prelude::LdtkProject
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LevelInfo {
+    /// User defined unique identifier of the level.
+    pub identifier: String,
+    /// Unique Int identifier of the level.
+    pub uid: i32,
+    /// Index that represents the "depth" of the level in the world.
+    pub world_depth: i32,
+}
+
+impl From<&Level> for LevelInfo {
+    fn from(level: &Level) -> Self {
+        LevelInfo {
+            identifier: level.identifier.clone(),
+            uid: level.uid,
+            world_depth: level.world_depth,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn info_is_copied_from_level() {
+        let level = Level {
+            identifier: "Cellar".to_string(),
+            uid: 7,
+            world_depth: -1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            LevelInfo::from(&level),
+            LevelInfo {
+                identifier: "Cellar".to_string(),
+                uid: 7,
+                world_depth: -1,
+            }
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::LevelIid;
+
+#[allow(unused_imports)]
+use crate::components::{LdtkWorldBundle, LevelSet};
+
+/// [`Component`] that offsets the translation of particular levels spawned in an
+/// [`LdtkWorldBundle`].
+///
+/// Insert this on the world entity alongside its [`LevelSet`].
+/// The offset of a level is added to the translation it would normally spawn with, relative to
+/// the world entity.
+/// Offsets are only applied when a level spawns, so move the level entity's [`Transform`]
+/// directly to reposition an already-spawned level.
+///
+/// Note that levels are always children of their world entity, so the world's own [`Transform`]
+/// (including scale and rotation) applies to all of its levels.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Component.html
+/// [`Transform`]: https://docs.rs/bevy/latest/bevy/prelude/struct.Transform.html
+#[derive(Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LevelOffsets {
+    pub offsets: HashMap<LevelIid, Vec3>,
+}
+
+impl LevelOffsets {
+    /// Sets the offset of the level with the given iid, returning `self` for chaining.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_ecs_ldtk::prelude::*;
+    ///
+    /// let level_offsets = LevelOffsets::default()
+    ///     .with_offset(LevelIid::new("minigame-level"), Vec3::new(1024., 0., 0.));
+    /// # assert_eq!(
+    /// #     level_offsets.get(&LevelIid::new("minigame-level")),
+    /// #     Vec3::new(1024., 0., 0.)
+    /// # );
+    /// ```
+    pub fn with_offset(mut self, level_iid: LevelIid, offset: Vec3) -> Self {
+        self.offsets.insert(level_iid, offset);
+        self
+    }
+
+    /// Returns the offset of the level with the given iid, or zero if it has none.
+    pub fn get(&self, level_iid: &LevelIid) -> Vec3 {
+        self.offsets.get(level_iid).copied().unwrap_or(Vec3::ZERO)
+    }
+}
@@ -5,13 +5,18 @@ use crate::LevelIid;
 
 /// [`Component`] that determines the desired levels to be spawned in an [`LdtkWorldBundle`].
 ///
+/// If the world has a [`LevelSelection`] component, or the [`LevelSelection`] resource exists,
+/// this is overwritten according to that selection.
+///
 /// For more explanation and comparison of options for selecting levels to spawn, see the
 /// [*Level Selection*](https://trouv.github.io/bevy_ecs_ldtk/v0.10.0/explanation/level-selection.html) <!-- x-release-please-version -->
 /// chapter of the `bevy_ecs_ldtk` book.
 ///
 /// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Component.html
 /// [`LdtkWorldBundle`]: crate::prelude::LdtkWorldBundle
-#[derive(Clone, Eq, PartialEq, Debug, Default, Component)]
+/// [`LevelSelection`]: crate::prelude::LevelSelection
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
 pub struct LevelSet {
     pub iids: HashSet<LevelIid>,
 }
@@ -2,17 +2,54 @@
 mod entity_iid;
 pub use entity_iid::EntityIid;
 
+mod entity_instance_ref;
+pub use entity_instance_ref::EntityInstanceRef;
+
 mod level_iid;
 pub use level_iid::LevelIid;
 
+mod spawn_point;
+pub use spawn_point::{LevelTraveler, SpawnPoint, SpawnPointQuery};
+
+mod level_bounds;
+pub use level_bounds::LevelBounds;
+
+mod level_info;
+pub use level_info::LevelInfo;
+
 mod level_set;
 pub use level_set::LevelSet;
 
+mod level_offsets;
+pub use level_offsets::LevelOffsets;
+
+mod level_exit;
+pub use level_exit::LevelExit;
+
+mod int_grid_csv;
+pub use int_grid_csv::{IntGridCsv, IntGridCsvError};
+
+mod tile_animation;
+pub use tile_animation::TileAnimation;
+
+mod tile_collision_shape;
+pub use tile_collision_shape::TileCollisionShape;
+
+mod sprite_sheet_animation;
+pub use sprite_sheet_animation::SpriteSheetAnimation;
+
+mod transform_from_fields;
+pub use transform_from_fields::TransformFromFields;
+
+mod y_sort;
+pub use y_sort::YSort;
+
 mod ldtk_sprite_sheet_bundle;
 pub use ldtk_sprite_sheet_bundle::LdtkSpriteSheetBundle;
 
 pub use crate::ldtk::EntityInstance;
 use crate::{
+    assets::LdtkLevel,
     ldtk::{LayerInstance, Type},
     prelude::LdtkProject,
     utils::ldtk_grid_coords_to_grid_coords,
@@ -40,6 +77,12 @@ pub struct IntGridCell {
     pub value: i32,
 }
 
+impl From<&IntGridCell> for IntGridCell {
+    fn from(value: &IntGridCell) -> Self {
+        *value
+    }
+}
+
 /// [`Component`] that indicates that an ldtk entity should be a child of the world, not their layer.
 ///
 /// For a more detailed explanation, please see the
@@ -68,6 +111,18 @@ impl Worldly {
     }
 }
 
+/// [Component] holding the world entity that a [Worldly] entity belongs to.
+///
+/// Inserted automatically when the worldly entity is adopted by its world.
+/// Worldly entities of a level spawned with an [LdtkLevelBundle] belong to the level itself.
+///
+/// Only the worldly entities of a level's own world prevent it from spawning them again, so the
+/// same project can be spawned by multiple worlds at once.
+/// This stays accurate even if the worldly entity is given a different parent.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub struct WorldlyOf(pub Entity);
+
 /// [Component] that stores grid-based coordinate information.
 ///
 /// For Tile, AutoTile, and IntGrid layers, all tiles have this component by default.
@@ -186,6 +241,21 @@ impl GridCoords {
     }
 }
 
+/// [Component] storing the "smart color" of an LDtk entity, as set in the editor.
+///
+/// Implements `From<&EntityInstance>`, so it can be added to an [`LdtkEntity`] bundle with the
+/// `#[from_entity_instance]` field attribute.
+/// This is useful for driving placeholder visuals or tinted variants from editor data.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deref, DerefMut, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct SmartColor(pub Color);
+
+impl From<&EntityInstance> for SmartColor {
+    fn from(entity_instance: &EntityInstance) -> Self {
+        SmartColor(entity_instance.smart_color)
+    }
+}
+
 /// [Component] for storing user-defined custom data for a paticular tile in an LDtk tileset
 /// definition.
 ///
@@ -311,6 +381,17 @@ impl From<&LayerInstance> for LayerMetadata {
 #[reflect(Component)]
 pub struct Respawn;
 
+/// [Component] holding the composite image of a level that is waiting for it to load before
+/// spawning.
+///
+/// Inserted and removed automatically for levels rendered with their composite image, see
+/// [`SimplifiedLevelRendering`].
+/// If the image fails to load, the level is spawned normally instead.
+///
+/// [`SimplifiedLevelRendering`]: crate::resources::SimplifiedLevelRendering
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component)]
+pub struct PendingCompositeImage(pub Handle<Image>);
+
 #[derive(Copy, Clone, Debug, Default, Bundle)]
 pub(crate) struct TileGridBundle {
     pub tile_bundle: TileBundle,
@@ -327,11 +408,46 @@ pub(crate) struct EntityInstanceBundle {
     pub entity_instance: EntityInstance,
 }
 
+/// [Component] holding the project of a level spawned with an [LdtkLevelBundle].
+///
+/// Levels of an [LdtkWorldBundle] get their project from the world instead.
+/// This is a separate component from the world's `Handle<LdtkProject>`, so that standalone levels
+/// aren't mistaken for worlds.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct LevelProject(pub Handle<LdtkProject>);
+
+/// `Bundle` for spawning a single LDtk level on its own.
+///
+/// Unlike [LdtkWorldBundle], this doesn't require a [LevelSet] or [LevelSelection].
+/// Once the level's project has finished loading, the level entity will get a [LevelIid] and a
+/// [LevelProject], and spawn just like the levels of an [LdtkWorldBundle], but with its layers as
+/// its own children.
+/// Changing the handle to a different level will respawn it.
+///
+/// Its [Worldly] entities are adopted by the level's parent, or become root entities if it has
+/// none.
+#[derive(Clone, Default, Bundle)]
+pub struct LdtkLevelBundle {
+    pub level: Handle<LdtkLevel>,
+    pub transform: Transform,
+    pub global_transform: GlobalTransform,
+    pub visibility: Visibility,
+    pub inherited_visibility: InheritedVisibility,
+    pub view_visibility: ViewVisibility,
+}
+
 /// `Bundle` for spawning LDtk worlds and their levels. The main bundle for using this plugin.
 ///
 /// For a more detailed explanation of the resulting world, please see the
 /// [*Anatomy of the World*](https://trouv.github.io/bevy_ecs_ldtk/v0.10.0/explanation/anatomy-of-the-world.html) <!-- x-release-please-version -->
 /// chapter of the `bevy_ecs_ldtk` book.
+///
+/// Multiple worlds may use the same project, each with their own [Transform], [LevelSet] and
+/// [Worldly] entities.
+///
+/// Levels, layers, tiles and entities are all spawned with visibility components, so setting the
+/// [Visibility] of the world, or of any level or layer entity, also applies to its descendants.
 #[derive(Clone, Default, Bundle)]
 pub struct LdtkWorldBundle {
     pub ldtk_handle: Handle<LdtkProject>,
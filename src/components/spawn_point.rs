@@ -0,0 +1,108 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::components::LevelIid;
+
+#[allow(unused_imports)]
+use crate::{
+    commands::LdtkCommandsExt,
+    components::LevelExit,
+    resources::{LdtkSettings, SpawnPointEvent},
+};
+
+/// [`Component`] marking LDtk entities that are the spawn point of their level.
+///
+/// Inserted on entities whose identifier is [`LdtkSettings::spawn_point_identifier`].
+/// Once the level has spawned and its transforms have been propagated, a [`SpawnPointEvent`] is
+/// sent with the spawn point's translation.
+/// The spawn points of spawned levels can also be looked up at any time with [`SpawnPointQuery`].
+///
+/// Following a [`LevelExit`] without a target spawn point moves [`LevelTraveler`]s to the spawn
+/// point of the target level.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct SpawnPoint {
+    /// Iid of the level this spawn point belongs to.
+    pub level_iid: LevelIid,
+}
+
+/// [`Component`] marking entities that are moved to the target spawn point when following a
+/// [`LevelExit`] with [`LdtkCommandsExt::transition_to_level`], like the player.
+///
+/// Only the x and y translation of the entity is changed.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct LevelTraveler;
+
+/// [`SystemParam`] for looking up the spawn points of spawned levels.
+///
+/// Requires [`LdtkSettings::spawn_point_identifier`] to be set.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// fn place_player(
+///     level_selection: Res<LevelSelection>,
+///     spawn_points: SpawnPointQuery,
+///     mut player_query: Query<&mut Transform, With<Player>>,
+/// ) {
+///     let LevelSelection::Iid(level_iid) = level_selection.as_ref() else {
+///         return;
+///     };
+///
+///     if let Some(translation) = spawn_points.translation(level_iid) {
+///         for mut transform in &mut player_query {
+///             transform.translation = translation.extend(transform.translation.z);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`SystemParam`]: https://docs.rs/bevy/latest/bevy/ecs/system/trait.SystemParam.html
+#[derive(SystemParam)]
+pub struct SpawnPointQuery<'w, 's> {
+    spawn_point_query: Query<'w, 's, (Entity, &'static SpawnPoint, &'static GlobalTransform)>,
+}
+
+impl<'w, 's> SpawnPointQuery<'w, 's> {
+    /// Returns the spawn point entity of the level with the given iid, if it has spawned.
+    pub fn entity(&self, level_iid: &LevelIid) -> Option<Entity> {
+        self.spawn_point_query
+            .iter()
+            .find(|(_, spawn_point, _)| spawn_point.level_iid == *level_iid)
+            .map(|(entity, ..)| entity)
+    }
+
+    /// Returns the world translation of the spawn point of the level with the given iid, if it
+    /// has spawned.
+    ///
+    /// Only accurate once the level's transforms have been propagated, see
+    /// [`LevelEvent::Transformed`].
+    ///
+    /// [`LevelEvent::Transformed`]: crate::resources::LevelEvent::Transformed
+    pub fn translation(&self, level_iid: &LevelIid) -> Option<Vec2> {
+        self.spawn_point_query
+            .iter()
+            .find(|(_, spawn_point, _)| spawn_point.level_iid == *level_iid)
+            .map(|(.., transform)| transform.translation().truncate())
+    }
+
+    /// Iterates over the spawn point entities of all spawned levels, with their level iids and
+    /// world translations.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &LevelIid, Vec2)> {
+        self.spawn_point_query
+            .iter()
+            .map(|(entity, spawn_point, transform)| {
+                (
+                    entity,
+                    &spawn_point.level_iid,
+                    transform.translation().truncate(),
+                )
+            })
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+use crate::ldtk::{ldtk_fields::LdtkFields, EntityInstance};
+
+#[allow(unused_imports)]
+use crate::app::LdtkEntity;
+
+/// [`Component`] that animates a sprite sheet by cycling its [`TextureAtlas`] index through a
+/// list of frames.
+///
+/// Can be configured from the fields of an LDtk entity with
+/// [`SpriteSheetAnimation::from_entity_fields`], or with the `#[sprite_sheet_animation]` attribute
+/// macro for `#[derive(LdtkEntity)]`.
+/// See [`LdtkEntity#sprite_sheet_animation`] for more info.
+///
+/// All entities with the same animation play in sync.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct SpriteSheetAnimation {
+    /// Texture atlas indices to cycle through.
+    pub frames: Vec<usize>,
+    /// Frames per second.
+    pub fps: f32,
+}
+
+impl SpriteSheetAnimation {
+    /// Creates a [`SpriteSheetAnimation`] from the fields of an LDtk entity.
+    ///
+    /// `frames_field` should be the identifier of an `Array<Int>` field, and `fps_field` should be
+    /// the identifier of a `Float` or `Int` field.
+    /// If either field is missing, null, or the wrong type, or any frame is negative, a warning is
+    /// logged and the animation will have no frames.
+    pub fn from_entity_fields(
+        entity_instance: &EntityInstance,
+        frames_field: &str,
+        fps_field: &str,
+    ) -> SpriteSheetAnimation {
+        let frames = match entity_instance.iter_ints_field(frames_field) {
+            Ok(frames) => frames
+                .map(|frame| usize::try_from(*frame).map_err(|_| *frame))
+                .collect::<Result<Vec<_>, _>>(),
+            Err(e) => {
+                warn!("unable to create SpriteSheetAnimation frames: {e}");
+                return SpriteSheetAnimation::default();
+            }
+        };
+
+        let frames = match frames {
+            Ok(frames) => frames,
+            Err(frame) => {
+                warn!("unable to create SpriteSheetAnimation frames: {frame} is not a valid texture atlas index");
+                return SpriteSheetAnimation::default();
+            }
+        };
+
+        let fps = match (
+            entity_instance.get_float_field(fps_field),
+            entity_instance.get_int_field(fps_field),
+        ) {
+            (Ok(fps), _) => *fps,
+            (_, Ok(fps)) => *fps as f32,
+            (Err(e), _) => {
+                warn!("unable to create SpriteSheetAnimation fps: {e}");
+                return SpriteSheetAnimation::default();
+            }
+        };
+
+        SpriteSheetAnimation { frames, fps }
+    }
+
+    /// Returns the texture atlas index that should be displayed after `elapsed_seconds`.
+    pub fn frame_at(&self, elapsed_seconds: f32) -> Option<usize> {
+        if self.frames.is_empty() || self.fps <= 0. {
+            return None;
+        }
+
+        let frame_index = (elapsed_seconds * self.fps).max(0.) as usize % self.frames.len();
+
+        self.frames.get(frame_index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::{ldtk_fields::field_instance_from_value, FieldValue};
+
+    #[test]
+    fn animation_is_configured_from_fields() {
+        let entity_instance = EntityInstance {
+            field_instances: vec![
+                field_instance_from_value(
+                    "frames",
+                    FieldValue::Ints(vec![Some(3), Some(4), Some(5)]),
+                ),
+                field_instance_from_value("fps", FieldValue::Int(Some(10))),
+            ],
+            ..default()
+        };
+
+        let animation = SpriteSheetAnimation::from_entity_fields(&entity_instance, "frames", "fps");
+
+        assert_eq!(
+            animation,
+            SpriteSheetAnimation {
+                frames: vec![3, 4, 5],
+                fps: 10.
+            }
+        );
+        assert_eq!(animation.frame_at(0.25), Some(5));
+        assert_eq!(animation.frame_at(0.35), Some(3));
+
+        assert_eq!(
+            SpriteSheetAnimation::from_entity_fields(&entity_instance, "frames", "speed"),
+            SpriteSheetAnimation::default()
+        );
+    }
+
+    #[test]
+    fn negative_frames_are_rejected() {
+        let entity_instance = EntityInstance {
+            field_instances: vec![
+                field_instance_from_value("frames", FieldValue::Ints(vec![Some(3), Some(-1)])),
+                field_instance_from_value("fps", FieldValue::Int(Some(10))),
+            ],
+            ..default()
+        };
+
+        assert_eq!(
+            SpriteSheetAnimation::from_entity_fields(&entity_instance, "frames", "fps"),
+            SpriteSheetAnimation::default()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use bevy_ecs_tilemap::tiles::AnimatedTile;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use crate::{
+    components::TileMetadata,
+    resources::{LdtkSettings, TileAnimations},
+};
+
+/// Animation descriptor read from tile custom data.
+///
+/// When [`LdtkSettings::tile_animations`] is [`TileAnimations::FromCustomData`], tiles whose
+/// [`TileMetadata`] describes an animation are given `bevy_ecs_tilemap`'s [`AnimatedTile`], which
+/// is animated by the tilemap renderer.
+/// This lets animations like water or torches be authored alongside the tileset in LDtk, by giving
+/// the first frame's tile custom data like:
+/// ```json
+/// { "frames": [12, 13, 14, 15], "fps": 8 }
+/// ```
+/// Frames are tile ids in the tile's tileset, and must be consecutive, since [`AnimatedTile`]
+/// animates through a range of tiles.
+///
+/// All tiles with the same animation play in sync.
+/// You can also insert [`AnimatedTile`] on tiles yourself.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TileAnimation {
+    /// Tile ids to cycle through.
+    pub frames: Vec<u32>,
+    /// Frames per second.
+    pub fps: f32,
+}
+
+impl TileAnimation {
+    /// Parses an animation descriptor from tile custom data.
+    ///
+    /// Returns `None` if the data is not a JSON object with `frames` and `fps`, or if it describes
+    /// no frames.
+    pub fn from_custom_data(data: &str) -> Option<TileAnimation> {
+        serde_json::from_str::<TileAnimation>(data)
+            .ok()
+            .filter(|animation| !animation.frames.is_empty() && animation.fps > 0.)
+    }
+
+    /// Returns the [`AnimatedTile`] that plays this animation.
+    ///
+    /// Returns `None` if the frames are not consecutive tile ids.
+    pub fn animated_tile(&self) -> Option<AnimatedTile> {
+        let start = *self.frames.first()?;
+
+        let consecutive = self
+            .frames
+            .iter()
+            .zip(start..)
+            .all(|(&frame, expected)| frame == expected);
+
+        consecutive.then(|| AnimatedTile {
+            start,
+            end: start + self.frames.len() as u32,
+            // AnimatedTile's speed is the number of loops through all frames per second.
+            speed: self.fps / self.frames.len() as f32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animation_parses_from_custom_data() {
+        assert_eq!(
+            TileAnimation::from_custom_data(r#"{ "frames": [12, 13, 14], "fps": 8, "other": 1 }"#),
+            Some(TileAnimation {
+                frames: vec![12, 13, 14],
+                fps: 8.
+            })
+        );
+        assert_eq!(TileAnimation::from_custom_data("solid"), None);
+        assert_eq!(
+            TileAnimation::from_custom_data(r#"{ "frames": [], "fps": 8 }"#),
+            None
+        );
+    }
+
+    #[test]
+    fn consecutive_frames_become_animated_tiles() {
+        let animated_tile = TileAnimation {
+            frames: vec![4, 5, 6, 7],
+            fps: 8.,
+        }
+        .animated_tile()
+        .unwrap();
+
+        assert_eq!(animated_tile.start, 4);
+        assert_eq!(animated_tile.end, 8);
+        assert_eq!(animated_tile.speed, 2.);
+
+        let scattered = TileAnimation {
+            frames: vec![4, 6, 5],
+            fps: 8.,
+        };
+        assert!(scattered.animated_tile().is_none());
+    }
+}
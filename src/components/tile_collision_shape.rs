@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use crate::components::TileMetadata;
+
+/// [`Component`] describing the collision shape of a tile, for building precise colliders for
+/// slopes, half-tiles, and other partially-solid tiles.
+///
+/// This is inserted automatically on tiles whose [`TileMetadata`] describes a collision shape.
+/// Shapes are authored alongside the tileset in LDtk, by giving the tile custom data like:
+/// ```json
+/// { "collision": "full" }
+/// ```
+/// ```json
+/// { "collision": { "rect": { "min": [0, 0.5], "max": [1, 1] } } }
+/// ```
+/// ```json
+/// { "collision": { "polygon": [[0, 1], [1, 0], [1, 1]] } }
+/// ```
+/// Coordinates are fractions of the tile's size, with `[0, 0]` at the tile's top-left corner and
+/// y pointing down, as in LDtk.
+/// The examples above are a solid tile, the bottom half of a tile, and a slope rising to the right.
+///
+/// Use [`TileCollisionShape::local_points`] to get the shape in the tile entity's local space.
+/// You can also insert this component on tiles yourself.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TileCollisionShape {
+    /// The whole tile is solid.
+    #[default]
+    Full,
+    /// A rectangular part of the tile is solid.
+    Rect {
+        /// Top-left corner of the rectangle.
+        min: [f32; 2],
+        /// Bottom-right corner of the rectangle.
+        max: [f32; 2],
+    },
+    /// A convex polygon of the tile is solid.
+    Polygon(Vec<[f32; 2]>),
+}
+
+#[derive(Deserialize)]
+struct TileCollisionData {
+    collision: TileCollisionShape,
+}
+
+impl TileCollisionShape {
+    /// Parses a collision shape from tile custom data.
+    ///
+    /// Returns `None` if the data is not a JSON object with a valid `collision` shape, or if the
+    /// shape is a polygon with fewer than 3 points.
+    pub fn from_custom_data(data: &str) -> Option<TileCollisionShape> {
+        serde_json::from_str::<TileCollisionData>(data)
+            .ok()
+            .map(|collision_data| collision_data.collision)
+            .filter(
+                |shape| !matches!(shape, TileCollisionShape::Polygon(points) if points.len() < 3),
+            )
+    }
+
+    /// Returns the vertices of the shape relative to the center of a tile of the given size, with
+    /// y pointing up, matching the local space of tile entities.
+    ///
+    /// Rectangles are returned as 4 vertices, counter-clockwise from their bottom-left corner.
+    pub fn local_points(&self, tile_size: Vec2) -> Vec<Vec2> {
+        let to_local = |[x, y]: [f32; 2]| Vec2::new(x - 0.5, 0.5 - y) * tile_size;
+
+        match self {
+            TileCollisionShape::Full => TileCollisionShape::Rect {
+                min: [0., 0.],
+                max: [1., 1.],
+            }
+            .local_points(tile_size),
+            TileCollisionShape::Rect { min, max } => vec![
+                to_local([min[0], max[1]]),
+                to_local(*max),
+                to_local([max[0], min[1]]),
+                to_local(*min),
+            ],
+            TileCollisionShape::Polygon(points) => points.iter().copied().map(to_local).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collision_shape_parses_from_custom_data() {
+        assert_eq!(
+            TileCollisionShape::from_custom_data(r#"{ "collision": "full", "other": 1 }"#),
+            Some(TileCollisionShape::Full)
+        );
+        assert_eq!(
+            TileCollisionShape::from_custom_data(
+                r#"{ "collision": { "rect": { "min": [0, 0.5], "max": [1, 1] } } }"#
+            ),
+            Some(TileCollisionShape::Rect {
+                min: [0., 0.5],
+                max: [1., 1.]
+            })
+        );
+        assert_eq!(
+            TileCollisionShape::from_custom_data(
+                r#"{ "collision": { "polygon": [[0, 1], [1, 0]] } }"#
+            ),
+            None
+        );
+        assert_eq!(TileCollisionShape::from_custom_data("solid"), None);
+    }
+
+    #[test]
+    fn local_points_are_centered_with_y_up() {
+        let tile_size = Vec2::splat(16.);
+
+        assert_eq!(
+            TileCollisionShape::Rect {
+                min: [0., 0.5],
+                max: [1., 1.]
+            }
+            .local_points(tile_size),
+            vec![
+                Vec2::new(-8., -8.),
+                Vec2::new(8., -8.),
+                Vec2::new(8., 0.),
+                Vec2::new(-8., 0.),
+            ]
+        );
+
+        assert_eq!(
+            TileCollisionShape::Polygon(vec![[0., 1.], [1., 0.], [1., 1.]]).local_points(tile_size),
+            vec![Vec2::new(-8., -8.), Vec2::new(8., 8.), Vec2::new(8., -8.)]
+        );
+    }
+}
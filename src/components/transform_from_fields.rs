@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+
+use crate::ldtk::{
+    ldtk_fields::{LdtkFields, LdtkFieldsError},
+    EntityInstance,
+};
+
+#[allow(unused_imports)]
+use crate::app::LdtkEntity;
+
+/// [`Component`] that rotates and scales an LDtk entity according to its fields, for props
+/// authored with a per-instance rotation or scale in LDtk.
+///
+/// Applied to the entity's [`Transform`] once, when the component is added.
+/// The rotation replaces the entity's rotation, while the scale multiplies the scale the entity
+/// already has from its size in LDtk.
+///
+/// Can be configured from the fields of an LDtk entity with
+/// [`TransformFromFields::from_entity_fields`], or with the `#[transform_from_fields]` attribute
+/// macro for `#[derive(LdtkEntity)]`.
+/// See [`LdtkEntity#transform_from_fields`] for more info.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+/// [`Transform`]: https://docs.rs/bevy/latest/bevy/transform/components/struct.Transform.html
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct TransformFromFields {
+    /// Clockwise rotation in degrees, as it would appear in LDtk.
+    pub rotation: Option<f32>,
+    /// Uniform scale.
+    pub scale: Option<f32>,
+}
+
+/// Reads a `Float` or `Int` field, warning if it exists but has some other type.
+fn get_number_field(entity_instance: &EntityInstance, identifier: &str) -> Option<f32> {
+    match (
+        entity_instance.get_float_field(identifier),
+        entity_instance.get_int_field(identifier),
+    ) {
+        (Ok(value), _) => Some(*value),
+        (_, Ok(value)) => Some(*value as f32),
+        (
+            Err(LdtkFieldsError::WrongFieldType { .. }),
+            Err(e @ LdtkFieldsError::WrongFieldType { .. }),
+        ) => {
+            warn!("unable to read TransformFromFields field: {e}");
+            None
+        }
+        _ => None,
+    }
+}
+
+impl TransformFromFields {
+    /// Creates a [`TransformFromFields`] from the fields of an LDtk entity.
+    ///
+    /// `rotation_field` and `scale_field` should be the identifiers of `Float` or `Int` fields.
+    /// Missing or null fields are left as `None`, so they don't affect the transform.
+    pub fn from_entity_fields(
+        entity_instance: &EntityInstance,
+        rotation_field: &str,
+        scale_field: &str,
+    ) -> TransformFromFields {
+        TransformFromFields {
+            rotation: get_number_field(entity_instance, rotation_field),
+            scale: get_number_field(entity_instance, scale_field),
+        }
+    }
+
+    /// Applies the rotation and scale to the given [`Transform`].
+    ///
+    /// [`Transform`]: https://docs.rs/bevy/latest/bevy/transform/components/struct.Transform.html
+    pub fn apply(&self, transform: &mut Transform) {
+        if let Some(rotation) = self.rotation {
+            // LDtk's y axis points down, so clockwise rotations are negative in bevy
+            transform.rotation = Quat::from_rotation_z(-rotation.to_radians());
+        }
+
+        if let Some(scale) = self.scale {
+            transform.scale.x *= scale;
+            transform.scale.y *= scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::{ldtk_fields::field_instance_from_value, FieldValue};
+
+    #[test]
+    fn transform_is_configured_from_fields() {
+        let entity_instance = EntityInstance {
+            field_instances: vec![
+                field_instance_from_value("angle", FieldValue::Int(Some(90))),
+                field_instance_from_value("size", FieldValue::Float(Some(2.))),
+            ],
+            ..default()
+        };
+
+        let transform_from_fields =
+            TransformFromFields::from_entity_fields(&entity_instance, "angle", "size");
+
+        assert_eq!(
+            transform_from_fields,
+            TransformFromFields {
+                rotation: Some(90.),
+                scale: Some(2.),
+            }
+        );
+
+        let mut transform = Transform::from_xyz(1., 2., 3.).with_scale(Vec3::new(1.5, 1., 1.));
+        transform_from_fields.apply(&mut transform);
+
+        assert_eq!(transform.translation, Vec3::new(1., 2., 3.));
+        assert_eq!(transform.scale, Vec3::new(3., 2., 1.));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2), 1e-6));
+
+        assert_eq!(
+            TransformFromFields::from_entity_fields(&entity_instance, "rotation", "scale"),
+            TransformFromFields::default()
+        );
+    }
+}
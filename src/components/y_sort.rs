@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+#[allow(unused_imports)]
+use crate::{app::LdtkEntity, resources::YSorting};
+
+/// [`Component`] that keeps an entity's z translation in sync with its y translation, so that
+/// entities lower on screen are drawn in front, as usual in top-down games.
+///
+/// Only takes effect while [`YSorting::Enabled`] is set in the [`LdtkSettings`].
+/// The z translation is updated whenever the entity's [`Transform`] changes.
+///
+/// Can be added to LDtk entities with the `#[y_sort]` attribute macro for `#[derive(LdtkEntity)]`.
+/// See [`LdtkEntity#y_sort`] for more info.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/component/trait.Component.html
+/// [`Transform`]: https://docs.rs/bevy/latest/bevy/transform/components/struct.Transform.html
+/// [`LdtkSettings`]: crate::resources::LdtkSettings
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct YSort {
+    /// Offset added to the entity's y translation before sorting, e.g. to sort by a sprite's feet
+    /// rather than its center.
+    pub y_offset: f32,
+}
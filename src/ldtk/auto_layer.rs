@@ -0,0 +1,509 @@
+//! Functions for evaluating LDtk auto-layer rules against IntGrid data at runtime.
+//!
+//! LDtk bakes the results of auto-layer rules into the `autoLayerTiles` of each layer when saving,
+//! which is what gets spawned normally.
+//! The functions in this module re-evaluate the rules authored in the project, so that IntGrid
+//! data that was generated or modified at runtime can be auto-tiled as well.
+//!
+//! Rule patterns, IntGrid value groups, chances, modulos, checker modes, flips, offsets, and
+//! single/stamp tile modes are supported.
+//! Perlin filters and biomes are not, so rules using them are evaluated without them.
+//! Random choices are deterministic per layer seed and cell, but may differ from the editor's.
+use crate::ldtk::{
+    AutoLayerRuleDefinition, Checker, Definitions, LayerDefinition, LayerInstance, TileInstance,
+    TileMode, TilesetDefinition,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Pattern value matching any nonzero IntGrid value (or an empty cell, if negative).
+const AUTO_LAYER_ANYTHING: i32 = 1000001;
+
+/// Deterministic pseudo-random number in `0..max` derived from a seed and cell coordinates.
+fn rand_seed_coords(seed: i32, x: i32, y: i32, max: i32) -> i32 {
+    if max <= 0 {
+        return 0;
+    }
+
+    let mut h = seed
+        .wrapping_add(x.wrapping_mul(374761393))
+        .wrapping_add(y.wrapping_mul(668265263));
+    h = (h ^ (h >> 13)).wrapping_mul(1274126177);
+    (h ^ (h >> 16)).rem_euclid(max)
+}
+
+/// IntGrid data that rules are matched against.
+struct RuleSource<'a> {
+    int_grid_csv: &'a [i32],
+    c_wid: i32,
+    c_hei: i32,
+    group_uids: HashMap<i32, i32>,
+}
+
+impl RuleSource<'_> {
+    fn get(&self, cx: i32, cy: i32) -> Option<i32> {
+        if cx < 0 || cy < 0 || cx >= self.c_wid || cy >= self.c_hei {
+            return None;
+        }
+
+        self.int_grid_csv
+            .get((cy * self.c_wid + cx) as usize)
+            .copied()
+    }
+
+    fn group_uid(&self, value: i32) -> i32 {
+        self.group_uids.get(&value).copied().unwrap_or_default()
+    }
+}
+
+fn passes_modulo(rule: &AutoLayerRuleDefinition, cx: i32, cy: i32) -> bool {
+    let x_modulo = rule.x_modulo.max(1);
+    let y_modulo = rule.y_modulo.max(1);
+
+    let y_passes = match rule.checker {
+        Checker::Vertical => (cy + (cx / x_modulo) % 2) % y_modulo == 0,
+        _ => (cy - rule.y_offset) % y_modulo == 0,
+    };
+
+    let x_passes = match rule.checker {
+        Checker::Horizontal => (cx + (cy / y_modulo) % 2) % x_modulo == 0,
+        _ => (cx - rule.x_offset) % x_modulo == 0,
+    };
+
+    y_passes && x_passes
+}
+
+fn matches(
+    rule: &AutoLayerRuleDefinition,
+    source: &RuleSource,
+    seed: i32,
+    cx: i32,
+    cy: i32,
+    dir_x: i32,
+    dir_y: i32,
+) -> bool {
+    if rule.chance <= 0.
+        || (rule.chance < 1.
+            && rand_seed_coords(seed.wrapping_add(rule.uid), cx, cy, 100) as f32
+                >= rule.chance * 100.)
+    {
+        return false;
+    }
+
+    let radius = rule.size / 2;
+
+    for py in 0..rule.size {
+        for px in 0..rule.size {
+            let Some(&pattern_value) = rule.pattern.get((px + py * rule.size) as usize) else {
+                return false;
+            };
+
+            if pattern_value == 0 {
+                continue;
+            }
+
+            let Some(value) = source
+                .get(cx + dir_x * (px - radius), cy + dir_y * (py - radius))
+                .or(rule.out_of_bounds_value)
+            else {
+                return false;
+            };
+
+            let passes = if pattern_value.abs() == AUTO_LAYER_ANYTHING {
+                (pattern_value > 0) == (value != 0)
+            } else if pattern_value.abs() > 999 {
+                let group_uid = pattern_value.abs() / 1000 - 1;
+                let in_group = value != 0 && source.group_uid(value) == group_uid;
+
+                (pattern_value > 0) == in_group
+            } else {
+                (pattern_value > 0) == (value == pattern_value.abs())
+            };
+
+            if !passes {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn rule_tiles_at(
+    rule: &AutoLayerRuleDefinition,
+    layer_definition: &LayerDefinition,
+    tileset_definition: &TilesetDefinition,
+    layer_instance: &LayerInstance,
+    cx: i32,
+    cy: i32,
+    flips: i32,
+) -> Vec<TileInstance> {
+    let seed = layer_instance.seed.wrapping_add(rule.uid);
+    let grid_size = layer_instance.grid_size;
+    let tileset_c_wid = tileset_definition.c_wid.max(1);
+
+    let tile_ids = if rule.tile_rects_ids.is_empty() {
+        let tile_ids = rule.tile_ids.as_deref().unwrap_or_default();
+        match tile_ids.get(rand_seed_coords(seed, cx, cy, tile_ids.len() as i32) as usize) {
+            Some(tile_id) => vec![*tile_id],
+            None => return Vec::new(),
+        }
+    } else {
+        rule.tile_rects_ids
+            [rand_seed_coords(seed, cx, cy, rule.tile_rects_ids.len() as i32) as usize]
+            .clone()
+    };
+
+    let random_offset = |seed: i32, min: i32, max: i32| {
+        if max > min {
+            min + rand_seed_coords(seed, cx, cy, max - min + 1)
+        } else {
+            min
+        }
+    };
+
+    let random_x = random_offset(
+        seed.wrapping_add(1),
+        rule.tile_random_x_min,
+        rule.tile_random_x_max,
+    );
+    let random_y = random_offset(
+        seed.wrapping_add(2),
+        rule.tile_random_y_min,
+        rule.tile_random_y_max,
+    );
+
+    let tile_cell = |tile_id: i32| IVec2::new(tile_id % tileset_c_wid, tile_id / tileset_c_wid);
+
+    let (min, max) = tile_ids
+        .iter()
+        .map(|tile_id| tile_cell(*tile_id))
+        .fold((IVec2::MAX, IVec2::MIN), |(min, max), cell| {
+            (min.min(cell), max.max(cell))
+        });
+
+    let flip_sign = IVec2::new(
+        if flips & 1 != 0 { -1 } else { 1 },
+        if flips & 2 != 0 { -1 } else { 1 },
+    );
+
+    tile_ids
+        .iter()
+        .map(|tile_id| {
+            let stamp_offset = match rule.tile_mode {
+                TileMode::Single => IVec2::ZERO,
+                TileMode::Stamp => {
+                    let tile_cell = tile_cell(*tile_id);
+                    let x = (tile_cell.x - min.x) as f32 - rule.pivot_x * (max.x - min.x) as f32
+                        + layer_definition.tile_pivot_x;
+                    let y = (tile_cell.y - min.y) as f32 - rule.pivot_y * (max.y - min.y) as f32
+                        + layer_definition.tile_pivot_y;
+
+                    IVec2::new((x * grid_size as f32) as i32, (y * grid_size as f32) as i32)
+                        * flip_sign
+                }
+            };
+
+            let tile_step = tileset_definition.tile_grid_size + tileset_definition.spacing;
+
+            TileInstance {
+                a: rule.alpha,
+                d: vec![rule.uid, cx + cy * layer_instance.c_wid],
+                f: flips,
+                px: IVec2::new(cx, cy) * grid_size
+                    + stamp_offset
+                    + IVec2::new(rule.tile_x_offset + random_x, rule.tile_y_offset + random_y),
+                src: IVec2::splat(tileset_definition.padding) + tile_cell(*tile_id) * tile_step,
+                t: *tile_id,
+            }
+        })
+        .collect()
+}
+
+/// Evaluates the auto-layer rules of a layer against the given IntGrid values.
+///
+/// `int_grid_csv` is the IntGrid data of the rules' source layer, in LDtk's order (left to right,
+/// top to bottom) and with the dimensions of `layer_instance`.
+/// For IntGrid layers with AutoTile functionality, this is the layer's own data.
+/// For AutoLayer layers, it's the data of the IntGrid layer identified by the definition's
+/// `auto_source_layer_def_uid`, whose definition should be provided as `source_layer_definition`.
+///
+/// The layer's `seed` and `optional_rules` are taken from `layer_instance`.
+///
+/// Returns tiles in display order, suitable for replacing the layer's `auto_layer_tiles`.
+pub fn auto_layer_tiles(
+    layer_instance: &LayerInstance,
+    int_grid_csv: &[i32],
+    layer_definition: &LayerDefinition,
+    source_layer_definition: &LayerDefinition,
+    tileset_definition: &TilesetDefinition,
+) -> Vec<TileInstance> {
+    let source = RuleSource {
+        int_grid_csv,
+        c_wid: layer_instance.c_wid,
+        c_hei: layer_instance.c_hei,
+        group_uids: source_layer_definition
+            .int_grid_values
+            .iter()
+            .map(|value_definition| (value_definition.value, value_definition.group_uid))
+            .collect(),
+    };
+
+    let mut broken_cells = vec![false; (source.c_wid * source.c_hei).max(0) as usize];
+    let mut tiles_by_rule = Vec::new();
+
+    let rules = layer_definition
+        .auto_rule_groups
+        .iter()
+        .filter(|group| {
+            group.active
+                && (!group.is_optional || layer_instance.optional_rules.contains(&group.uid))
+        })
+        .flat_map(|group| group.rules.iter())
+        .filter(|rule| rule.active && rule.size > 0);
+
+    for rule in rules {
+        let mut rule_tiles = Vec::new();
+
+        for cy in 0..source.c_hei {
+            for cx in 0..source.c_wid {
+                let cell_index = (cy * source.c_wid + cx) as usize;
+
+                if broken_cells[cell_index] || !passes_modulo(rule, cx, cy) {
+                    continue;
+                }
+
+                let mut matched = false;
+
+                for (flips, dir_x, dir_y, enabled) in [
+                    (0, 1, 1, true),
+                    (1, -1, 1, rule.flip_x),
+                    (2, 1, -1, rule.flip_y),
+                    (3, -1, -1, rule.flip_x && rule.flip_y),
+                ] {
+                    if !enabled || (matched && rule.break_on_match) {
+                        continue;
+                    }
+
+                    if matches(rule, &source, layer_instance.seed, cx, cy, dir_x, dir_y) {
+                        rule_tiles.extend(rule_tiles_at(
+                            rule,
+                            layer_definition,
+                            tileset_definition,
+                            layer_instance,
+                            cx,
+                            cy,
+                            flips,
+                        ));
+                        matched = true;
+                    }
+                }
+
+                if matched && rule.break_on_match {
+                    broken_cells[cell_index] = true;
+                }
+            }
+        }
+
+        tiles_by_rule.push(rule_tiles);
+    }
+
+    // Rules earlier in the list take priority, so their tiles are displayed on top.
+    tiles_by_rule.into_iter().rev().flatten().collect()
+}
+
+impl Definitions {
+    /// Evaluates the auto-layer rules of the given layer, looking up the necessary definitions.
+    ///
+    /// See [`auto_layer_tiles`] for more details.
+    ///
+    /// Returns `None` if the layer has no tileset, or if any of its definitions are missing.
+    pub fn auto_layer_tiles(
+        &self,
+        layer_instance: &LayerInstance,
+        int_grid_csv: &[i32],
+    ) -> Option<Vec<TileInstance>> {
+        let layer_definition = self
+            .layers
+            .iter()
+            .find(|layer_definition| layer_definition.uid == layer_instance.layer_def_uid)?;
+
+        let source_layer_uid = layer_definition
+            .auto_source_layer_def_uid
+            .unwrap_or(layer_definition.uid);
+
+        let source_layer_definition = self
+            .layers
+            .iter()
+            .find(|layer_definition| layer_definition.uid == source_layer_uid)?;
+
+        let tileset_uid = layer_instance.tileset_def_uid?;
+
+        let tileset_definition = self
+            .tilesets
+            .iter()
+            .find(|tileset_definition| tileset_definition.uid == tileset_uid)?;
+
+        Some(auto_layer_tiles(
+            layer_instance,
+            int_grid_csv,
+            layer_definition,
+            source_layer_definition,
+            tileset_definition,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::AutoLayerRuleGroup;
+
+    fn rule(uid: i32, pattern: Vec<i32>, tile_id: i32) -> AutoLayerRuleDefinition {
+        AutoLayerRuleDefinition {
+            active: true,
+            alpha: 1.,
+            break_on_match: true,
+            chance: 1.,
+            checker: Checker::None,
+            size: (pattern.len() as f32).sqrt() as i32,
+            pattern,
+            tile_rects_ids: vec![vec![tile_id]],
+            uid,
+            x_modulo: 1,
+            y_modulo: 1,
+            ..Default::default()
+        }
+    }
+
+    fn layer(rules: Vec<AutoLayerRuleDefinition>) -> (LayerInstance, LayerDefinition) {
+        (
+            LayerInstance {
+                c_wid: 3,
+                c_hei: 2,
+                grid_size: 16,
+                ..Default::default()
+            },
+            LayerDefinition {
+                auto_rule_groups: vec![AutoLayerRuleGroup {
+                    active: true,
+                    rules,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )
+    }
+
+    fn tileset() -> TilesetDefinition {
+        TilesetDefinition {
+            c_wid: 4,
+            tile_grid_size: 16,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn single_cell_rule_places_tiles_on_matching_cells() {
+        let (layer_instance, layer_definition) = layer(vec![rule(1, vec![1], 5)]);
+
+        let tiles = auto_layer_tiles(
+            &layer_instance,
+            &[1, 0, 0, 0, 2, 1],
+            &layer_definition,
+            &layer_definition,
+            &tileset(),
+        );
+
+        assert_eq!(
+            tiles.iter().map(|tile| tile.px).collect::<Vec<_>>(),
+            vec![IVec2::new(0, 0), IVec2::new(32, 16)]
+        );
+        assert!(tiles
+            .iter()
+            .all(|tile| tile.t == 5 && tile.src == IVec2::new(16, 16)));
+    }
+
+    #[test]
+    fn break_on_match_prevents_lower_rules() {
+        let (layer_instance, layer_definition) = layer(vec![
+            rule(1, vec![0, 0, 0, 1, 1, 0, 0, 0, 0], 1),
+            rule(2, vec![1], 2),
+        ]);
+
+        let tiles = auto_layer_tiles(
+            &layer_instance,
+            &[1, 1, 0, 0, 0, 0],
+            &layer_definition,
+            &layer_definition,
+            &tileset(),
+        );
+
+        // The first cell has no left neighbor, and there's no out of bounds value.
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|tile| (tile.t, tile.px))
+                .collect::<Vec<_>>(),
+            vec![(2, IVec2::new(0, 0)), (1, IVec2::new(16, 0))]
+        );
+    }
+
+    #[test]
+    fn flipped_patterns_match_with_flip_bits() {
+        let mut edge_rule = rule(1, vec![0, 0, 0, 0, 1, -1, 0, 0, 0], 3);
+        edge_rule.flip_x = true;
+        edge_rule.out_of_bounds_value = Some(0);
+
+        let (layer_instance, layer_definition) = layer(vec![edge_rule]);
+
+        let tiles = auto_layer_tiles(
+            &layer_instance,
+            &[0, 1, 0, 0, 0, 0],
+            &layer_definition,
+            &layer_definition,
+            &tileset(),
+        );
+
+        // Matches unflipped first, and break_on_match stops the flipped check.
+        assert_eq!(tiles.len(), 1);
+        assert_eq!(tiles[0].f, 0);
+
+        let tiles = auto_layer_tiles(
+            &layer_instance,
+            &[0, 1, 1, 0, 0, 0],
+            &layer_definition,
+            &layer_definition,
+            &tileset(),
+        );
+
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|tile| (tile.px, tile.f))
+                .collect::<Vec<_>>(),
+            vec![(IVec2::new(16, 0), 1), (IVec2::new(32, 0), 0)]
+        );
+    }
+
+    #[test]
+    fn modulo_skips_cells() {
+        let mut modulo_rule = rule(1, vec![1], 0);
+        modulo_rule.x_modulo = 2;
+
+        let (layer_instance, layer_definition) = layer(vec![modulo_rule]);
+
+        let tiles = auto_layer_tiles(
+            &layer_instance,
+            &[1, 1, 1, 0, 0, 0],
+            &layer_definition,
+            &layer_definition,
+            &tileset(),
+        );
+
+        assert_eq!(
+            tiles.iter().map(|tile| tile.px).collect::<Vec<_>>(),
+            vec![IVec2::new(0, 0), IVec2::new(32, 0)]
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Contains [`EntityRefGraph`]: the connections between LDtk entities linked by EntityRef fields.
+use crate::ldtk::{
+    raw_level_accessor::RawLevelAccessor, FieldValue, Level, ReferenceToAnEntityInstance,
+};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// An LDtk entity that refers to, or is referred to by, another entity in an [`EntityRefGraph`].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct EntityRefNode {
+    /// Iid of the entity.
+    pub entity_iid: String,
+    /// Identifier of the entity's definition.
+    pub identifier: String,
+    /// Iid of the layer containing the entity.
+    pub layer_iid: String,
+    /// Iid of the level containing the entity.
+    pub level_iid: String,
+    /// Pixel position of the entity in its level, with y pointing down as in LDtk.
+    pub px: IVec2,
+    /// Pixel position of the entity in the world, with y pointing down as in LDtk.
+    ///
+    /// Levels in `LinearHorizontal` and `LinearVertical` world layouts have no world coordinates,
+    /// so this is only meaningful for `Free` and `GridVania` layouts.
+    /// Use [`EntityRefNode::px`] in other layouts.
+    pub world_px: IVec2,
+}
+
+/// A reference from an entity to another entity, through one of its EntityRef fields.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct EntityRefEdge {
+    /// Iid of the entity with the EntityRef field.
+    pub from: String,
+    /// The referenced entity.
+    pub to: ReferenceToAnEntityInstance,
+    /// Identifier of the EntityRef field.
+    pub field_identifier: String,
+}
+
+/// Graph of the references between LDtk entities through their EntityRef fields, like doors
+/// referring to their destinations.
+///
+/// Built from level data without spawning any levels, so it can back fast-travel menus or
+/// validate the connectivity of levels.
+///
+/// Can be constructed via [`EntityRefGraph::from_levels`] or [`EntityRefGraph::from_project`].
+/// The graphs of loaded projects are also available in the [`EntityRefGraphs`] resource.
+///
+/// [`EntityRefGraphs`]: crate::resources::EntityRefGraphs
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct EntityRefGraph {
+    nodes: HashMap<String, EntityRefNode>,
+    edges: Vec<EntityRefEdge>,
+}
+
+impl EntityRefGraph {
+    /// Creates an [`EntityRefGraph`] from the EntityRef fields of the entities in the given
+    /// levels.
+    ///
+    /// Levels without layer instances, like the raw levels of projects with external levels,
+    /// don't contribute anything.
+    pub fn from_levels<'a>(levels: impl IntoIterator<Item = &'a Level>) -> EntityRefGraph {
+        let mut entities = HashMap::new();
+        let mut edges = Vec::new();
+
+        for level in levels {
+            let Some(layer_instances) = &level.layer_instances else {
+                continue;
+            };
+
+            for layer_instance in layer_instances {
+                for entity_instance in &layer_instance.entity_instances {
+                    entities.insert(
+                        entity_instance.iid.clone(),
+                        EntityRefNode {
+                            entity_iid: entity_instance.iid.clone(),
+                            identifier: entity_instance.identifier.clone(),
+                            layer_iid: layer_instance.iid.clone(),
+                            level_iid: level.iid.clone(),
+                            px: entity_instance.px,
+                            world_px: IVec2::new(level.world_x, level.world_y) + entity_instance.px,
+                        },
+                    );
+
+                    for field_instance in &entity_instance.field_instances {
+                        let references = match &field_instance.value {
+                            FieldValue::EntityRef(reference) => vec![reference],
+                            FieldValue::EntityRefs(references) => references.iter().collect(),
+                            _ => continue,
+                        };
+
+                        edges.extend(references.into_iter().flatten().map(|reference| {
+                            EntityRefEdge {
+                                from: entity_instance.iid.clone(),
+                                to: reference.clone(),
+                                field_identifier: field_instance.identifier.clone(),
+                            }
+                        }));
+                    }
+                }
+            }
+        }
+
+        let connected_iids: HashSet<&str> = edges
+            .iter()
+            .flat_map(|edge| [edge.from.as_str(), edge.to.entity_iid.as_str()])
+            .collect();
+
+        let nodes = entities
+            .into_iter()
+            .filter(|(iid, _)| connected_iids.contains(iid.as_str()))
+            .collect();
+
+        EntityRefGraph { nodes, edges }
+    }
+
+    /// Creates an [`EntityRefGraph`] from the raw levels of the given project data, such as an
+    /// [`LdtkProject`].
+    ///
+    /// For projects with external levels, use [`EntityRefGraph::from_levels`] with the loaded
+    /// levels instead.
+    ///
+    /// [`LdtkProject`]: crate::assets::LdtkProject
+    pub fn from_project(project: &impl RawLevelAccessor) -> EntityRefGraph {
+        EntityRefGraph::from_levels(project.iter_raw_levels())
+    }
+
+    /// Returns the node of the entity with the given iid, if it is part of any reference.
+    pub fn node(&self, entity_iid: &str) -> Option<&EntityRefNode> {
+        self.nodes.get(entity_iid)
+    }
+
+    /// Iterates over the nodes of all entities that are part of any reference.
+    pub fn nodes(&self) -> impl Iterator<Item = &EntityRefNode> {
+        self.nodes.values()
+    }
+
+    /// All references in the graph.
+    pub fn edges(&self) -> &[EntityRefEdge] {
+        &self.edges
+    }
+
+    /// Iterates over the references from the entity with the given iid.
+    pub fn edges_from<'a>(
+        &'a self,
+        entity_iid: &'a str,
+    ) -> impl Iterator<Item = &'a EntityRefEdge> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.from == entity_iid)
+    }
+
+    /// Iterates over the references to the entity with the given iid.
+    pub fn edges_to<'a>(&'a self, entity_iid: &'a str) -> impl Iterator<Item = &'a EntityRefEdge> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.to.entity_iid == entity_iid)
+    }
+
+    /// Returns the iids of the other levels that entities in the given level refer to.
+    pub fn connected_levels(&self, level_iid: &str) -> HashSet<&str> {
+        self.edges
+            .iter()
+            .filter(|edge| {
+                self.nodes
+                    .get(&edge.from)
+                    .is_some_and(|node| node.level_iid == level_iid)
+            })
+            .map(|edge| edge.to.level_iid.as_str())
+            .filter(|to_level_iid| *to_level_iid != level_iid)
+            .collect()
+    }
+
+    /// Iterates over the references to entities that weren't found in the levels the graph was
+    /// built from.
+    ///
+    /// If the graph was built from all levels of a project, these are broken references.
+    pub fn dangling_edges(&self) -> impl Iterator<Item = &EntityRefEdge> {
+        self.edges
+            .iter()
+            .filter(|edge| !self.nodes.contains_key(&edge.to.entity_iid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::{ldtk_fields::field_instance_from_value, EntityInstance, LayerInstance};
+
+    fn reference(entity_iid: &str, level_iid: &str) -> ReferenceToAnEntityInstance {
+        ReferenceToAnEntityInstance {
+            entity_iid: entity_iid.to_string(),
+            layer_iid: "entities".to_string(),
+            level_iid: level_iid.to_string(),
+            world_iid: "world".to_string(),
+        }
+    }
+
+    fn door(
+        iid: &str,
+        px: IVec2,
+        destinations: Vec<ReferenceToAnEntityInstance>,
+    ) -> EntityInstance {
+        EntityInstance {
+            iid: iid.to_string(),
+            identifier: "Door".to_string(),
+            px,
+            field_instances: vec![field_instance_from_value(
+                "destinations",
+                FieldValue::EntityRefs(destinations.into_iter().map(Some).collect()),
+            )],
+            ..default()
+        }
+    }
+
+    fn level(iid: &str, world_x: i32, entity_instances: Vec<EntityInstance>) -> Level {
+        Level {
+            iid: iid.to_string(),
+            world_x,
+            layer_instances: Some(vec![LayerInstance {
+                iid: "entities".to_string(),
+                entity_instances,
+                ..default()
+            }]),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn graph_connects_entities_through_entity_ref_fields() {
+        let levels = [
+            level(
+                "a",
+                0,
+                vec![
+                    door("a_door", IVec2::new(8, 8), vec![reference("b_door", "b")]),
+                    EntityInstance {
+                        iid: "a_chest".to_string(),
+                        ..default()
+                    },
+                ],
+            ),
+            level(
+                "b",
+                100,
+                vec![door(
+                    "b_door",
+                    IVec2::new(16, 0),
+                    vec![reference("a_door", "a"), reference("c_door", "c")],
+                )],
+            ),
+        ];
+
+        let graph = EntityRefGraph::from_levels(&levels);
+
+        assert_eq!(graph.edges().len(), 3);
+        assert_eq!(graph.nodes().count(), 2);
+        assert!(graph.node("a_chest").is_none());
+        assert_eq!(
+            graph.node("b_door").map(|node| node.world_px),
+            Some(IVec2::new(116, 0))
+        );
+        assert_eq!(
+            graph.node("b_door").map(|node| node.px),
+            Some(IVec2::new(16, 0))
+        );
+
+        assert_eq!(graph.edges_from("b_door").count(), 2);
+        assert_eq!(
+            graph
+                .edges_to("b_door")
+                .map(|edge| edge.from.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a_door"]
+        );
+
+        assert_eq!(graph.connected_levels("a"), HashSet::from(["b"]));
+        assert_eq!(graph.connected_levels("b"), HashSet::from(["a", "c"]));
+
+        assert_eq!(
+            graph
+                .dangling_edges()
+                .map(|edge| edge.to.entity_iid.as_str())
+                .collect::<Vec<_>>(),
+            vec!["c_door"]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::ldtk::{Definitions, Type};
+use crate::ldtk::{
+    Definitions, EntityDefinition, EntityInstance, EnumDefinition, FieldDefinition, FieldInstance,
+    LayerDefinition, TilesetDefinition, Type,
+};
 use bevy::{
     prelude::*,
     render::{
@@ -8,6 +11,37 @@ use bevy::{
 };
 
 impl Definitions {
+    /// Returns the layer definition with the given identifier.
+    pub fn get_layer_definition(&self, identifier: &str) -> Option<&LayerDefinition> {
+        self.layers
+            .iter()
+            .find(|layer_definition| layer_definition.identifier == identifier)
+    }
+
+    /// Returns the entity definition with the given identifier.
+    pub fn get_entity_definition(&self, identifier: &str) -> Option<&EntityDefinition> {
+        self.entities
+            .iter()
+            .find(|entity_definition| entity_definition.identifier == identifier)
+    }
+
+    /// Returns the tileset definition with the given identifier.
+    pub fn get_tileset_definition(&self, identifier: &str) -> Option<&TilesetDefinition> {
+        self.tilesets
+            .iter()
+            .find(|tileset_definition| tileset_definition.identifier == identifier)
+    }
+
+    /// Returns the enum definition with the given identifier.
+    ///
+    /// Both project enums and external enums are searched.
+    pub fn get_enum_definition(&self, identifier: &str) -> Option<&EnumDefinition> {
+        self.enums
+            .iter()
+            .chain(self.external_enums.iter())
+            .find(|enum_definition| enum_definition.identifier == identifier)
+    }
+
     /// Creates image that will be used for rendering IntGrid colors.
     ///
     /// The resulting image is completely white and can be thought of as a single tile of the grid.
@@ -36,9 +70,76 @@ impl Definitions {
     }
 }
 
+impl FieldDefinition {
+    /// Creates a [`FieldInstance`] holding this field's default value.
+    ///
+    /// This mirrors the value LDtk gives a field on a newly placed entity: the definition's
+    /// default value if it has one, `null` for nullable fields, and an empty array for arrays.
+    /// Non-nullable fields without a default value get `0`, `false`, `""`, or black.
+    pub fn create_default_instance(&self) -> Result<FieldInstance, serde_json::Error> {
+        use serde_json::{json, Value};
+
+        let default_value = self
+            .default_override
+            .as_ref()
+            .and_then(|default_override| default_override.get("params"))
+            .and_then(|params| params.get(0))
+            .cloned();
+
+        let value = match (self.field_definition_type.as_str(), default_value) {
+            _ if self.is_array => json!([]),
+            ("Color", Some(Value::Number(color))) => {
+                json!(format!("#{:06X}", color.as_i64().unwrap_or_default()))
+            }
+            ("Color", _) => json!("#000000"),
+            (_, Some(default_value)) => default_value,
+            ("Bool", None) => json!(false),
+            _ if self.can_be_null => Value::Null,
+            ("Int" | "Float", None) => json!(0),
+            ("String" | "Multilines", None) => json!(""),
+            _ => Value::Null,
+        };
+
+        serde_json::from_value(json!({
+            "__identifier": self.identifier,
+            "__tile": null,
+            "__type": self.field_definition_type,
+            "__value": value,
+            "defUid": self.uid,
+            "realEditorValues": [],
+        }))
+    }
+}
+
+impl EntityDefinition {
+    /// Creates an [`EntityInstance`] of this entity, as if it were newly placed in the editor.
+    ///
+    /// The instance has the definition's size, pivot, tags, and editor visual, and its fields have
+    /// their default values (see [`FieldDefinition::create_default_instance`]).
+    /// Its position is the origin and its `iid` is empty.
+    pub fn create_default_instance(&self) -> Result<EntityInstance, serde_json::Error> {
+        Ok(EntityInstance {
+            identifier: self.identifier.clone(),
+            def_uid: self.uid,
+            width: self.width,
+            height: self.height,
+            pivot: Vec2::new(self.pivot_x, self.pivot_y),
+            smart_color: self.color,
+            tags: self.tags.clone(),
+            tile: self.tile_rect,
+            field_instances: self
+                .field_defs
+                .iter()
+                .map(FieldDefinition::create_default_instance)
+                .collect::<Result<_, _>>()?,
+            ..default()
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ldtk::LayerDefinition;
+    use crate::ldtk::{FieldValue, LayerDefinition};
 
     use super::*;
 
@@ -144,4 +245,94 @@ mod tests {
 
         assert!(definitions.create_int_grid_image().is_none());
     }
+
+    #[test]
+    fn definitions_are_found_by_identifier() {
+        let definitions = Definitions {
+            layers: vec![LayerDefinition {
+                identifier: "Walls".to_string(),
+                uid: 1,
+                ..default()
+            }],
+            entities: vec![EntityDefinition {
+                identifier: "Player".to_string(),
+                uid: 2,
+                ..default()
+            }],
+            tilesets: vec![TilesetDefinition {
+                identifier: "Terrain".to_string(),
+                uid: 3,
+                ..default()
+            }],
+            external_enums: vec![EnumDefinition {
+                identifier: "Items".to_string(),
+                uid: 4,
+                ..default()
+            }],
+            ..default()
+        };
+
+        assert_eq!(definitions.get_layer_definition("Walls").unwrap().uid, 1);
+        assert_eq!(definitions.get_entity_definition("Player").unwrap().uid, 2);
+        assert_eq!(
+            definitions.get_tileset_definition("Terrain").unwrap().uid,
+            3
+        );
+        assert_eq!(definitions.get_enum_definition("Items").unwrap().uid, 4);
+        assert!(definitions.get_entity_definition("Walls").is_none());
+    }
+
+    #[test]
+    fn default_field_instances_use_definition_defaults() {
+        let field_definition =
+            |field_definition_type: &str,
+             can_be_null: bool,
+             default_override: Option<serde_json::Value>| FieldDefinition {
+                identifier: "field".to_string(),
+                field_definition_type: field_definition_type.to_string(),
+                can_be_null,
+                default_override,
+                ..default()
+            };
+
+        let default_value = |field_definition: FieldDefinition| {
+            field_definition.create_default_instance().unwrap().value
+        };
+
+        assert_eq!(
+            default_value(field_definition(
+                "Int",
+                true,
+                Some(serde_json::json!({ "id": "V_Int", "params": [5] }))
+            )),
+            FieldValue::Int(Some(5))
+        );
+        assert_eq!(
+            default_value(field_definition("Int", true, None)),
+            FieldValue::Int(None)
+        );
+        assert_eq!(
+            default_value(field_definition("Int", false, None)),
+            FieldValue::Int(Some(0))
+        );
+        assert_eq!(
+            default_value(field_definition("Bool", false, None)),
+            FieldValue::Bool(false)
+        );
+        assert_eq!(
+            default_value(field_definition(
+                "Color",
+                false,
+                Some(serde_json::json!({ "id": "V_Int", "params": [16711680] }))
+            )),
+            FieldValue::Color(Color::srgb(1., 0., 0.))
+        );
+        assert_eq!(
+            default_value(FieldDefinition {
+                is_array: true,
+                ..field_definition("Array<Int>", false, None)
+            }),
+            FieldValue::Ints(Vec::new())
+        );
+    }
 }
@@ -0,0 +1,149 @@
+//! Contains [`IntGridValueDefs`]: a lookup of IntGrid values by layer and editor-defined name.
+use crate::ldtk::{Definitions, IntGridValueDefinition, Type};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Lookup of the IntGrid value definitions of every IntGrid layer in a project, by layer
+/// identifier.
+///
+/// Allows game logic to refer to IntGrid values by the identifiers and colors given to them in the
+/// editor, rather than hardcoding their integers.
+///
+/// Can be constructed via [`Definitions::int_grid_value_defs`] or
+/// [`LdtkProject::int_grid_value_defs`].
+///
+/// ```
+/// # use bevy_ecs_ldtk::prelude::*;
+/// fn is_water(int_grid_value_defs: &IntGridValueDefs, int_grid_cell: &IntGridCell) -> bool {
+///     int_grid_value_defs.value_named("Terrain", "water") == Some(int_grid_cell.value)
+/// }
+/// ```
+///
+/// [`LdtkProject::int_grid_value_defs`]: crate::assets::LdtkProject::int_grid_value_defs
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct IntGridValueDefs {
+    layers: HashMap<String, Vec<IntGridValueDefinition>>,
+}
+
+impl IntGridValueDefs {
+    /// Creates an [`IntGridValueDefs`] from the IntGrid layers of the given definitions.
+    pub fn from_definitions(definitions: &Definitions) -> IntGridValueDefs {
+        let layers = definitions
+            .layers
+            .iter()
+            .filter(|layer_definition| layer_definition.purple_type == Type::IntGrid)
+            .map(|layer_definition| {
+                (
+                    layer_definition.identifier.clone(),
+                    layer_definition.int_grid_values.clone(),
+                )
+            })
+            .collect();
+
+        IntGridValueDefs { layers }
+    }
+
+    /// Returns the value definitions of the IntGrid layer with the given identifier.
+    pub fn layer(&self, layer_identifier: &str) -> Option<&[IntGridValueDefinition]> {
+        self.layers.get(layer_identifier).map(Vec::as_slice)
+    }
+
+    /// Returns the definition of the given value in the IntGrid layer with the given identifier.
+    pub fn get(&self, layer_identifier: &str, value: i32) -> Option<&IntGridValueDefinition> {
+        self.layer(layer_identifier)?
+            .iter()
+            .find(|value_definition| value_definition.value == value)
+    }
+
+    /// Returns the value with the given editor-defined identifier in the IntGrid layer with the
+    /// given identifier.
+    pub fn value_named(&self, layer_identifier: &str, value_identifier: &str) -> Option<i32> {
+        self.layer(layer_identifier)?
+            .iter()
+            .find(|value_definition| {
+                value_definition.identifier.as_deref() == Some(value_identifier)
+            })
+            .map(|value_definition| value_definition.value)
+    }
+
+    /// Returns the editor-defined identifier of the given value in the IntGrid layer with the
+    /// given identifier, if it has one.
+    pub fn identifier(&self, layer_identifier: &str, value: i32) -> Option<&str> {
+        self.get(layer_identifier, value)?.identifier.as_deref()
+    }
+
+    /// Returns the editor-defined color of the given value in the IntGrid layer with the given
+    /// identifier.
+    pub fn color(&self, layer_identifier: &str, value: i32) -> Option<Color> {
+        self.get(layer_identifier, value)
+            .map(|value_definition| value_definition.color)
+    }
+}
+
+impl Definitions {
+    /// Creates a lookup of the IntGrid values of every IntGrid layer by their editor-defined
+    /// identifiers and colors.
+    pub fn int_grid_value_defs(&self) -> IntGridValueDefs {
+        IntGridValueDefs::from_definitions(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::LayerDefinition;
+
+    #[test]
+    fn int_grid_values_are_looked_up_by_identifier() {
+        let definitions = Definitions {
+            layers: vec![
+                LayerDefinition {
+                    identifier: "Terrain".to_string(),
+                    purple_type: Type::IntGrid,
+                    int_grid_values: vec![
+                        IntGridValueDefinition {
+                            value: 1,
+                            identifier: Some("ground".to_string()),
+                            color: Color::srgb(0., 1., 0.),
+                            ..default()
+                        },
+                        IntGridValueDefinition {
+                            value: 2,
+                            identifier: Some("water".to_string()),
+                            color: Color::srgb(0., 0., 1.),
+                            ..default()
+                        },
+                        IntGridValueDefinition {
+                            value: 3,
+                            identifier: None,
+                            ..default()
+                        },
+                    ],
+                    ..default()
+                },
+                LayerDefinition {
+                    identifier: "Entities".to_string(),
+                    purple_type: Type::Entities,
+                    ..default()
+                },
+            ],
+            ..default()
+        };
+
+        let int_grid_value_defs = definitions.int_grid_value_defs();
+
+        assert_eq!(int_grid_value_defs.value_named("Terrain", "water"), Some(2));
+        assert_eq!(int_grid_value_defs.value_named("Terrain", "lava"), None);
+        assert_eq!(int_grid_value_defs.identifier("Terrain", 1), Some("ground"));
+        assert_eq!(int_grid_value_defs.identifier("Terrain", 3), None);
+        assert_eq!(
+            int_grid_value_defs.color("Terrain", 2),
+            Some(Color::srgb(0., 0., 1.))
+        );
+        assert_eq!(
+            int_grid_value_defs.layer("Terrain").map(<[_]>::len),
+            Some(3)
+        );
+        assert_eq!(int_grid_value_defs.layer("Entities"), None);
+    }
+}
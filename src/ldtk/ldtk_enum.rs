@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+#[allow(unused_imports)]
+use crate::ldtk::ldtk_fields::LdtkFields;
+
+/// Error returned when parsing an enum generated by `ldtk_enum!` from a string that isn't one of
+/// its values.
+///
+/// Enum fields can be parsed with [`str::parse`], e.g. from the values of
+/// [`LdtkFields::iter_enums_field`].
+/// ```
+/// # use std::str::FromStr;
+/// bevy_ecs_ldtk::ldtk_enum!("assets/Typical_2D_platformer_example.ldtk", "Item");
+///
+/// assert_eq!(Item::from_str("Knife"), Ok(Item::Knife));
+/// assert_eq!(
+///     Item::from_str("Sword").unwrap_err().to_string(),
+///     "Sword is not a value of the Item enum"
+/// );
+/// ```
+///
+/// Requires the `derive` feature to be enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("{value} is not a value of the {enum_identifier} enum")]
+pub struct ParseLdtkEnumError {
+    /// Identifier of the LDtk enum.
+    pub enum_identifier: &'static str,
+    /// The string that failed to parse.
+    pub value: String,
+}
@@ -1,7 +1,11 @@
 //! Contains [`LdtkFields`] trait, providing convenience methods for accessing field instances.
-use crate::ldtk::{
-    all_some_iter::AllSomeIter, EntityInstance, FieldInstance, FieldValue, Level,
-    ReferenceToAnEntityInstance, TilesetRectangle,
+use crate::{
+    components::GridCoords,
+    ldtk::{
+        all_some_iter::AllSomeIter, EntityInstance, FieldInstance, FieldValue, LayerInstance,
+        Level, ReferenceToAnEntityInstance, TilesetRectangle,
+    },
+    utils::{ldtk_grid_coords_to_grid_coords, ldtk_grid_coords_to_translation},
 };
 use bevy::prelude::*;
 use paste::paste;
@@ -90,6 +94,26 @@ macro_rules! create_iter_plural_fields_method {
     };
 }
 
+/// Generates a `get_types_field` method corresponding to an `iter_types_field` method,
+/// collecting the non-null values into a [`Vec`].
+macro_rules! create_get_plural_fields_method {
+    ($variant:ident, $item:ty) => {
+        paste! {
+            #[doc = " Get this item's non-null " $variant " field values for the given identifier, as a [`Vec`]."]
+            ///
+            /// Empty arrays result in an empty [`Vec`].
+            ///
+            /// # Errors
+            /// - returns [`LdtkFieldsError::FieldNotFound`] if no field with the given identifier exists.
+            #[doc = " - returns [`LdtkFieldsError::WrongFieldType`] if the field is not [`FieldValue::" $variant "`]."]
+            /// - returns [`LdtkFieldsError::UnexpectedNull`] if **any** element of the field is null.
+            fn [< get_ $variant:snake _field >](&self, identifier: &str) -> Result<Vec<$item>, LdtkFieldsError> {
+                Ok(self.[< iter_ $variant:snake _field >](identifier)?.cloned().collect())
+            }
+        }
+    };
+}
+
 /// Generates a `get_maybe_type_field` method for the given [FieldValue] variant,
 /// accessing a field instance and unwrapping it to the given variant or erroring.
 ///
@@ -137,14 +161,15 @@ macro_rules! create_just_get_plural_fields_method {
     };
 }
 
-/// Generates both `get_maybe_types_field` and `get_types_field` methods for the given [FieldValue]
-/// variant.
+/// Generates `get_maybe_types_field`, `iter_types_field`, and `get_types_field` methods for the
+/// given [FieldValue] variant.
 ///
 /// Intended only for variants whose internal type is a collection of an optional type.
 macro_rules! create_plural_fields_methods {
     ($variant:ident, $type:ty) => {
         create_get_maybe_field_method!($variant, &[Option<$type>]);
         create_iter_plural_fields_method!($variant, $type);
+        create_get_plural_fields_method!($variant, $type);
     };
 }
 
@@ -204,6 +229,118 @@ pub trait LdtkFields {
     create_plural_fields_methods!(Tiles, TilesetRectangle);
     create_plural_fields_methods!(EntityRefs, ReferenceToAnEntityInstance);
     create_plural_fields_methods!(Points, IVec2);
+
+    /// Get this item's non-null Point field value for the given identifier, as [`GridCoords`].
+    ///
+    /// LDtk stores points as cell coordinates in level space, with the y-axis pointing down.
+    /// `layer_instance` should be the layer whose grid the point is on, usually the layer of the
+    /// entity the field belongs to.
+    ///
+    /// # Errors
+    /// - returns [`LdtkFieldsError::FieldNotFound`] if no field with the given identifier exists.
+    /// - returns [`LdtkFieldsError::WrongFieldType`] if the field is not [`FieldValue::Point`].
+    /// - returns [`LdtkFieldsError::UnexpectedNull`] if the field is null.
+    fn get_point_field_as_grid_coords(
+        &self,
+        identifier: &str,
+        layer_instance: &LayerInstance,
+    ) -> Result<GridCoords, LdtkFieldsError> {
+        let point = self.get_point_field(identifier)?;
+
+        Ok(ldtk_grid_coords_to_grid_coords(
+            *point,
+            layer_instance.c_hei,
+        ))
+    }
+
+    /// Get this item's non-null Point field value for the given identifier, as a world-space
+    /// translation in the center of the cell.
+    ///
+    /// `level_transform` should be the [`GlobalTransform`] of the spawned level's bottom-left
+    /// corner, which is the level entity's [`GlobalTransform`] unless the level was spawned with
+    /// [`LevelOrigin::TopLeft`] (see [`LevelBounds::bottom_left_transform`]).
+    /// Use [`GlobalTransform::IDENTITY`] to get the translation relative to that corner instead.
+    ///
+    /// [`LevelOrigin::TopLeft`]: crate::resources::LevelOrigin::TopLeft
+    /// [`LevelBounds::bottom_left_transform`]: crate::components::LevelBounds::bottom_left_transform
+    ///
+    /// See [`LdtkFields::get_point_field_as_grid_coords`] for more details.
+    ///
+    /// # Errors
+    /// - returns [`LdtkFieldsError::FieldNotFound`] if no field with the given identifier exists.
+    /// - returns [`LdtkFieldsError::WrongFieldType`] if the field is not [`FieldValue::Point`].
+    /// - returns [`LdtkFieldsError::UnexpectedNull`] if the field is null.
+    fn get_point_field_as_translation(
+        &self,
+        identifier: &str,
+        layer_instance: &LayerInstance,
+        level_transform: &GlobalTransform,
+    ) -> Result<Vec2, LdtkFieldsError> {
+        let point = self.get_point_field(identifier)?;
+
+        Ok(point_to_translation(
+            *point,
+            layer_instance,
+            level_transform,
+        ))
+    }
+
+    /// Get this item's non-null Points field values for the given identifier, as [`GridCoords`].
+    ///
+    /// See [`LdtkFields::get_point_field_as_grid_coords`] for more details.
+    ///
+    /// # Errors
+    /// - returns [`LdtkFieldsError::FieldNotFound`] if no field with the given identifier exists.
+    /// - returns [`LdtkFieldsError::WrongFieldType`] if the field is not [`FieldValue::Points`].
+    /// - returns [`LdtkFieldsError::UnexpectedNull`] if **any** element of the field is null.
+    fn get_points_field_as_grid_coords(
+        &self,
+        identifier: &str,
+        layer_instance: &LayerInstance,
+    ) -> Result<Vec<GridCoords>, LdtkFieldsError> {
+        Ok(self
+            .iter_points_field(identifier)?
+            .map(|point| ldtk_grid_coords_to_grid_coords(*point, layer_instance.c_hei))
+            .collect())
+    }
+
+    /// Get this item's non-null Points field values for the given identifier, as world-space
+    /// translations in the center of their cells.
+    ///
+    /// Useful for paths, like patrol routes.
+    /// See [`LdtkFields::get_point_field_as_translation`] for more details.
+    ///
+    /// # Errors
+    /// - returns [`LdtkFieldsError::FieldNotFound`] if no field with the given identifier exists.
+    /// - returns [`LdtkFieldsError::WrongFieldType`] if the field is not [`FieldValue::Points`].
+    /// - returns [`LdtkFieldsError::UnexpectedNull`] if **any** element of the field is null.
+    fn get_points_field_as_translations(
+        &self,
+        identifier: &str,
+        layer_instance: &LayerInstance,
+        level_transform: &GlobalTransform,
+    ) -> Result<Vec<Vec2>, LdtkFieldsError> {
+        Ok(self
+            .iter_points_field(identifier)?
+            .map(|point| point_to_translation(*point, layer_instance, level_transform))
+            .collect())
+    }
+}
+
+fn point_to_translation(
+    point: IVec2,
+    layer_instance: &LayerInstance,
+    level_transform: &GlobalTransform,
+) -> Vec2 {
+    let translation = ldtk_grid_coords_to_translation(
+        point,
+        layer_instance.c_hei,
+        IVec2::splat(layer_instance.grid_size),
+    );
+
+    level_transform
+        .transform_point(translation.extend(0.))
+        .truncate()
 }
 
 impl LdtkFields for EntityInstance {
@@ -218,6 +355,19 @@ impl LdtkFields for Level {
     }
 }
 
+/// Creates a [FieldInstance] with the given identifier and value, for use in tests.
+#[cfg(test)]
+pub(crate) fn field_instance_from_value(identifier: &str, value: FieldValue) -> FieldInstance {
+    FieldInstance {
+        identifier: identifier.to_string(),
+        value,
+        field_instance_type: "".to_string(),
+        tile: None,
+        def_uid: 0,
+        real_editor_values: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,17 +378,6 @@ mod tests {
         }
     }
 
-    fn field_instance_from_value(identifier: &str, value: FieldValue) -> FieldInstance {
-        FieldInstance {
-            identifier: identifier.to_string(),
-            value,
-            field_instance_type: "".to_string(),
-            tile: None,
-            def_uid: 0,
-            real_editor_values: Vec::new(),
-        }
-    }
-
     fn sample_field_instances() -> Vec<FieldInstance> {
         use FieldValue::*;
         vec![
@@ -424,6 +563,31 @@ mod tests {
         };
     }
 
+    macro_rules! test_get_plural_fields_method {
+        ($method_name:ident, $wrong_ident:literal, $nullable_ident:literal, $ident:literal, $value:expr) => {
+            paste! {
+                #[test]
+                fn [< test_ $method_name >]() {
+                    let field_instances = sample_field_instances();
+
+                    assert!(matches!(
+                        field_instances.$method_name("NonExistent"),
+                        Err(LdtkFieldsError::FieldNotFound { .. })
+                    ));
+                    assert!(matches!(
+                        field_instances.$method_name($wrong_ident),
+                        Err(LdtkFieldsError::WrongFieldType { .. })
+                    ));
+                    assert!(matches!(
+                        field_instances.$method_name($nullable_ident),
+                        Err(LdtkFieldsError::UnexpectedNull { .. })
+                    ));
+                    assert_eq!(field_instances.$method_name($ident).unwrap(), $value);
+                }
+            }
+        };
+    }
+
     test_ambiguous_get_field_method!(
         get_maybe_int_field,
         "Bool",
@@ -553,6 +717,18 @@ mod tests {
         [Some(6), Some(7)]
     );
     test_iter_fields_method!(iter_ints_field, "Bools", "IntsNullable", "Ints", [6, 7]);
+    test_get_plural_fields_method!(get_ints_field, "Bools", "IntsNullable", "Ints", vec![6, 7]);
+
+    #[test]
+    fn empty_arrays_get_empty_vecs() {
+        let field_instances = vec![
+            field_instance_from_value("Ints", FieldValue::Ints(Vec::new())),
+            field_instance_from_value("Points", FieldValue::Points(Vec::new())),
+        ];
+
+        assert_eq!(field_instances.get_ints_field("Ints"), Ok(Vec::new()));
+        assert_eq!(field_instances.get_points_field("Points"), Ok(Vec::new()));
+    }
 
     test_ambiguous_get_field_method!(
         get_maybe_floats_field,
@@ -626,6 +802,13 @@ mod tests {
         "Enums",
         ["Eighteen".to_string(), "Nineteen".to_string()]
     );
+    test_get_plural_fields_method!(
+        get_enums_field,
+        "Bools",
+        "EnumsNullable",
+        "Enums",
+        vec!["Eighteen".to_string(), "Nineteen".to_string()]
+    );
 
     test_ambiguous_get_field_method!(
         get_maybe_tiles_field,
@@ -683,4 +866,56 @@ mod tests {
         "Points",
         [IVec2::default(), IVec2::default()]
     );
+    test_get_plural_fields_method!(
+        get_points_field,
+        "Bools",
+        "PointsNullable",
+        "Points",
+        vec![IVec2::default(), IVec2::default()]
+    );
+
+    #[test]
+    fn points_convert_to_grid_coords_and_translations() {
+        let field_instances = vec![
+            field_instance_from_value("Point", FieldValue::Point(Some(IVec2::new(1, 0)))),
+            field_instance_from_value(
+                "Points",
+                FieldValue::Points(vec![Some(IVec2::new(0, 0)), Some(IVec2::new(2, 1))]),
+            ),
+        ];
+
+        let layer_instance = LayerInstance {
+            c_wid: 3,
+            c_hei: 2,
+            grid_size: 16,
+            ..default()
+        };
+
+        let level_transform = GlobalTransform::from_translation(Vec3::new(100., 200., 0.));
+
+        assert_eq!(
+            field_instances.get_point_field_as_grid_coords("Point", &layer_instance),
+            Ok(GridCoords::new(1, 1))
+        );
+        assert_eq!(
+            field_instances.get_point_field_as_translation(
+                "Point",
+                &layer_instance,
+                &GlobalTransform::IDENTITY
+            ),
+            Ok(Vec2::new(24., 24.))
+        );
+        assert_eq!(
+            field_instances.get_points_field_as_grid_coords("Points", &layer_instance),
+            Ok(vec![GridCoords::new(0, 1), GridCoords::new(2, 0)])
+        );
+        assert_eq!(
+            field_instances.get_points_field_as_translations(
+                "Points",
+                &layer_instance,
+                &level_transform
+            ),
+            Ok(vec![Vec2::new(108., 224.), Vec2::new(140., 208.)])
+        );
+    }
 }
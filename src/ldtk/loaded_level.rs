@@ -41,7 +41,7 @@ impl<'a> TryFrom<&'a Level> for LoadedLevel<'a> {
 
 impl<'a> LoadedLevel<'a> {
     /// The raw level data borrowed by this instance.
-    pub fn raw(&self) -> &Level {
+    pub fn raw(&self) -> &'a Level {
         self.level
     }
 
@@ -0,0 +1,259 @@
+//! Contains [`Minimap`]: a low-resolution color grid of a level, for minimaps and world maps.
+use crate::ldtk::{
+    int_grid_value_defs::IntGridValueDefs, loaded_level::LoadedLevel, LayerInstance, Type,
+};
+use bevy::{
+    color::ColorToPacked,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+
+/// Low-resolution color grid of a level, generated from its IntGrid colors and background color.
+///
+/// Every cell covers a square of `cell_size` pixels of the level.
+/// Its color is the editor-defined color of the IntGrid value at the cell's center, taken from the
+/// top-most visible IntGrid layer with a non-zero value there.
+/// Cells without any IntGrid value use the level's background color.
+///
+/// Cells are stored row by row, starting with the top row, like LDtk and [`Image`]s.
+///
+/// Can be constructed via [`Minimap::from_level`] or [`LoadedLevel::minimap`], without spawning
+/// the level.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Minimap {
+    width: u32,
+    height: u32,
+    colors: Vec<Color>,
+}
+
+/// Returns the IntGrid value of the layer at the given level-space pixel, if any.
+fn int_grid_value_at(layer_instance: &LayerInstance, px: IVec2) -> Option<i32> {
+    if layer_instance.grid_size <= 0 {
+        return None;
+    }
+
+    let layer_px = px
+        - IVec2::new(
+            layer_instance.px_total_offset_x,
+            layer_instance.px_total_offset_y,
+        );
+
+    if layer_px.x < 0 || layer_px.y < 0 {
+        return None;
+    }
+
+    let grid = layer_px / layer_instance.grid_size;
+
+    if grid.x >= layer_instance.c_wid || grid.y >= layer_instance.c_hei {
+        return None;
+    }
+
+    layer_instance
+        .int_grid_csv
+        .get((grid.y * layer_instance.c_wid + grid.x) as usize)
+        .copied()
+        .filter(|value| *value != 0)
+}
+
+impl Minimap {
+    /// Creates a [`Minimap`] of the given level, with every cell covering `cell_size` pixels.
+    ///
+    /// `cell_size` is clamped to at least 1.
+    pub fn from_level(
+        level: &LoadedLevel,
+        int_grid_value_defs: &IntGridValueDefs,
+        cell_size: u32,
+    ) -> Minimap {
+        let cell_size = cell_size.max(1);
+
+        let width = ((*level.px_wid()).max(0) as u32).div_ceil(cell_size);
+        let height = ((*level.px_hei()).max(0) as u32).div_ceil(cell_size);
+
+        let int_grid_layers: Vec<&LayerInstance> = level
+            .layer_instances()
+            .iter()
+            .filter(|layer_instance| {
+                layer_instance.layer_instance_type == Type::IntGrid && layer_instance.visible
+            })
+            .collect();
+
+        let mut colors = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                let center =
+                    (UVec2::new(x, y) * cell_size + UVec2::splat(cell_size / 2)).as_ivec2();
+
+                // Layer instances are ordered from the top-most layer down
+                let color = int_grid_layers
+                    .iter()
+                    .find_map(|layer_instance| {
+                        int_grid_value_at(layer_instance, center).and_then(|value| {
+                            int_grid_value_defs.color(&layer_instance.identifier, value)
+                        })
+                    })
+                    .unwrap_or(*level.bg_color());
+
+                colors.push(color);
+            }
+        }
+
+        Minimap {
+            width,
+            height,
+            colors,
+        }
+    }
+
+    /// Width of the minimap in cells.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height of the minimap in cells.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Colors of every cell, row by row starting with the top row.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Returns the color of the cell at the given coordinates, with `(0, 0)` being the top-left
+    /// cell.
+    pub fn get(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.colors.get((y * self.width + x) as usize).copied()
+    }
+
+    /// Creates an [`Image`] with one pixel per cell, sampled with nearest-neighbor filtering so it
+    /// stays crisp when scaled up.
+    pub fn to_image(&self) -> Image {
+        let data = self
+            .colors
+            .iter()
+            .flat_map(|color| color.to_srgba().to_u8_array())
+            .collect();
+
+        let mut image = Image::new(
+            Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.sampler = ImageSampler::nearest();
+
+        image
+    }
+}
+
+impl<'a> LoadedLevel<'a> {
+    /// Creates a [`Minimap`] of this level, with every cell covering `cell_size` pixels.
+    ///
+    /// See [`Minimap`] for how cell colors are chosen.
+    pub fn minimap(&self, int_grid_value_defs: &IntGridValueDefs, cell_size: u32) -> Minimap {
+        Minimap::from_level(self, int_grid_value_defs, cell_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ldtk::{Definitions, IntGridValueDefinition, LayerDefinition, Level};
+
+    use super::*;
+
+    fn int_grid_layer(identifier: &str, c_wid: i32, c_hei: i32, csv: Vec<i32>) -> LayerInstance {
+        LayerInstance {
+            identifier: identifier.to_string(),
+            layer_instance_type: Type::IntGrid,
+            c_wid,
+            c_hei,
+            grid_size: 8,
+            int_grid_csv: csv,
+            visible: true,
+            ..Default::default()
+        }
+    }
+
+    fn int_grid_layer_definition(identifier: &str, values: &[(i32, Color)]) -> LayerDefinition {
+        LayerDefinition {
+            identifier: identifier.to_string(),
+            purple_type: Type::IntGrid,
+            int_grid_values: values
+                .iter()
+                .map(|(value, color)| IntGridValueDefinition {
+                    value: *value,
+                    color: *color,
+                    ..default()
+                })
+                .collect(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn minimap_colors_come_from_top_int_grid_layer_or_background() {
+        let red = Color::srgb(1., 0., 0.);
+        let green = Color::srgb(0., 1., 0.);
+        let black = Color::srgb(0., 0., 0.);
+
+        let int_grid_value_defs = Definitions {
+            layers: vec![
+                int_grid_layer_definition("Walls", &[(1, red)]),
+                int_grid_layer_definition("Ground", &[(1, green)]),
+            ],
+            ..default()
+        }
+        .int_grid_value_defs();
+
+        let level = Level {
+            px_wid: 32,
+            px_hei: 16,
+            bg_color: black,
+            layer_instances: Some(vec![
+                int_grid_layer("Walls", 4, 2, vec![1, 0, 0, 0, 0, 0, 0, 0]),
+                int_grid_layer("Ground", 4, 2, vec![1, 1, 0, 0, 1, 1, 1, 1]),
+            ]),
+            ..default()
+        };
+
+        let minimap = LoadedLevel::try_from(&level)
+            .unwrap()
+            .minimap(&int_grid_value_defs, 8);
+
+        assert_eq!(minimap.width(), 4);
+        assert_eq!(minimap.height(), 2);
+        assert_eq!(
+            minimap.colors(),
+            &[red, green, black, black, green, green, green, green]
+        );
+        assert_eq!(minimap.get(0, 1), Some(green));
+        assert_eq!(minimap.get(4, 0), None);
+
+        let coarse_minimap = Minimap::from_level(
+            &LoadedLevel::try_from(&level).unwrap(),
+            &int_grid_value_defs,
+            16,
+        );
+        assert_eq!(coarse_minimap.colors(), &[green, green]);
+
+        let image = minimap.to_image();
+        assert_eq!(image.width(), 4);
+        assert_eq!(image.height(), 2);
+        assert_eq!(&image.data[0..8], &[255, 0, 0, 255, 0, 255, 0, 255]);
+        assert_eq!(&image.data[8..12], &[0, 0, 0, 255]);
+    }
+}
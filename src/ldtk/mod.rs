@@ -12,7 +12,8 @@
 //!    [LimitBehavior], [LimitScope], [RenderMode], [TileRenderMode], and [Type].
 //! 3. [Copy], [Default], [Eq], [PartialEq], and [Reflect] have been derived wherever possible.
 //! 4. [i64] and [f64] have been changed to [i32] and [f32].
-//! 5. `Component` has been derived for [EntityInstance].
+//! 5. `Component` has been derived for [EntityInstance], and it reflects `Component`, `Default`,
+//!    and `Debug`.
 //! 6. Documentation added for [EntityInstance], which required the unused import of [LdtkEntity].
 //! 7. [FieldInstance] has been moved to its own module, and is re-exported here.
 //! 8. Some "coordinate" fields on [LevelBackgroundPosition], [EntityInstance], and [TileInstance]
@@ -27,7 +28,7 @@
 #![allow(clippy::doc_lazy_continuation)]
 
 use bevy::{
-    prelude::{Color, Component, IVec2, Vec2},
+    prelude::{Color, Component, IVec2, ReflectComponent, ReflectDefault, Vec2},
     reflect::Reflect,
 };
 use serde::{Deserialize, Serialize};
@@ -37,16 +38,23 @@ use std::collections::HashMap;
 use crate::prelude::LdtkEntity;
 
 pub mod all_some_iter;
+pub mod auto_layer;
 mod color;
+pub mod entity_ref_graph;
 #[cfg(test)]
 pub mod fake;
 mod field_instance;
 mod impl_definitions;
+pub mod int_grid_value_defs;
+mod ldtk_enum;
 pub mod ldtk_fields;
 pub mod loaded_level;
+pub mod minimap;
+pub mod nav_grid;
 pub mod raw_level_accessor;
 
 pub use field_instance::*;
+pub use ldtk_enum::ParseLdtkEnumError;
 
 /// This file is a JSON schema of files created by LDtk level editor (<https://ldtk.io>).
 ///
@@ -849,6 +857,10 @@ pub struct LayerDefinition {
     /// An array of tags to filter Entities that can be added to this layer
     pub required_tags: Vec<String>,
 
+    /// Color multiplied with every tile of this layer when rendering, authored in LDtk 1.x.
+    #[serde(default, with = "color::optional")]
+    pub tint_color: Option<Color>,
+
     /// If the tiles are smaller or larger than the layer grid, the pivot value will be used to
     /// position the tile relatively its grid cell.
     pub tile_pivot_x: f32,
@@ -1259,6 +1271,7 @@ pub struct ForcedRefs {
 /// `Added<EntityInstance>`.
 /// Or, you can hook into the entity's spawning process using [LdtkEntity].
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Reflect, Component)]
+#[reflect(Component, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityInstance {
     /// Grid-based coordinates (`[x,y]` format)
@@ -0,0 +1,287 @@
+//! Contains [`NavGrid`]: a walkability grid derived from IntGrid layer data.
+use crate::{
+    components::GridCoords,
+    ldtk::{loaded_level::LoadedLevel, LayerInstance, Type},
+    utils::int_grid_index_to_grid_coords,
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// 2D walkability grid generated from an IntGrid layer.
+///
+/// Cells are addressed by [`GridCoords`], so they line up with the [`GridCoords`] of spawned
+/// IntGrid tiles and entities.
+/// A cell is walkable if its IntGrid value is one of the `walkable_values` provided at
+/// construction.
+///
+/// Can be constructed via [`NavGrid::from_layer_instance`] or [`LoadedLevel::nav_grid`].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct NavGrid {
+    width: i32,
+    height: i32,
+    walkable: Vec<bool>,
+}
+
+impl NavGrid {
+    /// Creates a [`NavGrid`] from the `int_grid_csv` of the given layer.
+    ///
+    /// Returns `None` if the layer is not an IntGrid layer.
+    pub fn from_layer_instance(
+        layer_instance: &LayerInstance,
+        walkable_values: &[i32],
+    ) -> Option<NavGrid> {
+        if layer_instance.layer_instance_type != Type::IntGrid {
+            return None;
+        }
+
+        let width = layer_instance.c_wid;
+        let height = layer_instance.c_hei;
+
+        let mut walkable = vec![false; (width * height).max(0) as usize];
+
+        for (i, value) in layer_instance.int_grid_csv.iter().enumerate() {
+            if let Some(grid_coords) = int_grid_index_to_grid_coords(i, width as u32, height as u32)
+            {
+                walkable[(grid_coords.y * width + grid_coords.x) as usize] =
+                    walkable_values.contains(value);
+            }
+        }
+
+        Some(NavGrid {
+            width,
+            height,
+            walkable,
+        })
+    }
+
+    /// Grid-based width of the grid.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Grid-based height of the grid.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns true if the given coordinates are within the bounds of the grid.
+    pub fn in_bounds(&self, grid_coords: GridCoords) -> bool {
+        grid_coords.x >= 0
+            && grid_coords.y >= 0
+            && grid_coords.x < self.width
+            && grid_coords.y < self.height
+    }
+
+    /// Returns true if the cell at the given coordinates is walkable.
+    ///
+    /// Out-of-bounds coordinates are never walkable.
+    pub fn is_walkable(&self, grid_coords: GridCoords) -> bool {
+        self.in_bounds(grid_coords)
+            && self.walkable[(grid_coords.y * self.width + grid_coords.x) as usize]
+    }
+
+    /// Iterate through the walkable cells orthogonally adjacent to the given coordinates.
+    pub fn walkable_neighbors(
+        &self,
+        grid_coords: GridCoords,
+    ) -> impl Iterator<Item = GridCoords> + '_ {
+        [
+            GridCoords::new(0, 1),
+            GridCoords::new(1, 0),
+            GridCoords::new(0, -1),
+            GridCoords::new(-1, 0),
+        ]
+        .into_iter()
+        .map(move |offset| grid_coords + offset)
+        .filter(|neighbor| self.is_walkable(*neighbor))
+    }
+
+    /// Finds the shortest orthogonal path between two cells using A*.
+    ///
+    /// The resulting path includes both `start` and `goal`.
+    /// Returns `None` if either cell is not walkable, or if no path exists.
+    pub fn find_path(&self, start: GridCoords, goal: GridCoords) -> Option<Vec<GridCoords>> {
+        if !self.is_walkable(start) || !self.is_walkable(goal) {
+            return None;
+        }
+
+        let heuristic = |grid_coords: GridCoords| {
+            (grid_coords.x - goal.x).abs() + (grid_coords.y - goal.y).abs()
+        };
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<GridCoords, GridCoords> = HashMap::new();
+        let mut cost_so_far: HashMap<GridCoords, i32> = HashMap::new();
+
+        open.push(Reverse((heuristic(start), start.x, start.y)));
+        cost_so_far.insert(start, 0);
+
+        while let Some(Reverse((_, x, y))) = open.pop() {
+            let current = GridCoords::new(x, y);
+
+            if current == goal {
+                let mut path = vec![current];
+                let mut step = current;
+                while let Some(previous) = came_from.get(&step) {
+                    path.push(*previous);
+                    step = *previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let new_cost = cost_so_far[&current] + 1;
+
+            for neighbor in self.walkable_neighbors(current) {
+                if !matches!(cost_so_far.get(&neighbor), Some(cost) if *cost <= new_cost) {
+                    cost_so_far.insert(neighbor, new_cost);
+                    came_from.insert(neighbor, current);
+                    open.push(Reverse((
+                        new_cost + heuristic(neighbor),
+                        neighbor.x,
+                        neighbor.y,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> LoadedLevel<'a> {
+    /// Creates a [`NavGrid`] from the IntGrid layer with the given identifier.
+    ///
+    /// Cells whose IntGrid value is contained in `walkable_values` are considered walkable.
+    ///
+    /// Returns `None` if no IntGrid layer with the given identifier exists in this level.
+    pub fn nav_grid(&self, layer_identifier: &str, walkable_values: &[i32]) -> Option<NavGrid> {
+        self.layer_instances()
+            .iter()
+            .find(|layer_instance| layer_instance.identifier == layer_identifier)
+            .and_then(|layer_instance| {
+                NavGrid::from_layer_instance(layer_instance, walkable_values)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ldtk::Level;
+
+    use super::*;
+
+    fn int_grid_layer(identifier: &str, c_wid: i32, c_hei: i32, csv: Vec<i32>) -> LayerInstance {
+        LayerInstance {
+            identifier: identifier.to_string(),
+            layer_instance_type: Type::IntGrid,
+            c_wid,
+            c_hei,
+            int_grid_csv: csv,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn walkability_matches_int_grid_values() {
+        // top row of the csv is the top row of the grid
+        let layer = int_grid_layer("Ground", 3, 2, vec![1, 2, 0, 0, 1, 1]);
+
+        let nav_grid = NavGrid::from_layer_instance(&layer, &[1]).unwrap();
+
+        assert_eq!(nav_grid.width(), 3);
+        assert_eq!(nav_grid.height(), 2);
+
+        assert!(nav_grid.is_walkable(GridCoords::new(0, 1)));
+        assert!(!nav_grid.is_walkable(GridCoords::new(1, 1)));
+        assert!(!nav_grid.is_walkable(GridCoords::new(2, 1)));
+        assert!(!nav_grid.is_walkable(GridCoords::new(0, 0)));
+        assert!(nav_grid.is_walkable(GridCoords::new(1, 0)));
+        assert!(nav_grid.is_walkable(GridCoords::new(2, 0)));
+
+        assert!(!nav_grid.is_walkable(GridCoords::new(-1, 0)));
+        assert!(!nav_grid.is_walkable(GridCoords::new(3, 0)));
+        assert!(!nav_grid.is_walkable(GridCoords::new(0, 2)));
+    }
+
+    #[test]
+    fn non_int_grid_layers_have_no_nav_grid() {
+        let layer = LayerInstance {
+            layer_instance_type: Type::Tiles,
+            ..int_grid_layer("Tiles", 2, 2, vec![1, 1, 1, 1])
+        };
+
+        assert_eq!(NavGrid::from_layer_instance(&layer, &[1]), None);
+    }
+
+    #[test]
+    fn finds_shortest_path_around_walls() {
+        #[rustfmt::skip]
+        let layer = int_grid_layer("Ground", 4, 4, vec![
+            1, 1, 1, 1,
+            2, 2, 2, 1,
+            1, 1, 1, 1,
+            1, 2, 2, 2,
+        ]);
+
+        let nav_grid = NavGrid::from_layer_instance(&layer, &[1]).unwrap();
+
+        let path = nav_grid
+            .find_path(GridCoords::new(0, 3), GridCoords::new(0, 0))
+            .unwrap();
+
+        assert_eq!(
+            path,
+            vec![
+                GridCoords::new(0, 3),
+                GridCoords::new(1, 3),
+                GridCoords::new(2, 3),
+                GridCoords::new(3, 3),
+                GridCoords::new(3, 2),
+                GridCoords::new(3, 1),
+                GridCoords::new(2, 1),
+                GridCoords::new(1, 1),
+                GridCoords::new(0, 1),
+                GridCoords::new(0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_path_between_disconnected_cells() {
+        let layer = int_grid_layer("Ground", 3, 1, vec![1, 2, 1]);
+
+        let nav_grid = NavGrid::from_layer_instance(&layer, &[1]).unwrap();
+
+        assert_eq!(
+            nav_grid.find_path(GridCoords::new(0, 0), GridCoords::new(2, 0)),
+            None
+        );
+        assert_eq!(
+            nav_grid.find_path(GridCoords::new(0, 0), GridCoords::new(1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn loaded_level_finds_nav_grid_by_identifier() {
+        let level = Level {
+            layer_instances: Some(vec![
+                int_grid_layer("Walls", 2, 1, vec![1, 0]),
+                int_grid_layer("Ground", 2, 1, vec![0, 1]),
+            ]),
+            ..Default::default()
+        };
+
+        let loaded_level = LoadedLevel::try_from(&level).unwrap();
+
+        let nav_grid = loaded_level.nav_grid("Ground", &[1]).unwrap();
+
+        assert!(!nav_grid.is_walkable(GridCoords::new(0, 0)));
+        assert!(nav_grid.is_walkable(GridCoords::new(1, 0)));
+
+        assert_eq!(loaded_level.nav_grid("Nonexistent", &[1]), None);
+    }
+}
@@ -1,8 +1,10 @@
 //! Contains [`RawLevelAccessor`]: convenience methods for accessing raw level data by reference.
 use crate::{
+    components::LevelBounds,
     ldtk::{LdtkJson, Level, World},
     prelude::LevelIndices,
 };
+use bevy::math::Rect;
 
 /// Iterator returned by [`RawLevelAccessor::iter_root_levels`].
 pub type IterRootLevels<'a> = std::slice::Iter<'a, Level>;
@@ -117,6 +119,28 @@ pub trait RawLevelAccessor {
             .chain(self.iter_world_levels_with_indices())
     }
 
+    /// Find the level with the given identifier.
+    ///
+    /// This requires iterating through the levels to find the match.
+    ///
+    /// Note: all levels are considered [raw](crate::assets::LdtkProject#raw-vs-loaded-levels).
+    fn find_raw_level_by_identifier(&self, identifier: &str) -> Option<&Level> {
+        self.iter_raw_levels()
+            .find(|level| level.identifier == identifier)
+    }
+
+    /// The smallest area containing every level, in Bevy's coordinate system (i.e., y-up).
+    ///
+    /// This is the union of the [`LevelBounds::world_rect`]s of all levels, or `None` if there are
+    /// no levels.
+    ///
+    /// Note: all levels are considered [raw](crate::assets::LdtkProject#raw-vs-loaded-levels).
+    fn world_bounds(&self) -> Option<Rect> {
+        self.iter_raw_levels()
+            .map(|level| LevelBounds::from(level).world_rect)
+            .reduce(|bounds, level_rect| bounds.union(level_rect))
+    }
+
     /// Immutable access to a level at the given [`LevelIndices`].
     ///
     /// Note: all levels are considered [raw](crate::assets::LdtkProject#raw-vs-loaded-levels).
@@ -283,6 +307,54 @@ pub mod tests {
         assert_eq!(project.iter_world_levels().count(), 0);
     }
 
+    #[test]
+    fn find_levels_by_identifier() {
+        let project: LdtkJson =
+            MixedLevelsLdtkJsonFaker::new(UnloadedLevelsFaker::new(4..5), 4..5).fake();
+
+        for level in project.iter_raw_levels() {
+            assert_eq!(
+                project
+                    .find_raw_level_by_identifier(&level.identifier)
+                    .map(|level| &level.identifier),
+                Some(&level.identifier)
+            );
+        }
+
+        assert_eq!(
+            project.find_raw_level_by_identifier("__not_a_level__"),
+            None
+        );
+    }
+
+    #[test]
+    fn world_bounds_contain_all_levels() {
+        assert_eq!(LdtkJson::default().world_bounds(), None);
+
+        let project = LdtkJson {
+            levels: vec![
+                Level {
+                    px_wid: 32,
+                    px_hei: 32,
+                    ..Default::default()
+                },
+                Level {
+                    px_wid: 16,
+                    px_hei: 64,
+                    world_x: 48,
+                    world_y: -16,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let world_bounds = project.world_bounds().unwrap();
+
+        assert_eq!(world_bounds.min, bevy::math::Vec2::new(0., -48.));
+        assert_eq!(world_bounds.max, bevy::math::Vec2::new(64., 16.));
+    }
+
     #[test]
     fn get_root_levels_by_indices() {
         let project: LdtkJson = RootLevelsLdtkJsonFaker::new(UnloadedLevelsFaker::new(4..5)).fake();
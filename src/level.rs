@@ -2,27 +2,40 @@
 
 use crate::{
     app::{
-        LdtkEntity, LdtkEntityMap, LdtkIntCellMap, PhantomLdtkEntity, PhantomLdtkEntityTrait,
-        PhantomLdtkIntCell, PhantomLdtkIntCellTrait,
+        LdtkEntityHooks, LdtkEntityMap, LdtkIntCellMap, PhantomLdtkEntity, PhantomLdtkEntityTrait,
+        PhantomLdtkIntCell, PhantomLdtkIntCellTrait, PooledLdtkEntityBundle,
     },
     components::*,
     ldtk::{
-        loaded_level::LoadedLevel, EntityDefinition, EnumTagValue, LayerDefinition, LayerInstance,
-        LevelBackgroundPosition, TileCustomMetadata, TileInstance, TilesetDefinition, Type,
+        auto_layer::auto_layer_tiles, loaded_level::LoadedLevel, EntityDefinition, EnumTagValue,
+        LayerDefinition, LayerInstance, TileCustomMetadata, TileInstance, TilesetDefinition, Type,
+    },
+    resources::{
+        EntityInstanceStorage, IntGridRendering, InvisibleLayers, LdtkSettings, LdtkSpawnError,
+        LdtkSpawnErrorKind, LevelPatch, LevelSpawnMode, StrictMode, TilesetInfo,
     },
-    resources::{IntGridRendering, LdtkSettings, LevelBackground},
     tile_makers::*,
     utils::*,
 };
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::world::Command,
+    prelude::*,
+    tasks::{ComputeTaskPool, ParallelSlice, TaskPool},
+};
 use bevy_ecs_tilemap::{
     map::{
         TilemapGridSize, TilemapId, TilemapSize, TilemapSpacing, TilemapTexture, TilemapTileSize,
     },
     tiles::{TilePos, TileStorage},
 };
-use std::collections::{HashMap, HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+#[cfg(feature = "render")]
+use crate::{ldtk::LevelBackgroundPosition, resources::LevelBackground};
 
 #[cfg(feature = "render")]
 use bevy_ecs_tilemap::TilemapBundle;
@@ -30,14 +43,17 @@ use bevy_ecs_tilemap::TilemapBundle;
 #[cfg(not(feature = "render"))]
 use bevy_ecs_tilemap::StandardTilemapBundle as TilemapBundle;
 
+#[cfg(feature = "render")]
 use thiserror::Error;
 
+#[cfg(feature = "render")]
 #[derive(Error, Debug)]
 enum BackgroundImageError {
     #[error("background image handle not loaded into the image assets store")]
     ImageNotLoaded,
 }
 
+#[cfg(feature = "render")]
 fn background_image_sprite_sheet_bundle(
     images: &Assets<Image>,
     texture_atlases: &mut Assets<TextureAtlasLayout>,
@@ -109,7 +125,7 @@ pub(crate) fn tile_to_grid_coords(
     )
 }
 
-fn insert_metadata_to_tile(
+pub(crate) fn insert_metadata_to_tile(
     commands: &mut Commands,
     tile_instance: &TileInstance,
     tile_entity: Entity,
@@ -133,7 +149,18 @@ fn insert_metadata_to_tile(
     metadata_inserted
 }
 
-fn spatial_bundle_for_tiles(grid_coords: GridCoords, grid_size: i32) -> SpatialBundle {
+/// The [`LdtkEntity`] inserted on entities without a registration, which carries their instance
+/// data according to the given [`EntityInstanceStorage`].
+pub(crate) fn default_ldtk_entity(
+    entity_instance_storage: EntityInstanceStorage,
+) -> Box<dyn PhantomLdtkEntityTrait> {
+    match entity_instance_storage {
+        EntityInstanceStorage::Clone => Box::new(PhantomLdtkEntity::<EntityInstanceBundle>::new()),
+        EntityInstanceStorage::Reference => Box::new(PhantomLdtkEntity::<EntityInstanceRef>::new()),
+    }
+}
+
+pub(crate) fn spatial_bundle_for_tiles(grid_coords: GridCoords, grid_size: i32) -> SpatialBundle {
     let translation =
         grid_coords_to_translation_relative_to_tile_layer(grid_coords, IVec2::splat(grid_size))
             .extend(0.);
@@ -181,7 +208,7 @@ fn insert_tile_metadata_for_layer(
     }
 }
 
-fn layer_grid_tiles(grid_tiles: Vec<TileInstance>) -> Vec<Vec<TileInstance>> {
+pub(crate) fn layer_grid_tiles(grid_tiles: Vec<TileInstance>) -> Vec<Vec<TileInstance>> {
     let mut layer = Vec::new();
     let mut overflow = Vec::new();
     for tile in grid_tiles {
@@ -200,38 +227,392 @@ fn layer_grid_tiles(grid_tiles: Vec<TileInstance>) -> Vec<Vec<TileInstance>> {
     layered_grid_tiles
 }
 
-fn tile_in_layer_bounds(tile: &TileInstance, layer_instance: &LayerInstance) -> bool {
+pub(crate) fn tile_in_layer_bounds(tile: &TileInstance, layer_instance: &LayerInstance) -> bool {
     tile.px.x >= 0
         && tile.px.y >= 0
         && tile.px.x < (layer_instance.c_wid * layer_instance.grid_size)
         && tile.px.y < (layer_instance.c_hei * layer_instance.grid_size)
 }
 
+/// Registrations of the IntGrid values of a layer, resolved once per distinct value.
+///
+/// When the layer's values are close together, registrations are stored in a flat table indexed
+/// by each value's offset from the smallest value in the layer, so spawning a cell doesn't need to
+/// hash or clone anything.
+/// Layers with few values spread over a large range fall back to a [HashMap], so the table
+/// doesn't allocate a slot for every value in between.
+enum IntCellRegistrationTable<'a> {
+    Dense {
+        min_value: i32,
+        registrations: Vec<Option<&'a dyn PhantomLdtkIntCellTrait>>,
+    },
+    Sparse(HashMap<i32, &'a dyn PhantomLdtkIntCellTrait>),
+}
+
+impl<'a> IntCellRegistrationTable<'a> {
+    /// Value ranges up to this size always use a flat table.
+    const MIN_DENSE_RANGE: usize = 256;
+
+    fn new(
+        layer_identifier: &str,
+        int_grid_csv: &[i32],
+        default: &'a dyn PhantomLdtkIntCellTrait,
+        ldtk_int_cell_map: &'a LdtkIntCellMap,
+    ) -> Self {
+        let mut registrations: HashMap<i32, &'a dyn PhantomLdtkIntCellTrait> = HashMap::new();
+
+        for value in int_grid_csv.iter().copied().filter(|value| *value != 0) {
+            registrations.entry(value).or_insert_with(|| {
+                try_each_optional_permutation(layer_identifier.to_string(), value, |x, y| {
+                    ldtk_int_cell_map.get(&(x, y)).map(Box::as_ref)
+                })
+                .unwrap_or(default)
+            });
+        }
+
+        let (Some(&min_value), Some(&max_value)) =
+            (registrations.keys().min(), registrations.keys().max())
+        else {
+            return IntCellRegistrationTable::Sparse(registrations);
+        };
+
+        let range = max_value.abs_diff(min_value) as usize + 1;
+
+        if range > Self::MIN_DENSE_RANGE.max(registrations.len() * 4) {
+            return IntCellRegistrationTable::Sparse(registrations);
+        }
+
+        let mut dense_registrations = vec![None; range];
+        for (value, registration) in registrations {
+            dense_registrations[value.abs_diff(min_value) as usize] = Some(registration);
+        }
+
+        IntCellRegistrationTable::Dense {
+            min_value,
+            registrations: dense_registrations,
+        }
+    }
+
+    fn get(&self, value: i32) -> Option<&'a dyn PhantomLdtkIntCellTrait> {
+        match self {
+            IntCellRegistrationTable::Dense {
+                min_value,
+                registrations,
+            } => {
+                if value < *min_value {
+                    return None;
+                }
+
+                registrations
+                    .get(value.abs_diff(*min_value) as usize)
+                    .copied()
+                    .flatten()
+            }
+            IntCellRegistrationTable::Sparse(registrations) => registrations.get(&value).copied(),
+        }
+    }
+}
+
+/// Tile data of a Tile, AutoLayer, or IntGrid layer, prepared before any of it is spawned.
+struct PreparedTileLayer {
+    /// The layer's in-bounds tiles, split into sub-layers without overlapping tiles.
+    grid_tiles: Vec<Vec<TileInstance>>,
+    metadata_map: HashMap<i32, TileMetadata>,
+    enum_tags_map: HashMap<i32, TileEnumTags>,
+}
+
+/// Returns the [TileMetadata] and [TileEnumTags] of the tiles of a tileset, by tile id.
+pub(crate) fn tile_metadata_maps(
+    tileset_definition: Option<&TilesetDefinition>,
+) -> (HashMap<i32, TileMetadata>, HashMap<i32, TileEnumTags>) {
+    let metadata_map: HashMap<i32, TileMetadata> = tileset_definition
+        .map(|tileset_definition| {
+            tileset_definition
+                .custom_data
+                .iter()
+                .map(|TileCustomMetadata { data, tile_id }| {
+                    (*tile_id, TileMetadata { data: data.clone() })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut enum_tags_map: HashMap<i32, TileEnumTags> = HashMap::new();
+
+    if let Some(tileset_definition) = tileset_definition {
+        for EnumTagValue {
+            enum_value_id,
+            tile_ids,
+        } in tileset_definition.enum_tags.iter()
+        {
+            for tile_id in tile_ids {
+                enum_tags_map
+                    .entry(*tile_id)
+                    .or_insert_with(|| TileEnumTags {
+                        tags: Vec::new(),
+                        source_enum_uid: tileset_definition.tags_source_enum_uid,
+                    })
+                    .tags
+                    .push(enum_value_id.clone());
+            }
+        }
+    }
+
+    (metadata_map, enum_tags_map)
+}
+
+fn prepare_tile_layer(
+    layer_instance: &LayerInstance,
+    tileset_definition: Option<&TilesetDefinition>,
+) -> PreparedTileLayer {
+    let (metadata_map, enum_tags_map) = tile_metadata_maps(tileset_definition);
+
+    let mut grid_tiles = layer_instance.grid_tiles.clone();
+    grid_tiles.extend(layer_instance.auto_layer_tiles.clone());
+
+    let grid_tiles = layer_grid_tiles(grid_tiles)
+        .into_iter()
+        // filter out tiles that are out of bounds
+        .map(|grid_tiles| {
+            grid_tiles
+                .into_iter()
+                .filter(|tile| tile_in_layer_bounds(tile, layer_instance))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    PreparedTileLayer {
+        grid_tiles,
+        metadata_map,
+        enum_tags_map,
+    }
+}
+
+/// Prepares the tile data of all the given layers in parallel on the [`ComputeTaskPool`].
+///
+/// Only the preparation is parallel, the results are spawned with [`Commands`] afterwards.
+/// Returns `None` for entity layers, in the same order as the given layers.
+fn prepare_tile_layers(
+    layer_instances: &[&LayerInstance],
+    tileset_definition_map: &HashMap<i32, &TilesetDefinition>,
+) -> Vec<Option<PreparedTileLayer>> {
+    layer_instances.par_chunk_map(
+        ComputeTaskPool::get_or_init(TaskPool::default),
+        1,
+        |_, layer_instances| {
+            let layer_instance = layer_instances[0];
+
+            if layer_instance.layer_instance_type == Type::Entities {
+                return None;
+            }
+
+            let tileset_definition = layer_instance
+                .tileset_def_uid
+                .and_then(|uid| tileset_definition_map.get(&uid))
+                .copied();
+
+            Some(prepare_tile_layer(layer_instance, tileset_definition))
+        },
+    )
+}
+
+/// Applies the given [`LevelPatch`] to the layers of a level.
+///
+/// Auto-layer rules are re-evaluated for layers whose IntGrid source was modified by the patch,
+/// so their `auto_layer_tiles` reflect the modifications.
+fn patch_layer_instances<'a>(
+    layer_instances: &'a [LayerInstance],
+    level_patch: Option<&LevelPatch>,
+    layer_definition_map: &HashMap<i32, &LayerDefinition>,
+    tileset_definition_map: &HashMap<i32, &TilesetDefinition>,
+) -> Vec<Cow<'a, LayerInstance>> {
+    let Some(level_patch) = level_patch else {
+        return layer_instances.iter().map(Cow::Borrowed).collect();
+    };
+
+    let mut patched_layer_instances: Vec<Cow<LayerInstance>> = layer_instances
+        .iter()
+        .map(|layer_instance| level_patch.apply_to_layer_instance(layer_instance))
+        .collect();
+
+    let modified_int_grids: HashMap<i32, Vec<i32>> = patched_layer_instances
+        .iter()
+        .filter(|layer_instance| {
+            level_patch
+                .int_grid_cells
+                .iter()
+                .any(|cell| cell.layer_identifier == layer_instance.identifier)
+        })
+        .map(|layer_instance| {
+            (
+                layer_instance.layer_def_uid,
+                layer_instance.int_grid_csv.clone(),
+            )
+        })
+        .collect();
+
+    if modified_int_grids.is_empty() {
+        return patched_layer_instances;
+    }
+
+    for layer_instance in patched_layer_instances.iter_mut() {
+        let Some(layer_definition) = layer_definition_map.get(&layer_instance.layer_def_uid) else {
+            continue;
+        };
+
+        if layer_definition.auto_rule_groups.is_empty() {
+            continue;
+        }
+
+        let source_layer_uid = layer_definition
+            .auto_source_layer_def_uid
+            .unwrap_or(layer_definition.uid);
+
+        let (Some(int_grid_csv), Some(source_layer_definition), Some(tileset_definition)) = (
+            modified_int_grids.get(&source_layer_uid),
+            layer_definition_map.get(&source_layer_uid),
+            layer_instance
+                .tileset_def_uid
+                .and_then(|uid| tileset_definition_map.get(&uid)),
+        ) else {
+            continue;
+        };
+
+        let auto_layer_tiles = auto_layer_tiles(
+            layer_instance,
+            int_grid_csv,
+            layer_definition,
+            source_layer_definition,
+            tileset_definition,
+        );
+
+        layer_instance.to_mut().auto_layer_tiles = auto_layer_tiles;
+    }
+
+    patched_layer_instances
+}
+
+/// Reports a problem found while spawning a level according to the given [StrictMode].
+///
+/// Problems are only logged if strict mode is enabled, or if they have always been logged, see
+/// [LdtkSpawnErrorKind::is_logged_by_default].
+///
+/// Returns a command that sends the problem as an [LdtkSpawnError] event, if necessary.
+fn report_spawn_error(
+    strict: StrictMode,
+    level: &LoadedLevel,
+    kind: LdtkSpawnErrorKind,
+) -> impl Command {
+    let error = LdtkSpawnError {
+        level_iid: LevelIid::new(level.iid().clone()),
+        kind,
+    };
+
+    match strict {
+        StrictMode::Panic => panic!("{error}"),
+        StrictMode::Events => warn!("{error}"),
+        StrictMode::Disabled if error.kind.is_logged_by_default() => warn!("{error}"),
+        StrictMode::Disabled => (),
+    }
+
+    move |world: &mut World| {
+        if strict == StrictMode::Events {
+            world.send_event(error);
+        }
+    }
+}
+
+/// Spawns the layers and entities of a level as children of `ldtk_entity`.
+///
+/// If a loaded `composite_image` is given, it is spawned in place of the level's background and
+/// tile layers.
+/// With [`LevelSpawnMode::DataOnly`], only the level's data is spawned, see its documentation.
+///
+/// Without the `render` feature, no background sprites are spawned and tilemaps are given no
+/// texture, so only the logical components of the level are created.
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "render"), allow(unused_variables))]
 pub fn spawn_level(
     level: LoadedLevel,
     background_image: &Option<Handle<Image>>,
+    composite_image: Option<&Handle<Image>>,
     commands: &mut Commands,
     asset_server: &AssetServer,
     images: &Assets<Image>,
     texture_atlases: &mut Assets<TextureAtlasLayout>,
     ldtk_entity_map: &LdtkEntityMap,
     ldtk_int_cell_map: &LdtkIntCellMap,
+    ldtk_entity_hooks: &LdtkEntityHooks,
     entity_definition_map: &HashMap<i32, &EntityDefinition>,
     layer_definition_map: &HashMap<i32, &LayerDefinition>,
     tileset_map: &HashMap<i32, Handle<Image>>,
     tileset_definition_map: &HashMap<i32, &TilesetDefinition>,
+    tileset_infos: &HashMap<i32, TilesetInfo>,
     int_grid_image_handle: &Option<Handle<Image>>,
     worldly_set: HashSet<Worldly>,
     ldtk_entity: Entity,
     ldtk_settings: &LdtkSettings,
+    level_spawn_mode: LevelSpawnMode,
+    level_patch: Option<&LevelPatch>,
 ) {
     let layer_instances = level.layer_instances();
 
+    commands.entity(ldtk_entity).insert((
+        LevelBounds {
+            origin: ldtk_settings.level_origin,
+            ..LevelBounds::from(level.raw())
+        },
+        LevelInfo::from(level.raw()),
+    ));
+
+    // Everything in the level is positioned relative to its bottom-left corner.
+    let origin_offset = ldtk_settings
+        .level_origin
+        .bottom_left_offset(*level.px_hei());
+
     let mut layer_z = 0;
 
-    if ldtk_settings.level_background == LevelBackground::Rendered {
-        let translation = Vec3::new(*level.px_wid() as f32, *level.px_hei() as f32, 0.) / 2.;
+    let data_only = level_spawn_mode == LevelSpawnMode::DataOnly;
+
+    // Tile layers are only replaced by the composite image if it actually loaded
+    let composite_image = match composite_image {
+        Some(composite_image) if images.get(composite_image).is_none() => {
+            commands.add(report_spawn_error(
+                ldtk_settings.strict,
+                &level,
+                LdtkSpawnErrorKind::MissingCompositeImage,
+            ));
+            None
+        }
+        composite_image => composite_image,
+    };
+
+    #[cfg(feature = "render")]
+    if let Some(composite_image) = composite_image.filter(|_| !data_only) {
+        let size = Vec2::new(*level.px_wid() as f32, *level.px_hei() as f32);
+
+        let composite_entity = commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(size),
+                    ..default()
+                },
+                texture: composite_image.clone(),
+                transform: Transform::from_translation(
+                    (size / 2. + origin_offset).extend(ldtk_settings.layer_z.stack_z(layer_z)),
+                ),
+                ..default()
+            })
+            .insert(Name::new("_composite"))
+            .id();
+
+        commands.entity(ldtk_entity).add_child(composite_entity);
+
+        layer_z += 1;
+    } else if ldtk_settings.level_background == LevelBackground::Rendered && !data_only {
+        let translation = (Vec2::new(*level.px_wid() as f32, *level.px_hei() as f32) / 2.
+            + origin_offset)
+            .extend(ldtk_settings.layer_z.stack_z(layer_z));
 
         let background_entity = commands
             .spawn(SpriteBundle {
@@ -259,107 +640,265 @@ pub fn spawn_level(
                 background_image_handle,
                 background_position,
                 *level.px_hei(),
-                layer_z as f32,
+                ldtk_settings.layer_z.stack_z(layer_z),
             ) {
-                Ok(sprite_sheet_bundle) => {
+                Ok(mut sprite_sheet_bundle) => {
+                    sprite_sheet_bundle.sprite_bundle.transform.translation +=
+                        origin_offset.extend(0.);
+
                     commands.entity(ldtk_entity).with_children(|parent| {
                         parent.spawn(sprite_sheet_bundle);
                     });
 
                     layer_z += 1;
                 }
-                Err(e) => warn!("{}", e),
+                Err(e) => {
+                    commands.add(report_spawn_error(
+                        ldtk_settings.strict,
+                        &level,
+                        LdtkSpawnErrorKind::Background(e.to_string()),
+                    ));
+                }
             }
         }
     }
 
-    for layer_instance in layer_instances
+    let layer_instances = patch_layer_instances(
+        layer_instances,
+        level_patch,
+        layer_definition_map,
+        tileset_definition_map,
+    );
+
+    let layer_instances: Vec<&LayerInstance> = layer_instances
         .iter()
+        .map(|layer_instance| &**layer_instance)
         .filter(|layer| {
             !ldtk_settings
                 .exclusions
                 .layer_identifiers
                 .contains(&layer.identifier)
+                && ldtk_settings.layer_filter.allows(layer)
+                // the composite image replaces all tile layers
+                && !(composite_image.is_some()
+                    && matches!(layer.layer_instance_type, Type::Tiles | Type::AutoLayer))
+                && (layer.visible || ldtk_settings.invisible_layers != InvisibleLayers::Excluded)
+                && (!data_only || layer.layer_instance_type == Type::Entities)
         })
         .rev()
+        .collect();
+
+    let prepared_tile_layers = prepare_tile_layers(&layer_instances, tileset_definition_map);
+
+    for (layer_instance, prepared_tile_layer) in
+        layer_instances.into_iter().zip(prepared_tile_layers)
     {
+        // IntGrid layers are still spawned for their data when the composite image is rendered
+        let layer_visibility = if (!layer_instance.visible
+            && ldtk_settings.invisible_layers == InvisibleLayers::Hidden)
+            || (composite_image.is_some() && layer_instance.layer_instance_type == Type::IntGrid)
+        {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+
         let layer_offset = Vec2::new(
             layer_instance.px_total_offset_x as f32,
             -layer_instance.px_total_offset_y as f32,
-        );
+        ) + origin_offset;
 
         match layer_instance.layer_instance_type {
             Type::Entities => {
+                // Reported once the layer is spawned, since the child builder can't add commands
+                let mut entity_spawn_errors = Vec::new();
+
                 let layer_entity = commands
                     .spawn(SpatialBundle::from_transform(Transform::from_translation(
-                        layer_offset.extend(layer_z as f32),
+                        layer_offset.extend(ldtk_settings.layer_z.layer_z(
+                            &layer_instance.identifier,
+                            layer_z,
+                            0,
+                        )),
                     )))
+                    .insert(layer_visibility)
                     .insert(LayerMetadata::from(layer_instance))
                     .insert(Name::new(layer_instance.identifier.to_owned()))
                     .with_children(|commands| {
-                        for entity_instance in &layer_instance.entity_instances {
-                            let transform = calculate_transform_from_entity_instance(
+                        let default_ldtk_entity =
+                            default_ldtk_entity(ldtk_settings.entity_instance_storage);
+
+                        // Registrations are looked up once per entity identifier in this layer,
+                        // rather than cloning both identifiers for every entity instance.
+                        let mut ldtk_entity_registrations: HashMap<
+                            &str,
+                            &dyn PhantomLdtkEntityTrait,
+                        > = HashMap::new();
+
+                        // Pooled registrations construct their bundle once per entity identifier
+                        // in this layer, and clone it onto every instance.
+                        let mut pooled_ldtk_entity_bundles: HashMap<
+                            &str,
+                            Option<Box<dyn PooledLdtkEntityBundle>>,
+                        > = HashMap::new();
+
+                        for (entity_instance, entity_z) in ldtk_settings
+                            .entity_spawn_order
+                            .sort(&layer_instance.entity_instances)
+                        {
+                            let mut transform = calculate_transform_from_entity_instance(
                                 entity_instance,
                                 entity_definition_map,
                                 *level.px_hei(),
                             );
+                            transform.translation.z += entity_z
+                                + ldtk_settings
+                                    .entity_z_field
+                                    .z(entity_instance)
+                                    .unwrap_or_default();
+                            let entity_definition =
+                                *entity_definition_map.get(&entity_instance.def_uid).unwrap();
                             // Note: entities do not seem to be affected visually by layer offsets in
                             // the editor, so no layer offset is added to the transform here.
 
-                            let (tileset, tileset_definition) = match &entity_instance.tile {
+                            let (tileset_info, tileset_definition) = match &entity_instance.tile {
                                 Some(t) => (
-                                    tileset_map.get(&t.tileset_uid),
+                                    tileset_infos.get(&t.tileset_uid),
                                     tileset_definition_map.get(&t.tileset_uid).copied(),
                                 ),
                                 None => (None, None),
                             };
+                            let tileset =
+                                tileset_info.and_then(|tileset_info| tileset_info.image.as_ref());
+
+                            #[cfg(feature = "render")]
+                            if entity_instance.tile.is_some() && tileset.is_none() {
+                                entity_spawn_errors.push(
+                                    LdtkSpawnErrorKind::MissingEntityTileset {
+                                        layer_identifier: layer_instance.identifier.clone(),
+                                        entity_identifier: entity_instance.identifier.clone(),
+                                    },
+                                );
+                            }
 
-                            let predicted_worldly = Worldly::bundle_entity(
-                                entity_instance,
-                                layer_instance,
-                                tileset,
-                                tileset_definition,
-                                asset_server,
-                                texture_atlases,
-                            );
+                            let predicted_worldly = Worldly::from_entity_info(entity_instance);
 
                             if !worldly_set.contains(&predicted_worldly) {
-                                let default_ldtk_entity: Box<dyn PhantomLdtkEntityTrait> =
-                                    Box::new(PhantomLdtkEntity::<EntityInstanceBundle>::new());
+                                if !data_only
+                                    && ldtk_settings.strict != StrictMode::Disabled
+                                    && !ldtk_entity_registrations
+                                        .contains_key(entity_instance.identifier.as_str())
+                                    && try_each_optional_permutation(
+                                        layer_instance.identifier.clone(),
+                                        entity_instance.identifier.clone(),
+                                        |x, y| ldtk_entity_map.get(&(x, y)),
+                                    )
+                                    .is_none()
+                                {
+                                    entity_spawn_errors.push(
+                                        LdtkSpawnErrorKind::UnregisteredEntity {
+                                            layer_identifier: layer_instance.identifier.clone(),
+                                            entity_identifier: entity_instance.identifier.clone(),
+                                        },
+                                    );
+                                }
+
                                 let mut entity_commands = commands.spawn_empty();
 
                                 // insert Name before evaluating LdtkEntitys so that user-provided
                                 // names aren't overwritten
                                 entity_commands.insert((
                                     EntityIid::new(entity_instance.iid.to_owned()),
-                                    Name::new(entity_instance.identifier.to_owned()),
+                                    ldtk_name(&entity_instance.identifier, &entity_instance.iid),
                                 ));
 
-                                ldtk_map_get_or_default(
-                                    layer_instance.identifier.clone(),
-                                    entity_instance.identifier.clone(),
-                                    &default_ldtk_entity,
-                                    ldtk_entity_map,
-                                )
-                                .evaluate(
-                                    &mut entity_commands,
-                                    entity_instance,
-                                    layer_instance,
-                                    tileset,
-                                    tileset_definition,
-                                    asset_server,
-                                    texture_atlases,
-                                );
+                                if ldtk_settings.spawn_point_identifier.as_ref()
+                                    == Some(&entity_instance.identifier)
+                                {
+                                    entity_commands.insert(SpawnPoint {
+                                        level_iid: LevelIid::new(level.iid().clone()),
+                                    });
+                                }
+
+                                let ldtk_entity_registration = *ldtk_entity_registrations
+                                    .entry(entity_instance.identifier.as_str())
+                                    .or_insert_with(|| {
+                                        if data_only {
+                                            return default_ldtk_entity.as_ref();
+                                        }
+
+                                        ldtk_map_get_or_default(
+                                            layer_instance.identifier.clone(),
+                                            entity_instance.identifier.clone(),
+                                            &default_ldtk_entity,
+                                            ldtk_entity_map,
+                                        )
+                                        .as_ref()
+                                    });
+
+                                let pooled_bundle = pooled_ldtk_entity_bundles
+                                    .entry(entity_instance.identifier.as_str())
+                                    .or_insert_with(|| {
+                                        ldtk_entity_registration.pooled_bundle(
+                                            entity_instance,
+                                            entity_definition,
+                                            layer_instance,
+                                            tileset_info,
+                                            tileset_definition,
+                                            asset_server,
+                                            texture_atlases,
+                                        )
+                                    });
+
+                                match pooled_bundle {
+                                    // Pooled bundles can't carry per-instance data, so the
+                                    // instance data is stored like for unregistered entities.
+                                    Some(pooled_bundle) => {
+                                        pooled_bundle.insert_clone(&mut entity_commands);
+                                        default_ldtk_entity.evaluate(
+                                            &mut entity_commands,
+                                            entity_instance,
+                                            entity_definition,
+                                            layer_instance,
+                                            tileset_info,
+                                            tileset_definition,
+                                            asset_server,
+                                            texture_atlases,
+                                        );
+                                    }
+                                    None => {
+                                        ldtk_entity_registration.evaluate(
+                                            &mut entity_commands,
+                                            entity_instance,
+                                            entity_definition,
+                                            layer_instance,
+                                            tileset_info,
+                                            tileset_definition,
+                                            asset_server,
+                                            texture_atlases,
+                                        );
+                                    }
+                                }
 
                                 entity_commands.insert(SpatialBundle {
                                     transform,
                                     ..default()
                                 });
+
+                                if let Some(hook_command) =
+                                    ldtk_entity_hooks.entity_command(entity_instance)
+                                {
+                                    entity_commands.add(hook_command);
+                                }
                             }
                         }
                     })
                     .id();
 
+                for kind in entity_spawn_errors {
+                    commands.add(report_spawn_error(ldtk_settings.strict, &level, kind));
+                }
+
                 commands.entity(ldtk_entity).add_child(layer_entity);
                 layer_z += 1;
             }
@@ -389,6 +928,12 @@ pub fn spawn_level(
 
                 let grid_size = layer_instance.grid_size as f32;
 
+                let layer_tint = ldtk_settings.layer_tints.tint(
+                    layer_definition_map
+                        .get(&layer_instance.layer_def_uid)
+                        .expect("Encountered layer without definition"),
+                );
+
                 let tilemap_grid_size = TilemapGridSize {
                     x: grid_size,
                     y: grid_size,
@@ -418,64 +963,46 @@ pub fn spawn_level(
                     _ => TilemapSpacing::default(),
                 };
 
+                #[cfg(feature = "render")]
                 let texture = match (tileset_definition, int_grid_image_handle) {
-                    (Some(tileset_definition), _) => TilemapTexture::Single(
-                        tileset_map.get(&tileset_definition.uid).unwrap().clone(),
-                    ),
+                    (Some(tileset_definition), _) => {
+                        match tileset_map.get(&tileset_definition.uid) {
+                            Some(handle) => TilemapTexture::Single(handle.clone()),
+                            None => {
+                                commands.add(report_spawn_error(
+                                    ldtk_settings.strict,
+                                    &level,
+                                    LdtkSpawnErrorKind::MissingTilesetImage {
+                                        layer_identifier: layer_instance.identifier.clone(),
+                                    },
+                                ));
+                                continue;
+                            }
+                        }
+                    }
                     (None, Some(handle)) => TilemapTexture::Single(handle.clone()),
                     _ => {
-                        warn!("unable to render tilemap layer, it has no tileset and no intgrid layers were expected");
+                        commands.add(report_spawn_error(
+                            ldtk_settings.strict,
+                            &level,
+                            LdtkSpawnErrorKind::MissingTileset {
+                                layer_identifier: layer_instance.identifier.clone(),
+                            },
+                        ));
                         continue;
                     }
                 };
 
-                let metadata_map: HashMap<i32, TileMetadata> = tileset_definition
-                    .map(|tileset_definition| {
-                        tileset_definition
-                            .custom_data
-                            .iter()
-                            .map(|TileCustomMetadata { data, tile_id }| {
-                                (*tile_id, TileMetadata { data: data.clone() })
-                            })
-                            .collect()
-                    })
-                    .unwrap_or_default();
-
-                let mut enum_tags_map: HashMap<i32, TileEnumTags> = HashMap::new();
-
-                if let Some(tileset_definition) = tileset_definition {
-                    for EnumTagValue {
-                        enum_value_id,
-                        tile_ids,
-                    } in tileset_definition.enum_tags.iter()
-                    {
-                        for tile_id in tile_ids {
-                            enum_tags_map
-                                .entry(*tile_id)
-                                .or_insert_with(|| TileEnumTags {
-                                    tags: Vec::new(),
-                                    source_enum_uid: tileset_definition.tags_source_enum_uid,
-                                })
-                                .tags
-                                .push(enum_value_id.clone());
-                        }
-                    }
-                }
+                #[cfg(not(feature = "render"))]
+                let texture = TilemapTexture::default();
 
-                let mut grid_tiles = layer_instance.grid_tiles.clone();
-                grid_tiles.extend(layer_instance.auto_layer_tiles.clone());
-
-                for (i, grid_tiles) in layer_grid_tiles(grid_tiles)
-                    .into_iter()
-                    // filter out tiles that are out of bounds
-                    .map(|grid_tiles| {
-                        grid_tiles
-                            .into_iter()
-                            .filter(|tile| tile_in_layer_bounds(tile, layer_instance))
-                            .collect::<Vec<_>>()
-                    })
-                    .enumerate()
-                {
+                let PreparedTileLayer {
+                    grid_tiles: layered_grid_tiles,
+                    metadata_map,
+                    enum_tags_map,
+                } = prepared_tile_layer.expect("tile layers should have prepared tile data");
+
+                for (i, grid_tiles) in layered_grid_tiles.into_iter().enumerate() {
                     let layer_entity = commands.spawn_empty().id();
 
                     let tilemap_bundle = if layer_instance.layer_instance_type == Type::IntGrid {
@@ -492,16 +1019,19 @@ pub fn spawn_level(
                                     size,
                                     TilemapId(layer_entity),
                                     tile_pos_to_tile_grid_bundle_maker(
-                                        tile_pos_to_transparent_tile_maker(
-                                            tile_pos_to_int_grid_with_grid_tiles_tile_maker(
-                                                &grid_tiles,
-                                                &layer_instance.int_grid_csv,
-                                                layer_instance.c_wid,
-                                                layer_instance.c_hei,
-                                                layer_instance.grid_size,
-                                                i,
+                                        tile_pos_to_tinted_tile_maker(
+                                            tile_pos_to_transparent_tile_maker(
+                                                tile_pos_to_int_grid_with_grid_tiles_tile_maker(
+                                                    &grid_tiles,
+                                                    &layer_instance.int_grid_csv,
+                                                    layer_instance.c_wid,
+                                                    layer_instance.c_hei,
+                                                    layer_instance.grid_size,
+                                                    i,
+                                                ),
+                                                layer_instance.opacity,
                                             ),
-                                            layer_instance.opacity,
+                                            layer_tint,
                                         ),
                                     ),
                                 );
@@ -520,14 +1050,17 @@ pub fn spawn_level(
                                             size,
                                             TilemapId(layer_entity),
                                             tile_pos_to_tile_grid_bundle_maker(
-                                                tile_pos_to_transparent_tile_maker(
-                                                    tile_pos_to_int_grid_colored_tile_maker(
-                                                        &layer_instance.int_grid_csv,
-                                                        int_grid_value_defs,
-                                                        layer_instance.c_wid,
-                                                        layer_instance.c_hei,
+                                                tile_pos_to_tinted_tile_maker(
+                                                    tile_pos_to_transparent_tile_maker(
+                                                        tile_pos_to_int_grid_colored_tile_maker(
+                                                            &layer_instance.int_grid_csv,
+                                                            int_grid_value_defs,
+                                                            layer_instance.c_wid,
+                                                            layer_instance.c_hei,
+                                                        ),
+                                                        layer_instance.opacity,
                                                     ),
-                                                    layer_instance.opacity,
+                                                    layer_tint,
                                                 ),
                                             ),
                                         );
@@ -539,14 +1072,17 @@ pub fn spawn_level(
                                             size,
                                             TilemapId(layer_entity),
                                             tile_pos_to_tile_grid_bundle_maker(
-                                                tile_pos_to_transparent_tile_maker(
-                                                    tile_pos_to_tile_if_int_grid_nonzero_maker(
-                                                        tile_pos_to_invisible_tile,
-                                                        &layer_instance.int_grid_csv,
-                                                        layer_instance.c_wid,
-                                                        layer_instance.c_hei,
+                                                tile_pos_to_tinted_tile_maker(
+                                                    tile_pos_to_transparent_tile_maker(
+                                                        tile_pos_to_tile_if_int_grid_nonzero_maker(
+                                                            tile_pos_to_invisible_tile,
+                                                            &layer_instance.int_grid_csv,
+                                                            layer_instance.c_wid,
+                                                            layer_instance.c_hei,
+                                                        ),
+                                                        layer_instance.opacity,
                                                     ),
-                                                    layer_instance.opacity,
+                                                    layer_tint,
                                                 ),
                                             ),
                                         );
@@ -556,6 +1092,20 @@ pub fn spawn_level(
                         }
 
                         if i == 0 {
+                            commands
+                                .entity(layer_entity)
+                                .insert(IntGridCsv::from(layer_instance));
+
+                            let default_ldtk_int_cell: Box<dyn PhantomLdtkIntCellTrait> =
+                                Box::new(PhantomLdtkIntCell::<IntGridCellBundle>::new());
+
+                            let ldtk_int_cell_registrations = IntCellRegistrationTable::new(
+                                &layer_instance.identifier,
+                                &layer_instance.int_grid_csv,
+                                default_ldtk_int_cell.as_ref(),
+                                ldtk_int_cell_map,
+                            );
+
                             for (i, value) in layer_instance
                                 .int_grid_csv
                                 .iter()
@@ -571,20 +1121,14 @@ pub fn spawn_level(
                                 if let Some(tile_entity) = storage.get(&grid_coords.into()) {
                                     let mut entity_commands = commands.entity(tile_entity);
 
-                                    let default_ldtk_int_cell: Box<dyn PhantomLdtkIntCellTrait> =
-                                        Box::new(PhantomLdtkIntCell::<IntGridCellBundle>::new());
-
-                                    ldtk_map_get_or_default(
-                                        layer_instance.identifier.clone(),
-                                        *value,
-                                        &default_ldtk_int_cell,
-                                        ldtk_int_cell_map,
-                                    )
-                                    .evaluate(
-                                        &mut entity_commands,
-                                        IntGridCell { value: *value },
-                                        layer_instance,
-                                    );
+                                    ldtk_int_cell_registrations
+                                        .get(*value)
+                                        .expect("registrations should be resolved for every value in the layer")
+                                        .evaluate(
+                                            &mut entity_commands,
+                                            IntGridCell { value: *value },
+                                            layer_instance,
+                                        );
                                 }
                             }
                         }
@@ -611,13 +1155,16 @@ pub fn spawn_level(
                         }
                     } else {
                         let tile_bundle_maker =
-                            tile_pos_to_tile_grid_bundle_maker(tile_pos_to_transparent_tile_maker(
-                                tile_pos_to_tile_maker(
-                                    &grid_tiles,
-                                    layer_instance.c_hei,
-                                    layer_instance.grid_size,
+                            tile_pos_to_tile_grid_bundle_maker(tile_pos_to_tinted_tile_maker(
+                                tile_pos_to_transparent_tile_maker(
+                                    tile_pos_to_tile_maker(
+                                        &grid_tiles,
+                                        layer_instance.c_hei,
+                                        layer_instance.grid_size,
+                                    ),
+                                    layer_instance.opacity,
                                 ),
-                                layer_instance.opacity,
+                                layer_tint,
                             ));
 
                         // When we add metadata to tiles, we need to add additional
@@ -707,8 +1254,13 @@ pub fn spawn_level(
                                 + centering_adjustment
                                 + pivot_adjustment
                                 + layer_offset)
-                                .extend(layer_z as f32),
+                                .extend(ldtk_settings.layer_z.layer_z(
+                                    &layer_instance.identifier,
+                                    layer_z,
+                                    i,
+                                )),
                         )))
+                        .insert(layer_visibility)
                         .insert(LayerMetadata::from(layer_instance))
                         .insert(Name::new(layer_instance.identifier.to_owned()));
 
@@ -720,3 +1272,34 @@ pub fn spawn_level(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_cell_registration_table_falls_back_to_hash_map_for_sparse_values() {
+        let default = PhantomLdtkIntCell::<IntGridCellBundle>::new();
+        let ldtk_int_cell_map = LdtkIntCellMap::new();
+
+        let dense = IntCellRegistrationTable::new(
+            "IntGrid",
+            &[0, 3, 1, 3, 2],
+            &default,
+            &ldtk_int_cell_map,
+        );
+        assert!(matches!(dense, IntCellRegistrationTable::Dense { .. }));
+        assert!(dense.get(1).is_some() && dense.get(3).is_some());
+        assert!(dense.get(0).is_none() && dense.get(4).is_none());
+
+        let sparse = IntCellRegistrationTable::new(
+            "IntGrid",
+            &[1, 0, i32::MAX, i32::MIN],
+            &default,
+            &ldtk_int_cell_map,
+        );
+        assert!(matches!(sparse, IntCellRegistrationTable::Sparse(_)));
+        assert!(sparse.get(i32::MIN).is_some() && sparse.get(i32::MAX).is_some());
+        assert!(sparse.get(2).is_none());
+    }
+}
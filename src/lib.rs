@@ -21,11 +21,27 @@
 //! I.e., projects that store level data within the main project file.
 //! - `external_levels`: Enable support for projects that store levels externally.
 //! I.e., projects that store data for each level in files separate from the main project file.
-//! - `derive`: Enables the derive macros for [LdtkEntity] and [LdtkIntCell].
+//! - `derive`: Enables the derive macros for [LdtkEntity] and [LdtkIntCell], and the `ldtk_enum!`
+//! macro for generating Rust enums from LDtk enum definitions.
 //! - `render`: Enables rendering via [bevy_ecs_tilemap]'s `render` feature. Disable it if you want
-//! to run in headless mode.
-//! - `atlas`: Enables the `atlas` feature of [bevy_ecs_tilemap]. This is required for WASM support
-//! and also for tile spacing to work on Tile and AutoTile layers.
+//! to run in headless mode, e.g. on a dedicated server.
+//! Without it, images are not loaded and no backgrounds or tile textures are created, but
+//! logical components like [EntityInstance], [IntGridCell], [GridCoords], and transforms are
+//! still spawned.
+//! - `atlas`: Enables the `atlas` feature of [bevy_ecs_tilemap]. This is required for tile spacing
+//! to work on Tile and AutoTile layers.
+//! Enable it when targeting `wasm32-unknown-unknown`, since WebGL2 doesn't support the texture
+//! arrays used otherwise.
+//! - `save`: Enables writing projects and levels back to disk as LDtk JSON, with runtime
+//! modifications from [LevelPatches] applied.
+//! - `camera`: Enables the [LdtkCameraPlugin], which frames orthographic cameras on the active
+//! level.
+//! - `avian`: Enables [LdtkAvianAppExt], which generates static [avian2d] colliders for IntGrid
+//! values and tile enum tags.
+//! - `scene`: Enables [level_to_dynamic_scene], which converts spawned levels into bevy
+//! `DynamicScene`s.
+//! - `test-utils`: Enables the [test_utils] module, with helpers for testing level spawning in a
+//! headless bevy `App`.
 //!
 //! The `derive`, `render`, and `internal_levels` features are enabled by default.
 //! Furthermore, one or both of `internal_levels` and `external_levels` must be enabled.
@@ -33,19 +49,37 @@
 //! [feature flags]: https://doc.rust-lang.org/cargo/reference/features.html#the-features-section
 //! [LdtkEntity]: app::LdtkEntity
 //! [LdtkIntCell]: app::LdtkEntity
+//! [LevelPatches]: prelude::LevelPatches
+//! [LdtkCameraPlugin]: camera::LdtkCameraPlugin
+//! [LdtkAvianAppExt]: avian::LdtkAvianAppExt
+//! [avian2d]: https://docs.rs/avian2d
+//! [level_to_dynamic_scene]: scene::level_to_dynamic_scene
+//! [EntityInstance]: prelude::EntityInstance
+//! [IntGridCell]: prelude::IntGridCell
+//! [GridCoords]: prelude::GridCoords
 //! [bevy_ecs_tilemap]: https://docs.rs/bevy_ecs_tilemap
 
 pub mod app;
 pub mod assets;
+#[cfg(feature = "avian")]
+pub mod avian;
+#[cfg(feature = "camera")]
+pub mod camera;
+mod commands;
 mod components;
 pub mod ldtk;
 mod level;
 mod plugin;
 mod resources;
+#[cfg(feature = "scene")]
+pub mod scene;
 pub mod systems;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 mod tile_makers;
 pub mod utils;
 
+pub use commands::*;
 pub use components::*;
 pub use plugin::*;
 pub use resources::*;
@@ -57,21 +91,35 @@ pub mod prelude {
     //! `use bevy_ecs_ldtk::prelude::*;` to import commonly used items.
 
     pub use crate::{
-        app::{LdtkEntity, LdtkEntityAppExt, LdtkIntCell, LdtkIntCellAppExt},
-        assets::{LdtkProject, LevelIndices, LevelMetadataAccessor},
+        app::{
+            LdtkEntity, LdtkEntityAppExt, LdtkEnumTagMarkerAppExt, LdtkIntCell, LdtkIntCellAppExt,
+        },
+        assets::{LdtkLevel, LdtkProject, LevelIndices, LevelMetadataAccessor},
+        commands::LdtkCommandsExt,
         components::LdtkSpriteSheetBundle,
         components::{
-            EntityIid, EntityInstance, GridCoords, IntGridCell, LayerMetadata, LdtkWorldBundle,
-            LevelIid, LevelSet, Respawn, TileEnumTags, TileMetadata, Worldly,
+            EntityIid, EntityInstance, EntityInstanceRef, GridCoords, IntGridCell, IntGridCsv,
+            LayerMetadata, LdtkLevelBundle, LdtkWorldBundle, LevelBounds, LevelExit, LevelIid,
+            LevelInfo, LevelOffsets, LevelProject, LevelSet, LevelTraveler, Respawn, SmartColor,
+            SpawnPoint, SpawnPointQuery, SpriteSheetAnimation, TileAnimation, TileCollisionShape,
+            TileEnumTags, TileMetadata, TransformFromFields, Worldly, WorldlyOf, YSort,
         },
         ldtk::{
-            self, ldtk_fields::LdtkFields, raw_level_accessor::RawLevelAccessor, FieldValue,
-            LayerInstance, TilesetDefinition,
+            self, entity_ref_graph::EntityRefGraph, int_grid_value_defs::IntGridValueDefs,
+            ldtk_fields::LdtkFields, minimap::Minimap, nav_grid::NavGrid,
+            raw_level_accessor::RawLevelAccessor, EntityDefinition, FieldValue, LayerInstance,
+            TilesetDefinition,
         },
         plugin::{LdtkPlugin, ProcessLdtkApi},
         resources::{
-            IntGridRendering, LdtkSettings, LevelBackground, LevelEvent, LevelSelection,
-            LevelSpawnBehavior, SetClearColor, SpawnExclusions,
+            EntityInstanceStorage, EntityRefGraphs, EntitySpawnOrder, EntityZField,
+            IntGridRendering, InvisibleLayers, LayerFilter, LayerTints, LayerZPolicy,
+            LdtkEntityIndex, LdtkLoadError, LdtkLoadErrorKind, LdtkLoadProgress, LdtkSettings,
+            LdtkSpawnError, LdtkSpawnErrorKind, LevelBackground, LevelEvent, LevelOrigin,
+            LevelPatch, LevelPatches, LevelSelection, LevelSpawnBehavior, LevelSpawnMode,
+            LevelTransition, SetClearColor, SimplifiedLevelRendering, SpawnExclusions,
+            SpawnPointEvent, StrictMode, TileAnimations, TilesetFilter, TilesetInfo, TilesetMap,
+            TilesetSampler, WorldDepth, YSorting,
         },
     };
 
@@ -80,4 +128,13 @@ pub mod prelude {
 
     #[cfg(feature = "external_levels")]
     pub use crate::assets::LdtkExternalLevel;
+
+    #[cfg(feature = "render")]
+    pub use crate::app::LdtkLayerMaterialAppExt;
+
+    #[cfg(feature = "camera")]
+    pub use crate::camera::{CameraFit, LdtkCamera, LdtkCameraPlugin};
+
+    #[cfg(feature = "avian")]
+    pub use crate::avian::{LdtkAvianAppExt, LdtkStaticCollider};
 }
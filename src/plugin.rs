@@ -1,5 +1,5 @@
 //! Provides [LdtkPlugin] and its scheduling-related dependencies.
-use crate::{app, assets, components, resources, systems};
+use crate::{app, assets, components, ldtk, resources, systems};
 use bevy::{
     app::MainScheduleOrder, ecs::schedule::ScheduleLabel, prelude::*, transform::TransformSystem,
 };
@@ -36,6 +36,22 @@ impl Plugin for LdtkPlugin {
             app = app.add_plugins(bevy_ecs_tilemap::TilemapPlugin);
         }
 
+        // Headless apps may not have the plugins that register these assets, but they are still
+        // used while spawning levels.
+        #[cfg(not(feature = "render"))]
+        {
+            if !app.world().contains_resource::<Assets<Image>>() {
+                app.init_asset::<Image>();
+            }
+
+            if !app
+                .world()
+                .contains_resource::<Assets<TextureAtlasLayout>>()
+            {
+                app.init_asset::<TextureAtlasLayout>();
+            }
+        }
+
         app.world_mut()
             .get_resource_mut::<MainScheduleOrder>()
             .expect("expected MainScheduleOrder to exist, try using DefaultPlugins")
@@ -48,16 +64,40 @@ impl Plugin for LdtkPlugin {
             )
             .init_non_send_resource::<app::LdtkEntityMap>()
             .init_non_send_resource::<app::LdtkIntCellMap>()
+            .init_resource::<app::LdtkEntityHooks>()
             .init_resource::<resources::LdtkSettings>()
+            .init_resource::<resources::LevelPatches>()
+            .init_resource::<resources::LdtkLoadProgress>()
+            .init_resource::<resources::LevelTransition>()
+            .init_resource::<resources::LevelSpawnGenerations>()
+            .init_resource::<resources::TilesetMap>()
+            .init_resource::<resources::EntityRefGraphs>()
+            .init_resource::<resources::LdtkEntityIndex>()
             .add_event::<resources::LevelEvent>()
+            .add_event::<resources::LdtkLoadError>()
+            .add_event::<resources::LdtkSpawnError>()
+            .add_event::<resources::SpawnPointEvent>()
             .add_systems(
                 PreUpdate,
-                (systems::process_ldtk_assets, systems::process_ldtk_levels),
+                (
+                    systems::process_ldtk_assets,
+                    (
+                        systems::forget_despawned_levels,
+                        systems::process_ldtk_levels,
+                    )
+                        .chain(),
+                    systems::update_ldtk_load_progress,
+                    systems::update_tileset_map.before(systems::process_ldtk_levels),
+                    systems::update_entity_ref_graphs,
+                    systems::fire_ldtk_load_errors,
+                ),
             )
             .add_systems(
                 ProcessLdtkApi,
-                (systems::apply_level_selection, systems::apply_level_set)
-                    .chain()
+                (
+                    (systems::apply_level_selection, systems::apply_level_set).chain(),
+                    systems::apply_ldtk_level_handles,
+                )
                     .in_set(ProcessApiSet::PreClean),
             )
             .add_systems(
@@ -69,16 +109,53 @@ impl Plugin for LdtkPlugin {
             .add_systems(
                 PostUpdate,
                 (
-                    systems::detect_level_spawned_events
-                        .pipe(systems::fire_level_transformed_events),
+                    (
+                        systems::detect_level_spawned_events
+                            .pipe(systems::fire_level_transformed_events),
+                        systems::fire_spawn_point_events.after(TransformSystem::TransformPropagate),
+                    )
+                        .chain(),
                     systems::worldly_adoption.after(TransformSystem::TransformPropagate),
+                    systems::apply_level_transition_spawn_points
+                        .before(TransformSystem::TransformPropagate),
+                    systems::insert_tile_animations,
+                    systems::insert_tile_collision_shapes,
+                    systems::animate_sprite_sheets,
+                    systems::apply_transform_from_fields
+                        .before(TransformSystem::TransformPropagate),
+                    systems::y_sort
+                        .after(systems::apply_transform_from_fields)
+                        .before(TransformSystem::TransformPropagate),
                 ),
             )
+            .observe(systems::despawn_tilemap_tiles)
+            .observe(systems::index_ldtk_entity)
+            .observe(systems::unindex_ldtk_entity)
+            .register_type::<ldtk::EntityInstance>()
+            .register_type::<components::IntGridCell>()
+            .register_type::<components::Worldly>()
+            .register_type::<components::WorldlyOf>()
+            .register_type::<components::SmartColor>()
+            .register_type::<components::Respawn>()
+            .register_type::<components::LevelSet>()
+            .register_type::<components::LevelOffsets>()
+            .register_type::<components::LevelExit>()
             .register_type::<components::LevelIid>()
+            .register_type::<components::LevelBounds>()
+            .register_type::<components::LevelInfo>()
+            .register_type::<components::LevelProject>()
             .register_type::<components::EntityIid>()
+            .register_type::<components::EntityInstanceRef>()
             .register_type::<components::GridCoords>()
             .register_type::<components::TileMetadata>()
             .register_type::<components::TileEnumTags>()
-            .register_type::<components::LayerMetadata>();
+            .register_type::<components::TileCollisionShape>()
+            .register_type::<components::SpriteSheetAnimation>()
+            .register_type::<components::TransformFromFields>()
+            .register_type::<components::YSort>()
+            .register_type::<components::SpawnPoint>()
+            .register_type::<components::LevelTraveler>()
+            .register_type::<components::LayerMetadata>()
+            .register_type::<components::IntGridCsv>();
     }
 }
@@ -0,0 +1,140 @@
+use crate::components::EntityIid;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// [Resource] mapping the iids of spawned LDtk entities to their [Entity]s.
+///
+/// Updated as soon as an [EntityIid] is inserted, replaced or removed, so specific placed instances
+/// can be addressed by their stable LDtk iid without scanning queries, e.g. by quests, save systems
+/// or scripted events.
+///
+/// Several spawned entities may share an iid, like when the same level is spawned twice.
+/// All of them are indexed, and [LdtkEntityIndex::get] returns the most recently indexed one.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// # #[derive(Component)]
+/// # struct Open;
+/// fn open_quest_door(mut commands: Commands, entity_index: Res<LdtkEntityIndex>) {
+///     if let Some(door) = entity_index.get("a5d4f1e0-66b0-11ec-9cd7-45bd2e4d4a4e") {
+///         commands.entity(door).insert(Open);
+///     }
+/// }
+/// ```
+///
+/// [Resource]: https://docs.rs/bevy/latest/bevy/ecs/system/trait.Resource.html
+#[derive(Clone, Debug, Default, Resource)]
+pub struct LdtkEntityIndex {
+    entities: HashMap<EntityIid, Vec<Entity>>,
+    iids: HashMap<Entity, EntityIid>,
+}
+
+impl LdtkEntityIndex {
+    /// Returns the most recently indexed entity with the given iid.
+    pub fn get(&self, iid: impl Into<EntityIid>) -> Option<Entity> {
+        self.get_all(iid).last().copied()
+    }
+
+    /// Returns all spawned entities with the given iid, from least to most recently indexed.
+    pub fn get_all(&self, iid: impl Into<EntityIid>) -> &[Entity] {
+        self.entities.get(&iid.into()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns true if an entity with the given iid is spawned.
+    pub fn contains(&self, iid: impl Into<EntityIid>) -> bool {
+        self.entities.contains_key(&iid.into())
+    }
+
+    /// Iterates over the iids of all spawned LDtk entities along with their [Entity]s.
+    ///
+    /// Iids shared by several entities are listed once for each of them.
+    pub fn iter(&self) -> impl Iterator<Item = (&EntityIid, Entity)> {
+        self.entities
+            .iter()
+            .flat_map(|(iid, entities)| entities.iter().map(move |entity| (iid, *entity)))
+    }
+
+    /// Number of indexed iids.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns true if no entities are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Indexes the entity under the given iid, removing it from the iid it was indexed under
+    /// before, if any.
+    pub(crate) fn insert(&mut self, iid: EntityIid, entity: Entity) {
+        if self.iids.get(&entity) == Some(&iid) {
+            return;
+        }
+
+        self.remove(entity);
+
+        self.entities.entry(iid.clone()).or_default().push(entity);
+        self.iids.insert(entity, iid);
+    }
+
+    /// Removes the given entity from the index.
+    pub(crate) fn remove(&mut self, entity: Entity) {
+        let Some(iid) = self.iids.remove(&entity) else {
+            return;
+        };
+
+        if let Some(entities) = self.entities.get_mut(&iid) {
+            entities.retain(|indexed| *indexed != entity);
+
+            if entities.is_empty() {
+                self.entities.remove(&iid);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{index_ldtk_entity, unindex_ldtk_entity};
+
+    #[test]
+    fn index_follows_spawned_entities() {
+        let mut world = World::new();
+        world.init_resource::<LdtkEntityIndex>();
+        world.observe(index_ldtk_entity);
+        world.observe(unindex_ldtk_entity);
+        world.flush();
+
+        let chest = world.spawn(EntityIid::new("chest")).id();
+        let old_door = world.spawn(EntityIid::new("door")).id();
+        let new_door = world.spawn(EntityIid::new("door")).id();
+        world.flush();
+
+        let entity_index = world.resource::<LdtkEntityIndex>();
+        assert_eq!(entity_index.len(), 2);
+        assert_eq!(entity_index.get("chest"), Some(chest));
+        assert_eq!(entity_index.get("door"), Some(new_door));
+        assert_eq!(entity_index.get_all("door"), &[old_door, new_door]);
+
+        world.despawn(new_door);
+        world.flush();
+        assert_eq!(
+            world.resource::<LdtkEntityIndex>().get("door"),
+            Some(old_door)
+        );
+
+        // Replacing the iid moves the entity in the index
+        world.entity_mut(chest).insert(EntityIid::new("door"));
+        world.flush();
+        let entity_index = world.resource::<LdtkEntityIndex>();
+        assert!(!entity_index.contains("chest"));
+        assert_eq!(entity_index.get_all("door"), &[old_door, chest]);
+
+        world.entity_mut(chest).remove::<EntityIid>();
+        world.despawn(old_door);
+        world.flush();
+        assert!(world.resource::<LdtkEntityIndex>().is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{assets::LdtkProject, ldtk::entity_ref_graph::EntityRefGraph};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// [Resource] storing the [EntityRefGraph] of every loaded [LdtkProject], by asset id.
+///
+/// Graphs are built when projects finish loading, without spawning any levels, and rebuilt when
+/// they are modified.
+/// For projects with external levels, the graph covers the external levels loaded along with the
+/// project.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::prelude::*;
+/// fn list_fast_travel_destinations(
+///     projects: Query<&Handle<LdtkProject>>,
+///     entity_ref_graphs: Res<EntityRefGraphs>,
+///     level_selection: Res<LevelSelection>,
+/// ) {
+///     let LevelSelection::Iid(level_iid) = level_selection.as_ref() else {
+///         return;
+///     };
+///
+///     for project in &projects {
+///         if let Some(graph) = entity_ref_graphs.get(project) {
+///             for destination in graph.connected_levels(level_iid.as_str()) {
+///                 info!("can travel to {destination}");
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// [Resource]: https://docs.rs/bevy/latest/bevy/ecs/system/trait.Resource.html
+#[derive(Clone, Debug, Default, Resource)]
+pub struct EntityRefGraphs {
+    projects: HashMap<AssetId<LdtkProject>, EntityRefGraph>,
+}
+
+impl EntityRefGraphs {
+    /// Returns the [EntityRefGraph] of the given project.
+    pub fn get(&self, project: impl Into<AssetId<LdtkProject>>) -> Option<&EntityRefGraph> {
+        self.projects.get(&project.into())
+    }
+
+    pub(crate) fn insert(&mut self, project_id: AssetId<LdtkProject>, graph: EntityRefGraph) {
+        self.projects.insert(project_id, graph);
+    }
+
+    pub(crate) fn remove(&mut self, project_id: AssetId<LdtkProject>) {
+        self.projects.remove(&project_id);
+    }
+}
@@ -26,3 +26,19 @@ pub enum LevelEvent {
     /// Indicates that a level has despawned.
     Despawned(LevelIid),
 }
+
+/// Event fired when a level with a [`SpawnPoint`] has spawned and its transforms have been
+/// propagated, with the spawn point's world translation.
+///
+/// Useful for positioning the player after switching levels.
+///
+/// [`SpawnPoint`]: crate::components::SpawnPoint
+#[derive(Clone, PartialEq, Debug, Event)]
+pub struct SpawnPointEvent {
+    /// Iid of the level that spawned.
+    pub level_iid: LevelIid,
+    /// The spawn point entity.
+    pub entity: Entity,
+    /// World translation of the spawn point.
+    pub translation: Vec2,
+}
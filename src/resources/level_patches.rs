@@ -0,0 +1,282 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    components::{GridCoords, LevelIid},
+    ldtk::{LayerInstance, Level},
+    utils::grid_coords_to_ldtk_grid_coords,
+};
+
+#[allow(unused_imports)]
+use crate::commands::LdtkCommandsExt;
+
+/// A single IntGrid cell change stored in a [`LevelPatch`].
+#[derive(Clone, Eq, PartialEq, Debug, Default, Hash, Serialize, Deserialize)]
+pub struct IntGridCellPatch {
+    /// Identifier of the IntGrid layer containing the cell.
+    pub layer_identifier: String,
+    /// Position of the cell in the layer.
+    pub grid_coords: GridCoords,
+    /// New IntGrid value of the cell, where `0` means "empty cell".
+    pub value: i32,
+}
+
+/// Runtime modifications made to a single level.
+///
+/// Patches are stored in the [`LevelPatches`] resource, and are re-applied to the level data
+/// whenever the level spawns, so modifications survive respawning and revisiting levels.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct LevelPatch {
+    /// Changed IntGrid cells.
+    pub int_grid_cells: Vec<IntGridCellPatch>,
+    /// `iid`s of LDtk entities that have been removed.
+    pub removed_entities: HashSet<String>,
+}
+
+impl LevelPatch {
+    /// Record a change to an IntGrid cell, replacing any previous change to the same cell.
+    pub fn set_int_grid_cell(
+        &mut self,
+        layer_identifier: impl Into<String>,
+        grid_coords: GridCoords,
+        value: i32,
+    ) {
+        let layer_identifier = layer_identifier.into();
+
+        match self.int_grid_cells.iter_mut().find(|cell| {
+            cell.layer_identifier == layer_identifier && cell.grid_coords == grid_coords
+        }) {
+            Some(cell) => cell.value = value,
+            None => self.int_grid_cells.push(IntGridCellPatch {
+                layer_identifier,
+                grid_coords,
+                value,
+            }),
+        }
+    }
+
+    /// Record the removal of the LDtk entity with the given `iid`.
+    pub fn remove_entity(&mut self, entity_iid: impl Into<String>) {
+        self.removed_entities.insert(entity_iid.into());
+    }
+
+    /// Returns true if the LDtk entity with the given `iid` has been removed.
+    pub fn is_entity_removed(&self, entity_iid: &str) -> bool {
+        self.removed_entities.contains(entity_iid)
+    }
+
+    /// Returns true if this patch has no modifications.
+    pub fn is_empty(&self) -> bool {
+        self.int_grid_cells.is_empty() && self.removed_entities.is_empty()
+    }
+
+    /// Apply this patch to the given layer data.
+    ///
+    /// The layer is only cloned if this patch modifies it.
+    pub fn apply_to_layer_instance<'a>(
+        &self,
+        layer_instance: &'a LayerInstance,
+    ) -> Cow<'a, LayerInstance> {
+        let cells = self
+            .int_grid_cells
+            .iter()
+            .filter(|cell| cell.layer_identifier == layer_instance.identifier)
+            .collect::<Vec<_>>();
+
+        let removes_entities = layer_instance
+            .entity_instances
+            .iter()
+            .any(|entity_instance| self.is_entity_removed(&entity_instance.iid));
+
+        if cells.is_empty() && !removes_entities {
+            return Cow::Borrowed(layer_instance);
+        }
+
+        let mut layer_instance = layer_instance.clone();
+
+        for IntGridCellPatch {
+            grid_coords, value, ..
+        } in cells
+        {
+            if grid_coords.x < 0
+                || grid_coords.y < 0
+                || grid_coords.x >= layer_instance.c_wid
+                || grid_coords.y >= layer_instance.c_hei
+            {
+                continue;
+            }
+
+            let ldtk_grid_coords =
+                grid_coords_to_ldtk_grid_coords(*grid_coords, layer_instance.c_hei);
+            let index = (ldtk_grid_coords.y * layer_instance.c_wid + ldtk_grid_coords.x) as usize;
+
+            if let Some(cell) = layer_instance.int_grid_csv.get_mut(index) {
+                *cell = *value;
+            }
+        }
+
+        layer_instance
+            .entity_instances
+            .retain(|entity_instance| !self.is_entity_removed(&entity_instance.iid));
+
+        Cow::Owned(layer_instance)
+    }
+
+    /// Apply this patch to the layer data of the given level in place.
+    ///
+    /// Does nothing to levels whose layer instances are null.
+    pub fn apply_to_level(&self, level: &mut Level) {
+        for layer_instance in level.layer_instances.iter_mut().flatten() {
+            if let Cow::Owned(patched) = self.apply_to_layer_instance(layer_instance) {
+                *layer_instance = patched;
+            }
+        }
+    }
+}
+
+/// [`Resource`] storing [`LevelPatch`]es by level `iid`.
+///
+/// Modifications made via [`LdtkCommandsExt`] are recorded here automatically.
+/// Whenever a level spawns, its patch is applied to the level data before any layers are spawned.
+///
+/// This resource implements [`Serialize`] and [`Deserialize`], so it can be stored in save files
+/// and re-inserted later.
+///
+/// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
+#[derive(Clone, Eq, PartialEq, Debug, Default, Resource, Serialize, Deserialize)]
+pub struct LevelPatches {
+    patches: HashMap<String, LevelPatch>,
+}
+
+impl LevelPatches {
+    /// Immutable access to the patch for the given level, if it has one.
+    pub fn get(&self, level_iid: &LevelIid) -> Option<&LevelPatch> {
+        self.patches.get(level_iid.as_str())
+    }
+
+    /// Mutable access to the patch for the given level, creating an empty one if necessary.
+    pub fn get_or_default(&mut self, level_iid: &LevelIid) -> &mut LevelPatch {
+        self.patches.entry(level_iid.to_string()).or_default()
+    }
+
+    /// Remove the patch for the given level, so it will spawn unmodified in the future.
+    pub fn remove(&mut self, level_iid: &LevelIid) -> Option<LevelPatch> {
+        self.patches.remove(level_iid.as_str())
+    }
+
+    /// Remove all patches.
+    pub fn clear(&mut self) {
+        self.patches.clear();
+    }
+
+    /// Iterate through all patches along with their level `iid`s.
+    pub fn iter(&self) -> impl Iterator<Item = (LevelIid, &LevelPatch)> {
+        self.patches
+            .iter()
+            .map(|(iid, patch)| (LevelIid::new(iid.clone()), patch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::EntityInstance;
+
+    #[test]
+    fn setting_the_same_cell_replaces_the_previous_change() {
+        let mut patch = LevelPatch::default();
+
+        patch.set_int_grid_cell("Walls", GridCoords::new(1, 2), 3);
+        patch.set_int_grid_cell("Walls", GridCoords::new(1, 2), 0);
+        patch.set_int_grid_cell("Water", GridCoords::new(1, 2), 1);
+
+        assert_eq!(
+            patch.int_grid_cells,
+            vec![
+                IntGridCellPatch {
+                    layer_identifier: "Walls".to_string(),
+                    grid_coords: GridCoords::new(1, 2),
+                    value: 0,
+                },
+                IntGridCellPatch {
+                    layer_identifier: "Water".to_string(),
+                    grid_coords: GridCoords::new(1, 2),
+                    value: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unaffected_layers_are_borrowed() {
+        let mut patch = LevelPatch::default();
+        patch.set_int_grid_cell("Walls", GridCoords::new(0, 0), 1);
+        patch.remove_entity("removed-iid");
+
+        let layer_instance = LayerInstance {
+            identifier: "Water".to_string(),
+            c_wid: 1,
+            c_hei: 1,
+            int_grid_csv: vec![0],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            patch.apply_to_layer_instance(&layer_instance),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn patch_modifies_int_grid_csv_and_removes_entities() {
+        let mut patch = LevelPatch::default();
+        patch.set_int_grid_cell("Walls", GridCoords::new(0, 1), 5);
+        patch.set_int_grid_cell("Walls", GridCoords::new(2, 0), 6);
+        patch.set_int_grid_cell("Walls", GridCoords::new(3, 0), 7);
+        patch.remove_entity("removed-iid");
+
+        let layer_instance = LayerInstance {
+            identifier: "Walls".to_string(),
+            c_wid: 3,
+            c_hei: 2,
+            int_grid_csv: vec![1, 1, 1, 1, 1, 1],
+            entity_instances: vec![
+                EntityInstance {
+                    iid: "removed-iid".to_string(),
+                    ..Default::default()
+                },
+                EntityInstance {
+                    iid: "kept-iid".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let patched = patch.apply_to_layer_instance(&layer_instance);
+
+        assert_eq!(patched.int_grid_csv, vec![5, 1, 1, 1, 1, 6]);
+        assert_eq!(patched.entity_instances.len(), 1);
+        assert_eq!(patched.entity_instances[0].iid, "kept-iid");
+    }
+
+    #[test]
+    fn level_patches_round_trip_through_json() {
+        let mut level_patches = LevelPatches::default();
+
+        let patch = level_patches.get_or_default(&LevelIid::new("level-iid"));
+        patch.set_int_grid_cell("Walls", GridCoords::new(1, 1), 2);
+        patch.remove_entity("entity-iid");
+
+        let json = serde_json::to_string(&level_patches).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<LevelPatches>(&json).unwrap(),
+            level_patches
+        );
+    }
+}
@@ -3,12 +3,19 @@ use bevy::prelude::*;
 
 /// [`Resource`] for choosing which level(s) to spawn.
 ///
+/// It can also be inserted as a [`Component`] on individual [`LdtkWorldBundle`] entities, giving
+/// them their own level selection independent of the resource.
+/// This is useful when multiple projects are loaded at once.
+/// Worlds without the component follow the resource.
+///
 /// For more explanation and comparison of options for selecting levels to spawn, see the
 /// [*Level Selection*](https://trouv.github.io/bevy_ecs_ldtk/v0.10.0/explanation/level-selection.html) <!-- x-release-please-version -->
 /// chapter of the `bevy_ecs_ldtk` book.
 ///
 /// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
-#[derive(Clone, Eq, PartialEq, Debug, Resource)]
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Component.html
+/// [`LdtkWorldBundle`]: crate::prelude::LdtkWorldBundle
+#[derive(Clone, Eq, PartialEq, Debug, Resource, Component)]
 pub enum LevelSelection {
     /// Spawn level with the given identifier.
     Identifier(String),
@@ -0,0 +1,112 @@
+use crate::components::LevelIid;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[allow(unused_imports)]
+use crate::components::Respawn;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct LevelGeneration {
+    level_iid: LevelIid,
+    generation: u32,
+    spawned: bool,
+}
+
+/// [`Resource`] keeping track of which level entities have been spawned, so that repeated spawn
+/// requests for the same level coalesce rather than spawning its contents twice.
+///
+/// Every level entity has a generation, keyed by its [`LevelIid`].
+/// The generation is incremented when the level is respawned (see [`Respawn`]), or when the
+/// entity's [`LevelIid`] changes.
+/// A level's contents are only spawned once per generation, even if the spawn is requested several
+/// times before it completes, e.g. by a level selection change and a hot-reload in the same frame.
+///
+/// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
+#[derive(Clone, Eq, PartialEq, Debug, Default, Resource)]
+pub struct LevelSpawnGenerations {
+    generations: HashMap<Entity, LevelGeneration>,
+}
+
+impl LevelSpawnGenerations {
+    /// Returns the current generation of the given level entity, if it has been spawned.
+    pub fn generation(&self, level_entity: Entity) -> Option<u32> {
+        self.generations
+            .get(&level_entity)
+            .map(|level_generation| level_generation.generation)
+    }
+
+    /// Returns true if the given level entity has already been spawned for its current generation.
+    pub fn is_spawned(&self, level_entity: Entity, level_iid: &LevelIid) -> bool {
+        self.generations
+            .get(&level_entity)
+            .is_some_and(|level_generation| {
+                level_generation.spawned && level_generation.level_iid == *level_iid
+            })
+    }
+
+    /// Records that the given level entity has been spawned for its current generation.
+    pub(crate) fn mark_spawned(&mut self, level_entity: Entity, level_iid: &LevelIid) {
+        let level_generation =
+            self.generations
+                .entry(level_entity)
+                .or_insert_with(|| LevelGeneration {
+                    level_iid: level_iid.clone(),
+                    generation: 0,
+                    spawned: false,
+                });
+
+        if level_generation.level_iid != *level_iid {
+            level_generation.level_iid = level_iid.clone();
+            level_generation.generation += 1;
+        }
+
+        level_generation.spawned = true;
+    }
+
+    /// Starts a new generation for the given level entity, so that it will be spawned again.
+    pub(crate) fn respawn(&mut self, level_entity: Entity) {
+        if let Some(level_generation) = self.generations.get_mut(&level_entity) {
+            level_generation.generation += 1;
+            level_generation.spawned = false;
+        }
+    }
+
+    /// Forgets the given level entity, e.g. after it has been despawned.
+    pub(crate) fn remove(&mut self, level_entity: Entity) {
+        self.generations.remove(&level_entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_spawned_once_per_generation() {
+        let mut generations = LevelSpawnGenerations::default();
+        let level_entity = Entity::from_raw(1);
+        let level_iid = LevelIid::new("level-iid");
+
+        assert!(!generations.is_spawned(level_entity, &level_iid));
+        assert_eq!(generations.generation(level_entity), None);
+
+        generations.mark_spawned(level_entity, &level_iid);
+        assert!(generations.is_spawned(level_entity, &level_iid));
+        assert_eq!(generations.generation(level_entity), Some(0));
+
+        generations.respawn(level_entity);
+        assert!(!generations.is_spawned(level_entity, &level_iid));
+
+        generations.mark_spawned(level_entity, &level_iid);
+        assert!(generations.is_spawned(level_entity, &level_iid));
+        assert_eq!(generations.generation(level_entity), Some(1));
+
+        let other_iid = LevelIid::new("other-iid");
+        assert!(!generations.is_spawned(level_entity, &other_iid));
+        generations.mark_spawned(level_entity, &other_iid);
+        assert_eq!(generations.generation(level_entity), Some(2));
+
+        generations.remove(level_entity);
+        assert_eq!(generations.generation(level_entity), None);
+    }
+}
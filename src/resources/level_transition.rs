@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::components::{EntityIid, LevelIid};
+
+#[allow(unused_imports)]
+use crate::{
+    commands::LdtkCommandsExt,
+    components::{LevelTraveler, SpawnPoint},
+};
+
+/// [`Resource`] storing the state of a level transition started with
+/// [`LdtkCommandsExt::transition_to_level`].
+///
+/// The transition is pending until its spawn point has spawned and [`LevelTraveler`] entities
+/// have been moved to it.
+///
+/// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
+#[derive(Clone, Eq, PartialEq, Debug, Default, Resource)]
+pub struct LevelTransition {
+    /// Level being transitioned to.
+    pub pending_level: Option<LevelIid>,
+    /// Spawn point that [`LevelTraveler`] entities will be moved to once it has spawned.
+    ///
+    /// If `None`, the [`SpawnPoint`] of the pending level is used.
+    pub pending_spawn_point: Option<EntityIid>,
+}
@@ -0,0 +1,44 @@
+use bevy::{asset::AssetPath, prelude::*};
+
+use crate::{assets::LdtkProject, components::LevelIid};
+
+/// The kind of asset that failed to load in an [`LdtkLoadError`], and what it affects.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LdtkLoadErrorKind {
+    /// The LDtk project itself failed to load.
+    Project,
+    /// A tileset image failed to load.
+    Tileset {
+        /// Identifier of the tileset.
+        identifier: String,
+        /// Identifiers of the layers using the tileset, which will be spawned without textures.
+        layer_identifiers: Vec<String>,
+    },
+    /// The background image of a level failed to load.
+    LevelBackground {
+        /// Iid of the level.
+        level_iid: LevelIid,
+    },
+    /// The file of an external level failed to load.
+    ExternalLevel {
+        /// Iid of the level, which won't be spawned.
+        level_iid: LevelIid,
+    },
+}
+
+/// Event fired when an LDtk project, or an asset it depends on, fails to load.
+///
+/// Without these, failures like a tileset with a bad `relPath` only produce a warning in the logs,
+/// and the affected layers spawn without textures.
+/// Listen for this event to show actionable errors, or to assert on them in tests.
+#[derive(Clone, Eq, PartialEq, Debug, Event)]
+pub struct LdtkLoadError {
+    /// The project the failed asset belongs to.
+    pub project: AssetId<LdtkProject>,
+    /// Path of the asset that failed to load.
+    pub path: AssetPath<'static>,
+    /// What the asset is used for in the project.
+    pub kind: LdtkLoadErrorKind,
+    /// Description of the failure.
+    pub error: String,
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+/// [`Resource`] reporting the loading progress of LDtk projects and their dependencies.
+///
+/// Counts every [`LdtkProject`] that is loaded or referenced by an entity, along with the
+/// assets each loaded project depends on (see [`LdtkProject::dependency_ids`]).
+/// Since dependencies are only known once their project has loaded, `total` may grow while
+/// loading.
+///
+/// Assets that the [`AssetServer`] is not tracking, like those added to [`Assets`] directly, are
+/// counted as `untracked` rather than `loaded`, since their load state is unknown.
+///
+/// Updated every frame in the [`PreUpdate`] schedule.
+///
+/// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
+/// [`LdtkProject`]: crate::assets::LdtkProject
+/// [`LdtkProject::dependency_ids`]: crate::assets::LdtkProject::dependency_ids
+/// [`PreUpdate`]: https://docs.rs/bevy/latest/bevy/app/struct.PreUpdate.html
+/// [`AssetServer`]: https://docs.rs/bevy/latest/bevy/asset/struct.AssetServer.html
+/// [`Assets`]: https://docs.rs/bevy/latest/bevy/asset/struct.Assets.html
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Resource)]
+pub struct LdtkLoadProgress {
+    /// Number of assets that have finished loading.
+    pub loaded: usize,
+    /// Number of assets that failed to load.
+    pub failed: usize,
+    /// Number of assets not tracked by the asset server, which will never load or fail.
+    pub untracked: usize,
+    /// Total number of assets.
+    pub total: usize,
+}
+
+impl LdtkLoadProgress {
+    /// Returns true if every asset has either loaded, failed to load, or is untracked.
+    ///
+    /// Also true if there is nothing to load.
+    pub fn is_finished(&self) -> bool {
+        self.settled() >= self.total
+    }
+
+    /// Fraction of assets that have finished loading, failed, or are untracked, from `0.` to `1.`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.
+        } else {
+            self.settled() as f32 / self.total as f32
+        }
+    }
+
+    fn settled(&self) -> usize {
+        self.loaded + self.failed + self.untracked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_fraction_and_completion() {
+        let nothing_to_load = LdtkLoadProgress::default();
+        assert!(nothing_to_load.is_finished());
+        assert_eq!(nothing_to_load.fraction(), 1.);
+
+        let loading = LdtkLoadProgress {
+            loaded: 2,
+            failed: 1,
+            total: 4,
+            ..default()
+        };
+        assert!(!loading.is_finished());
+        assert_eq!(loading.fraction(), 0.75);
+
+        let finished = LdtkLoadProgress {
+            loaded: 3,
+            failed: 1,
+            total: 4,
+            ..default()
+        };
+        assert!(finished.is_finished());
+
+        let with_untracked = LdtkLoadProgress {
+            loaded: 2,
+            failed: 0,
+            untracked: 2,
+            total: 4,
+        };
+        assert!(with_untracked.is_finished());
+        assert_eq!(with_untracked.fraction(), 1.);
+    }
+}
@@ -1,5 +1,11 @@
 //! Resources and events used by the plugin.
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    render::texture::{ImageFilterMode, ImageSampler},
+};
+use std::{collections::HashMap, mem::discriminant, sync::Arc};
+
+use crate::ldtk::{ldtk_fields::LdtkFields, EntityInstance, LayerDefinition, LayerInstance};
 
 #[allow(unused_imports)]
 use crate::assets::LdtkProject;
@@ -10,7 +16,34 @@ mod level_selection;
 pub use level_selection::LevelSelection;
 
 mod level_event;
-pub use level_event::LevelEvent;
+pub use level_event::{LevelEvent, SpawnPointEvent};
+
+mod load_progress;
+pub use load_progress::LdtkLoadProgress;
+
+mod load_error;
+pub use load_error::{LdtkLoadError, LdtkLoadErrorKind};
+
+mod spawn_error;
+pub use spawn_error::{LdtkSpawnError, LdtkSpawnErrorKind};
+
+mod level_patches;
+pub use level_patches::{IntGridCellPatch, LevelPatch, LevelPatches};
+
+mod level_transition;
+pub use level_transition::LevelTransition;
+
+mod level_spawn_generations;
+pub use level_spawn_generations::LevelSpawnGenerations;
+
+mod entity_index;
+pub use entity_index::LdtkEntityIndex;
+
+mod entity_ref_graphs;
+pub use entity_ref_graphs::EntityRefGraphs;
+
+mod tileset_map;
+pub use tileset_map::{TilesetInfo, TilesetMap};
 
 /// Option in [LdtkSettings] that determines clear color behavior.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -48,6 +81,143 @@ pub enum LevelSpawnBehavior {
     },
 }
 
+/// Option in [LdtkSettings] that determines which corner of a level its entity is placed at.
+///
+/// LDtk uses a y-down coordinate system with levels anchored at their top-left corner, while
+/// Bevy is y-up.
+/// By default, the level entity sits at the level's bottom-left corner, so everything in the
+/// level has a positive translation relative to it.
+/// With [LevelOrigin::TopLeft], the level entity sits at the top-left corner like in LDtk, and the
+/// level's contents extend below it.
+///
+/// Either way, levels spawned with [LevelSpawnBehavior::UseWorldTranslation] occupy the same area
+/// of the world.
+/// Only translations relative to the level entity differ, see [LevelOrigin::ldtk_pixel_height]
+/// for using the coordinate utilities with either origin.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Reflect)]
+#[reflect(Default, Debug)]
+pub enum LevelOrigin {
+    /// The level entity is placed at the level's bottom-left corner.
+    #[default]
+    BottomLeft,
+    /// The level entity is placed at the level's top-left corner.
+    TopLeft,
+}
+
+impl LevelOrigin {
+    /// Returns the translation of the level's bottom-left corner relative to the level entity, for
+    /// a level with the given pixel height.
+    pub fn bottom_left_offset(&self, level_px_hei: i32) -> Vec2 {
+        match self {
+            LevelOrigin::BottomLeft => Vec2::ZERO,
+            LevelOrigin::TopLeft => Vec2::new(0., -level_px_hei as f32),
+        }
+    }
+
+    /// Returns the height to pass to coordinate utilities like
+    /// [ldtk_pixel_coords_to_translation] so that translations are relative to the level entity,
+    /// for a level with the given pixel height.
+    ///
+    /// [ldtk_pixel_coords_to_translation]: crate::utils::ldtk_pixel_coords_to_translation
+    pub fn ldtk_pixel_height(&self, level_px_hei: i32) -> i32 {
+        match self {
+            LevelOrigin::BottomLeft => level_px_hei,
+            LevelOrigin::TopLeft => 0,
+        }
+    }
+
+    /// Returns the area of a level with the given size relative to the level entity.
+    pub fn local_rect(&self, size: Vec2) -> Rect {
+        let min = self.bottom_left_offset(size.y as i32);
+        Rect::from_corners(min, min + size)
+    }
+}
+
+/// Option in [LdtkSettings] that determines how the `worldDepth` of levels affects their
+/// translation.
+///
+/// LDtk projects can stack levels at different depths in the same world, e.g. for floors of a
+/// building or interiors overlapping exteriors.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum WorldDepth {
+    /// Levels are spawned at the same z regardless of their depth.
+    #[default]
+    Ignored,
+    /// Levels are offset by their depth multiplied by the given translation.
+    ///
+    /// For example, `WorldDepth::Offset(Vec3::new(0., 0., 100.))` renders deeper levels above
+    /// shallower ones.
+    Offset(Vec3),
+}
+
+impl WorldDepth {
+    /// Returns the translation offset of a level with the given `worldDepth`.
+    pub fn offset(&self, world_depth: i32) -> Vec3 {
+        match self {
+            WorldDepth::Ignored => Vec3::ZERO,
+            WorldDepth::Offset(offset) => *offset * world_depth as f32,
+        }
+    }
+}
+
+/// Option in [LdtkSettings] that determines how entities without an [`LdtkEntity`] registration
+/// keep their LDtk data.
+///
+/// This also applies to entities with a pooled registration, since pooled bundles can't carry
+/// per-instance data, and to entities spawned with [`LdtkCommandsExt::spawn_ldtk_entity`].
+///
+/// [`LdtkEntity`]: crate::app::LdtkEntity
+/// [`LdtkCommandsExt::spawn_ldtk_entity`]: crate::prelude::LdtkCommandsExt::spawn_ldtk_entity
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum EntityInstanceStorage {
+    /// Entities get a clone of their [`EntityInstance`], including all of its field instances.
+    #[default]
+    Clone,
+    /// Entities get an [`EntityInstanceRef`] instead, which looks the [`EntityInstance`] up in the
+    /// project data when needed.
+    ///
+    /// Saves memory in levels with many entities, at the cost of a lookup whenever the entity's
+    /// data is read.
+    /// Entities spawned with [`LdtkCommandsExt::spawn_ldtk_entity`] don't belong to any level, so
+    /// their references don't resolve.
+    ///
+    /// [`EntityInstanceRef`]: crate::components::EntityInstanceRef
+    Reference,
+}
+
+/// Option in [LdtkSettings] that determines how much of a level is spawned.
+///
+/// It can also be inserted as a [`Component`] on a world or level entity, overriding the setting
+/// for the levels it spawns.
+/// A level's own component takes precedence over its world's.
+/// Changing it only affects levels spawned afterwards, so insert [`Respawn`] to apply it to a
+/// level that is already spawned.
+///
+/// [`Component`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Component.html
+/// [`Respawn`]: crate::components::Respawn
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Component)]
+pub enum LevelSpawnMode {
+    /// Levels are spawned completely, with their background, tiles, and entities.
+    #[default]
+    Full,
+    /// Only the level's metadata and entity instances are spawned, without any visuals.
+    ///
+    /// The level entity gets its [`LevelInfo`] and [`LevelBounds`] as usual, but no background
+    /// or tile layers are spawned.
+    /// Entity layers are spawned with every entity as a plain [`EntityInstanceBundle`], ignoring
+    /// [`LdtkEntity`] registrations so that no sprites are created.
+    /// Entity hooks still run.
+    ///
+    /// Useful for save-slot previews, counting enemies, or validating levels on a server, where
+    /// spawning the full level would be wasteful.
+    ///
+    /// [`LevelInfo`]: crate::components::LevelInfo
+    /// [`LevelBounds`]: crate::components::LevelBounds
+    /// [`EntityInstanceBundle`]: crate::components::EntityInstanceBundle
+    /// [`LdtkEntity`]: crate::app::LdtkEntity
+    DataOnly,
+}
+
 /// Option in [LdtkSettings] that determines the visual representation of IntGrid layers when they don't have AutoTile rules.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
 pub enum IntGridRendering {
@@ -69,21 +239,701 @@ pub enum LevelBackground {
     Nonexistent,
 }
 
+/// Option in [LdtkSettings] that determines which levels are rendered with the composite image
+/// from LDtk's "super simple export".
+///
+/// When a project has super simple export enabled, LDtk saves a `_composite.png` of every level,
+/// in `<project name>/simplified/<level identifier>/` next to the project file.
+/// Rendering a level as this single sprite instead of as tilemaps is much cheaper for static,
+/// background-only levels.
+///
+/// For levels rendered this way, the composite image replaces the level background, and Tile and
+/// AutoLayer layers are not spawned at all.
+/// IntGrid layers are still spawned so their [`IntGridCell`]s can be used for gameplay, but they
+/// are hidden.
+/// Entity layers are spawned as usual.
+///
+/// These levels wait for their composite image to load before spawning.
+/// If it fails to load, the level is spawned with its tile layers instead, and the failure is
+/// reported as an [`LdtkSpawnErrorKind::MissingCompositeImage`].
+///
+/// Levels of projects without super simple export enabled are always spawned normally.
+///
+/// [`IntGridCell`]: crate::components::IntGridCell
+/// [`LdtkSpawnErrorKind::MissingCompositeImage`]: crate::resources::LdtkSpawnErrorKind::MissingCompositeImage
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub enum SimplifiedLevelRendering {
+    /// All levels are spawned normally.
+    #[default]
+    Disabled,
+    /// All levels are rendered with their composite image.
+    All,
+    /// Only levels with the given `Identifier`s are rendered with their composite image.
+    Allowlist(Vec<String>),
+}
+
+impl SimplifiedLevelRendering {
+    /// Returns `true` if the level with the given identifier should be rendered with its
+    /// composite image.
+    pub fn renders_composite(&self, level_identifier: &str) -> bool {
+        match self {
+            SimplifiedLevelRendering::Disabled => false,
+            SimplifiedLevelRendering::All => true,
+            SimplifiedLevelRendering::Allowlist(identifiers) => identifiers
+                .iter()
+                .any(|identifier| identifier == level_identifier),
+        }
+    }
+}
+
+/// Option in [LdtkSettings] that determines whether tiles are animated automatically.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum TileAnimations {
+    /// Tiles are not animated, unless an [`AnimatedTile`] is inserted manually.
+    ///
+    /// [`AnimatedTile`]: bevy_ecs_tilemap::tiles::AnimatedTile
+    #[default]
+    Disabled,
+    /// Tiles whose tileset custom data describes a [`TileAnimation`] are given an
+    /// [`AnimatedTile`].
+    ///
+    /// [`TileAnimation`]: crate::prelude::TileAnimation
+    /// [`AnimatedTile`]: bevy_ecs_tilemap::tiles::AnimatedTile
+    FromCustomData,
+}
+
+/// Option in [LdtkSettings] that determines how layers that are hidden in LDtk are spawned.
+///
+/// Useful for keeping debug or annotation layers used while editing out of the game.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum InvisibleLayers {
+    /// Layers are spawned and rendered regardless of their visibility in LDtk.
+    #[default]
+    Rendered,
+    /// Layers that are hidden in LDtk are spawned with [`Visibility::Hidden`].
+    ///
+    /// Their tiles and entities are still spawned, so they can be shown at runtime and IntGrid
+    /// values are still available for game logic.
+    Hidden,
+    /// Layers that are hidden in LDtk are not spawned at all, like layers in [SpawnExclusions].
+    Excluded,
+}
+
+/// Texture filtering used for tileset images, see [TilesetSampler].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum TilesetFilter {
+    /// Nearest-neighbor filtering, usually desired for pixel art.
+    Nearest,
+    /// Linear filtering.
+    Linear,
+}
+
+impl TilesetFilter {
+    /// Returns the [`ImageFilterMode`] corresponding to this filter.
+    ///
+    /// [`ImageFilterMode`]: https://docs.rs/bevy/latest/bevy/render/texture/enum.ImageFilterMode.html
+    pub fn filter_mode(self) -> ImageFilterMode {
+        match self {
+            TilesetFilter::Nearest => ImageFilterMode::Nearest,
+            TilesetFilter::Linear => ImageFilterMode::Linear,
+        }
+    }
+
+    /// Returns an [`ImageSampler`] using this filter.
+    ///
+    /// [`ImageSampler`]: https://docs.rs/bevy/latest/bevy/render/texture/enum.ImageSampler.html
+    pub fn sampler(self) -> ImageSampler {
+        match self {
+            TilesetFilter::Nearest => ImageSampler::nearest(),
+            TilesetFilter::Linear => ImageSampler::linear(),
+        }
+    }
+
+    /// Returns true if the given sampler already uses this filter.
+    pub fn is_used_by(self, sampler: &ImageSampler) -> bool {
+        let filter_mode = self.filter_mode();
+
+        matches!(
+            sampler,
+            ImageSampler::Descriptor(descriptor)
+                if discriminant(&descriptor.mag_filter) == discriminant(&filter_mode)
+                    && discriminant(&descriptor.min_filter) == discriminant(&filter_mode)
+                    && discriminant(&descriptor.mipmap_filter) == discriminant(&filter_mode)
+        )
+    }
+}
+
+/// Option in [LdtkSettings] that determines the texture filtering of tileset images.
+///
+/// Pixel art tilesets usually need [TilesetFilter::Nearest] to avoid blurry or bleeding tiles,
+/// regardless of the global default sampler configured in Bevy's `ImagePlugin`.
+///
+/// Samplers are set on the tileset images whenever a level spawns.
+/// Only applies when the `render` feature is enabled.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct TilesetSampler {
+    /// Filter for all tileset images, or `None` to leave their samplers unchanged.
+    pub default: Option<TilesetFilter>,
+    /// Filters for tilesets with the given `Identifier`s, taking priority over `default`.
+    pub overrides: HashMap<String, TilesetFilter>,
+}
+
+impl TilesetSampler {
+    /// Uses nearest-neighbor filtering for all tilesets.
+    pub fn nearest() -> Self {
+        TilesetSampler {
+            default: Some(TilesetFilter::Nearest),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Returns the filter for the tileset with the given identifier, if one is configured.
+    pub fn filter_for(&self, tileset_identifier: &str) -> Option<TilesetFilter> {
+        self.overrides
+            .get(tileset_identifier)
+            .or(self.default.as_ref())
+            .copied()
+    }
+}
+
 /// Specifies data that should be ignored completely when spawning levels. Excluded items will still
 /// be present in the [`LdtkProject`] but will not cause any entities to be spawned in the world.
+///
+/// For more control over which layers are spawned, see [LayerFilter].
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct SpawnExclusions {
     /// List of layer `Identifier` names (not UIDs) to skip spawning as tilemaps.
     pub layer_identifiers: Vec<String>,
 }
 
+/// Predicate used by [LayerFilter::Predicate].
+pub type LayerPredicate = Arc<dyn Fn(&LayerInstance) -> bool + Send + Sync>;
+
+/// Option in [LdtkSettings] that determines which layers are spawned.
+///
+/// Layers that are filtered out are skipped entirely, so no tiles or entities are spawned for them.
+/// This is applied in addition to [SpawnExclusions].
+#[derive(Clone, Default)]
+pub enum LayerFilter {
+    /// All layers are spawned.
+    #[default]
+    All,
+    /// Only layers with the given `Identifier`s are spawned.
+    Allowlist(Vec<String>),
+    /// Layers with the given `Identifier`s are not spawned.
+    Denylist(Vec<String>),
+    /// Only layers for which the predicate returns `true` are spawned.
+    ///
+    /// ```
+    /// use bevy_ecs_ldtk::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// let layer_filter = LayerFilter::Predicate(Arc::new(|layer: &LayerInstance| {
+    ///     !layer.identifier.starts_with("Reference")
+    /// }));
+    /// ```
+    Predicate(LayerPredicate),
+}
+
+impl LayerFilter {
+    /// Returns `true` if the given layer should be spawned.
+    pub fn allows(&self, layer_instance: &LayerInstance) -> bool {
+        match self {
+            LayerFilter::All => true,
+            LayerFilter::Allowlist(identifiers) => identifiers.contains(&layer_instance.identifier),
+            LayerFilter::Denylist(identifiers) => !identifiers.contains(&layer_instance.identifier),
+            LayerFilter::Predicate(predicate) => predicate(layer_instance),
+        }
+    }
+}
+
+impl std::fmt::Debug for LayerFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayerFilter::All => write!(f, "All"),
+            LayerFilter::Allowlist(identifiers) => {
+                f.debug_tuple("Allowlist").field(identifiers).finish()
+            }
+            LayerFilter::Denylist(identifiers) => {
+                f.debug_tuple("Denylist").field(identifiers).finish()
+            }
+            LayerFilter::Predicate(_) => write!(f, "Predicate(..)"),
+        }
+    }
+}
+
+impl PartialEq for LayerFilter {
+    /// Predicates are only equal if they are the same [Arc].
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (LayerFilter::All, LayerFilter::All) => true,
+            (LayerFilter::Allowlist(a), LayerFilter::Allowlist(b)) => a == b,
+            (LayerFilter::Denylist(a), LayerFilter::Denylist(b)) => a == b,
+            (LayerFilter::Predicate(a), LayerFilter::Predicate(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for LayerFilter {}
+
+/// Option in [LdtkSettings] that determines the z translation of layers relative to their level.
+///
+/// Layers are stacked in the same order as in LDtk, starting with the level background (if
+/// [LevelBackground::Rendered]).
+/// Tile and AutoTile layers may be split into several layer entities when tiles overlap, each of
+/// which take up a spot in the stack.
+///
+/// Use `layer_overrides` to place specific layers at a known z, so you can interleave your own
+/// sprites between them.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LayerZPolicy {
+    /// z translation of the bottom-most layer.
+    pub base_z: f32,
+    /// z distance between consecutive layers.
+    pub z_increment: f32,
+    /// z translations for layers with the given `Identifier`s, replacing their position in the
+    /// stack.
+    ///
+    /// Layers that are split into several layer entities will have these spaced out by
+    /// `z_increment` above the overridden z.
+    pub layer_overrides: HashMap<String, f32>,
+}
+
+impl Default for LayerZPolicy {
+    fn default() -> Self {
+        LayerZPolicy {
+            base_z: 0.,
+            z_increment: 1.,
+            layer_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl LayerZPolicy {
+    /// Returns the z translation of a layer entity.
+    ///
+    /// `stack_index` is the position of the layer entity in the level's stack of layers, and
+    /// `sublayer_index` is its position among the layer entities spawned for the same LDtk layer.
+    pub fn layer_z(
+        &self,
+        layer_identifier: &str,
+        stack_index: usize,
+        sublayer_index: usize,
+    ) -> f32 {
+        match self.layer_overrides.get(layer_identifier) {
+            Some(z) => z + sublayer_index as f32 * self.z_increment,
+            None => self.base_z + stack_index as f32 * self.z_increment,
+        }
+    }
+
+    /// Returns the z translation of the layer entity at the given position in the level's stack
+    /// of layers, ignoring overrides.
+    pub fn stack_z(&self, stack_index: usize) -> f32 {
+        self.base_z + stack_index as f32 * self.z_increment
+    }
+}
+
+/// Option in [LdtkSettings] that determines the tint multiplied with the tiles of each layer.
+///
+/// By default, layers use the tint color authored on their definition in LDtk, if any.
+/// Use `layer_overrides` to tint specific layers at runtime, e.g. for darkening fog-of-war layers
+/// or coloring lighting layers.
+/// Entity layers are not affected.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LayerTints {
+    /// Tints for layers with the given `Identifier`s, replacing their authored tint.
+    ///
+    /// A value of `None` disables tinting for the layer.
+    pub layer_overrides: HashMap<String, Option<Color>>,
+}
+
+impl LayerTints {
+    /// Returns the tint of layers with the given definition, if they should be tinted.
+    pub fn tint(&self, layer_definition: &LayerDefinition) -> Option<Color> {
+        match self.layer_overrides.get(&layer_definition.identifier) {
+            Some(tint) => *tint,
+            None => layer_definition.tint_color,
+        }
+    }
+}
+
+/// Option in [LdtkSettings] that determines the order entities are spawned in within their layer,
+/// and the z translation they are given relative to it.
+///
+/// By default, entities are spawned in the same order as in LDtk, all at the same z.
+/// Overlapping entity sprites may then z-fight.
+/// The sorting variants instead spread the entities of each layer over `z_range` in a
+/// deterministic order, with entities later in the order in front.
+/// Ties keep their LDtk order.
+///
+/// Keep `z_range` below [LayerZPolicy::z_increment] so entities stay between their layer and the
+/// next one.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum EntitySpawnOrder {
+    /// Entities are spawned in LDtk order, with a z translation of zero.
+    #[default]
+    Ldtk,
+    /// Entities lower in the level are spawned later and in front, for top-down depth sorting.
+    YSort {
+        /// Range of z translations the entities of a layer are spread over.
+        z_range: f32,
+    },
+    /// Entities are sorted by the value of an `Int` or `Float` field, with larger values spawned
+    /// later and in front.
+    ///
+    /// Entities without the field are spawned first.
+    Field {
+        /// `Identifier` of the field to sort by.
+        identifier: String,
+        /// Range of z translations the entities of a layer are spread over.
+        z_range: f32,
+    },
+}
+
+impl EntitySpawnOrder {
+    /// Returns the given entity instances in spawn order, along with their z translations.
+    pub fn sort<'a>(
+        &self,
+        entity_instances: &'a [EntityInstance],
+    ) -> Vec<(&'a EntityInstance, f32)> {
+        let sort_key = |entity_instance: &EntityInstance| match self {
+            EntitySpawnOrder::Ldtk => None,
+            EntitySpawnOrder::YSort { .. } => Some(entity_instance.px.y as f32),
+            EntitySpawnOrder::Field { identifier, .. } => entity_instance
+                .get_float_field(identifier)
+                .copied()
+                .or_else(|_| entity_instance.get_int_field(identifier).map(|i| *i as f32))
+                .ok(),
+        };
+
+        let z_range = match self {
+            EntitySpawnOrder::Ldtk => 0.,
+            EntitySpawnOrder::YSort { z_range } | EntitySpawnOrder::Field { z_range, .. } => {
+                *z_range
+            }
+        };
+
+        let mut sorted: Vec<&EntityInstance> = entity_instances.iter().collect();
+        if *self != EntitySpawnOrder::Ldtk {
+            // stable, so ties keep their LDtk order
+            sorted.sort_by(|a, b| match (sort_key(a), sort_key(b)) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            });
+        }
+
+        let count = sorted.len().max(1) as f32;
+        sorted
+            .into_iter()
+            .enumerate()
+            .map(|(i, entity_instance)| (entity_instance, i as f32 / count * z_range))
+            .collect()
+    }
+}
+
+/// Option in [LdtkSettings] that determines which field of LDtk entities sets their z translation.
+///
+/// Lets designers control the draw order of overlapping props directly in LDtk.
+/// If an entity has a non-null `Int` or `Float` field with this identifier, its value is added to
+/// the entity's z translation relative to its layer, on top of the z from [EntitySpawnOrder].
+/// Entities without the field are unaffected.
+///
+/// Defaults to a field named `"z"`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct EntityZField {
+    /// Identifier of the field, or `None` to ignore entity fields.
+    pub identifier: Option<String>,
+}
+
+impl Default for EntityZField {
+    fn default() -> Self {
+        EntityZField {
+            identifier: Some("z".to_string()),
+        }
+    }
+}
+
+impl EntityZField {
+    /// Returns the z translation the given entity instance requests through its field, if any.
+    pub fn z(&self, entity_instance: &EntityInstance) -> Option<f32> {
+        let identifier = self.identifier.as_ref()?;
+
+        entity_instance
+            .get_float_field(identifier)
+            .copied()
+            .or_else(|_| entity_instance.get_int_field(identifier).map(|i| *i as f32))
+            .ok()
+    }
+}
+
+/// Option in [LdtkSettings] that determines whether entities with a [`YSort`] component have their
+/// z translation updated from their y translation at runtime.
+///
+/// Unlike [EntitySpawnOrder], this keeps moving entities sorted after they spawn.
+///
+/// [`YSort`]: crate::components::YSort
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum YSorting {
+    /// [`YSort`] entities keep the z translation they spawned with.
+    ///
+    /// [`YSort`]: crate::components::YSort
+    #[default]
+    Disabled,
+    /// The z translation of [`YSort`] entities is set to `max_z - y * z_per_pixel`, clamped
+    /// between `0` and `max_z`.
+    ///
+    /// Since LDtk entities are children of their layer, keep `max_z` below
+    /// [LayerZPolicy::z_increment] so they stay between their layer and the next one.
+    ///
+    /// [`YSort`]: crate::components::YSort
+    Enabled {
+        /// z translation of entities at a y translation of zero.
+        max_z: f32,
+        /// Decrease in z translation per pixel of y translation.
+        z_per_pixel: f32,
+    },
+}
+
+impl YSorting {
+    /// Returns the z translation for the given y translation, if y-sorting is enabled.
+    pub fn z(&self, y: f32) -> Option<f32> {
+        match self {
+            YSorting::Disabled => None,
+            YSorting::Enabled { max_z, z_per_pixel } => {
+                Some((max_z - y * z_per_pixel).clamp(0., max_z.max(0.)))
+            }
+        }
+    }
+}
+
+/// Option in [LdtkSettings] that determines how problems found while spawning levels are
+/// reported, see [LdtkSpawnErrorKind] for the problems detected.
+///
+/// By default, these only produce warnings, and the affected content is spawned with defaults or
+/// skipped, which can go unnoticed.
+/// Stricter modes help catch content regressions during development, in CI or playtests.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum StrictMode {
+    /// Only problems with backgrounds and layer tilesets are logged as warnings.
+    #[default]
+    Disabled,
+    /// Problems are logged as warnings and sent as [LdtkSpawnError] events.
+    Events,
+    /// Problems cause a panic.
+    Panic,
+}
+
 /// Settings resource for the plugin.
 /// Check out the documentation for each field type to learn more.
-#[derive(Clone, Eq, PartialEq, Debug, Default, Resource)]
+///
+/// Some settings contain floats, which may be NaN, so this only implements [PartialEq].
+#[derive(Clone, PartialEq, Debug, Default, Resource)]
 pub struct LdtkSettings {
     pub level_spawn_behavior: LevelSpawnBehavior,
+    pub level_spawn_mode: LevelSpawnMode,
+    pub level_origin: LevelOrigin,
+    pub entity_instance_storage: EntityInstanceStorage,
     pub set_clear_color: SetClearColor,
     pub int_grid_rendering: IntGridRendering,
     pub level_background: LevelBackground,
+    pub simplified_level_rendering: SimplifiedLevelRendering,
     pub exclusions: SpawnExclusions,
+    pub layer_filter: LayerFilter,
+    pub layer_z: LayerZPolicy,
+    pub layer_tints: LayerTints,
+    pub entity_spawn_order: EntitySpawnOrder,
+    pub entity_z_field: EntityZField,
+    pub y_sorting: YSorting,
+    pub strict: StrictMode,
+    /// Identifier of the LDtk entity that marks the spawn point of each level.
+    ///
+    /// Entities with this identifier get a [`SpawnPoint`] component.
+    ///
+    /// [`SpawnPoint`]: crate::components::SpawnPoint
+    pub spawn_point_identifier: Option<String>,
+    pub tile_animations: TileAnimations,
+    pub invisible_layers: InvisibleLayers,
+    pub world_depth: WorldDepth,
+    pub tileset_sampler: TilesetSampler,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_origin_offsets_level_contents() {
+        assert_eq!(LevelOrigin::BottomLeft.bottom_left_offset(32), Vec2::ZERO);
+        assert_eq!(
+            LevelOrigin::TopLeft.bottom_left_offset(32),
+            Vec2::new(0., -32.)
+        );
+
+        assert_eq!(LevelOrigin::BottomLeft.ldtk_pixel_height(32), 32);
+        assert_eq!(LevelOrigin::TopLeft.ldtk_pixel_height(32), 0);
+
+        assert_eq!(
+            LevelOrigin::TopLeft.local_rect(Vec2::new(64., 32.)),
+            Rect::new(0., -32., 64., 0.)
+        );
+    }
+
+    #[test]
+    fn layer_z_uses_stack_index_unless_overridden() {
+        let policy = LayerZPolicy {
+            base_z: 10.,
+            z_increment: 0.5,
+            layer_overrides: HashMap::from([("Foreground".to_string(), 100.)]),
+        };
+
+        assert_eq!(policy.layer_z("Background", 0, 0), 10.);
+        assert_eq!(policy.layer_z("Background", 3, 1), 11.5);
+        assert_eq!(policy.layer_z("Foreground", 4, 0), 100.);
+        assert_eq!(policy.layer_z("Foreground", 5, 1), 100.5);
+        assert_eq!(policy.stack_z(2), 11.);
+    }
+
+    #[test]
+    fn entity_spawn_order_sorts_and_spreads_z() {
+        let entity_instance = |iid: &str, y: i32| EntityInstance {
+            iid: iid.to_string(),
+            px: IVec2::new(0, y),
+            ..default()
+        };
+        let entity_instances = vec![
+            entity_instance("a", 32),
+            entity_instance("b", 0),
+            entity_instance("c", 32),
+            entity_instance("d", 16),
+        ];
+
+        let spawn_order = |order: &EntitySpawnOrder| -> Vec<(&str, f32)> {
+            order
+                .sort(&entity_instances)
+                .into_iter()
+                .map(|(entity_instance, z)| (entity_instance.iid.as_str(), z))
+                .collect()
+        };
+
+        assert_eq!(
+            spawn_order(&EntitySpawnOrder::Ldtk),
+            vec![("a", 0.), ("b", 0.), ("c", 0.), ("d", 0.)]
+        );
+        assert_eq!(
+            spawn_order(&EntitySpawnOrder::YSort { z_range: 0.5 }),
+            vec![("b", 0.), ("d", 0.125), ("a", 0.25), ("c", 0.375)]
+        );
+        assert_eq!(
+            spawn_order(&EntitySpawnOrder::Field {
+                identifier: "depth".to_string(),
+                z_range: 0.5
+            }),
+            vec![("a", 0.), ("b", 0.125), ("c", 0.25), ("d", 0.375)]
+        );
+    }
+
+    #[test]
+    fn entity_z_field_reads_int_and_float_fields() {
+        use crate::ldtk::{ldtk_fields::field_instance_from_value, FieldValue};
+
+        let entity_instance = |value: FieldValue| EntityInstance {
+            field_instances: vec![field_instance_from_value("z", value)],
+            ..default()
+        };
+
+        let entity_z_field = EntityZField::default();
+
+        assert_eq!(
+            entity_z_field.z(&entity_instance(FieldValue::Int(Some(3)))),
+            Some(3.)
+        );
+        assert_eq!(
+            entity_z_field.z(&entity_instance(FieldValue::Float(Some(0.5)))),
+            Some(0.5)
+        );
+        assert_eq!(
+            entity_z_field.z(&entity_instance(FieldValue::Float(None))),
+            None
+        );
+        assert_eq!(
+            entity_z_field.z(&entity_instance(FieldValue::Bool(true))),
+            None
+        );
+        assert_eq!(
+            EntityZField { identifier: None }.z(&entity_instance(FieldValue::Int(Some(3)))),
+            None
+        );
+    }
+
+    #[test]
+    fn y_sorting_decreases_z_with_y() {
+        let y_sorting = YSorting::Enabled {
+            max_z: 0.5,
+            z_per_pixel: 0.001,
+        };
+
+        assert_eq!(y_sorting.z(0.), Some(0.5));
+        assert_eq!(y_sorting.z(100.), Some(0.4));
+        assert_eq!(y_sorting.z(1000.), Some(0.));
+        assert_eq!(y_sorting.z(-100.), Some(0.5));
+        assert_eq!(YSorting::Disabled.z(100.), None);
+    }
+
+    #[test]
+    fn layer_filter_allows_layers() {
+        let layer = |identifier: &str| LayerInstance {
+            identifier: identifier.to_string(),
+            ..default()
+        };
+
+        let allowlist = LayerFilter::Allowlist(vec!["Tiles".to_string()]);
+        assert!(allowlist.allows(&layer("Tiles")));
+        assert!(!allowlist.allows(&layer("Annotations")));
+
+        let denylist = LayerFilter::Denylist(vec!["Annotations".to_string()]);
+        assert!(denylist.allows(&layer("Tiles")));
+        assert!(!denylist.allows(&layer("Annotations")));
+
+        let predicate = LayerFilter::Predicate(Arc::new(|layer| layer.identifier.len() < 6));
+        assert!(predicate.allows(&layer("Tiles")));
+        assert!(!predicate.allows(&layer("Annotations")));
+
+        assert!(LayerFilter::All.allows(&layer("Annotations")));
+    }
+
+    #[test]
+    fn world_depth_offset_scales_with_depth() {
+        let world_depth = WorldDepth::Offset(Vec3::new(0., 8., 100.));
+
+        assert_eq!(world_depth.offset(0), Vec3::ZERO);
+        assert_eq!(world_depth.offset(2), Vec3::new(0., 16., 200.));
+        assert_eq!(world_depth.offset(-1), Vec3::new(0., -8., -100.));
+        assert_eq!(WorldDepth::Ignored.offset(3), Vec3::ZERO);
+    }
+
+    #[test]
+    fn tileset_sampler_overrides_take_priority() {
+        let mut tileset_sampler = TilesetSampler::nearest();
+        tileset_sampler
+            .overrides
+            .insert("Painting".to_string(), TilesetFilter::Linear);
+
+        assert_eq!(
+            tileset_sampler.filter_for("Tiles"),
+            Some(TilesetFilter::Nearest)
+        );
+        assert_eq!(
+            tileset_sampler.filter_for("Painting"),
+            Some(TilesetFilter::Linear)
+        );
+        assert_eq!(TilesetSampler::default().filter_for("Tiles"), None);
+    }
+
+    #[test]
+    fn tileset_filter_detects_matching_samplers() {
+        assert!(TilesetFilter::Nearest.is_used_by(&ImageSampler::nearest()));
+        assert!(!TilesetFilter::Nearest.is_used_by(&ImageSampler::linear()));
+        assert!(!TilesetFilter::Linear.is_used_by(&ImageSampler::Default));
+    }
 }
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use thiserror::Error;
+
+use crate::components::LevelIid;
+
+#[allow(unused_imports)]
+use crate::resources::{LdtkSettings, SimplifiedLevelRendering, StrictMode};
+
+/// A problem found while spawning a level, see [`LdtkSpawnError`].
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum LdtkSpawnErrorKind {
+    /// The background image of the level could not be rendered.
+    #[error("unable to render level background image: {0}")]
+    Background(String),
+    /// The composite image of a level rendered with [`SimplifiedLevelRendering`] failed to load,
+    /// so the level was spawned with its tile layers instead.
+    #[error("unable to render level with its composite image, it failed to load")]
+    MissingCompositeImage,
+    /// A Tile or AutoTile layer's tileset image was not loaded, so the layer was not spawned.
+    #[error("unable to render tilemap layer {layer_identifier}, its tileset image was not loaded")]
+    MissingTilesetImage {
+        /// Identifier of the layer.
+        layer_identifier: String,
+    },
+    /// A layer has no tileset, and no image for rendering IntGrid colors was created, so the
+    /// layer was not spawned.
+    #[error("unable to render tilemap layer {layer_identifier}, it has no tileset and no intgrid layers were expected")]
+    MissingTileset {
+        /// Identifier of the layer.
+        layer_identifier: String,
+    },
+    /// An entity's tile uses a tileset whose image was not loaded, so it was spawned without a
+    /// texture.
+    #[error("entity {entity_identifier} in layer {layer_identifier} has a tile, but its tileset image was not loaded")]
+    MissingEntityTileset {
+        /// Identifier of the entity's layer.
+        layer_identifier: String,
+        /// Identifier of the entity.
+        entity_identifier: String,
+    },
+    /// No `LdtkEntity` is registered for an entity, so it was spawned with an
+    /// `EntityInstanceBundle`.
+    #[error("entity {entity_identifier} in layer {layer_identifier} is not registered")]
+    UnregisteredEntity {
+        /// Identifier of the entity's layer.
+        layer_identifier: String,
+        /// Identifier of the entity.
+        entity_identifier: String,
+    },
+}
+
+impl LdtkSpawnErrorKind {
+    /// Whether this problem is logged as a warning even if [`StrictMode::Disabled`] is set.
+    ///
+    /// Problems with backgrounds and layer tilesets are always logged, while problems with
+    /// individual entities are only reported in strict modes, since they are often intentional.
+    pub fn is_logged_by_default(&self) -> bool {
+        matches!(
+            self,
+            LdtkSpawnErrorKind::Background(_)
+                | LdtkSpawnErrorKind::MissingCompositeImage
+                | LdtkSpawnErrorKind::MissingTilesetImage { .. }
+                | LdtkSpawnErrorKind::MissingTileset { .. }
+        )
+    }
+}
+
+/// Event fired for problems found while spawning a level, if [`StrictMode::Events`] is set in
+/// the [`LdtkSettings`].
+///
+/// Otherwise, the affected content is spawned with defaults or not at all, and only some problems
+/// are logged as warnings, see [`LdtkSpawnErrorKind::is_logged_by_default`].
+#[derive(Clone, Eq, PartialEq, Debug, Error, Event)]
+#[error("in level {level_iid}: {kind}")]
+pub struct LdtkSpawnError {
+    /// Iid of the level being spawned.
+    pub level_iid: LevelIid,
+    /// What went wrong.
+    pub kind: LdtkSpawnErrorKind,
+}
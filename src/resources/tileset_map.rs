@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::{assets::LdtkProject, ldtk::TilesetDefinition};
+
+#[allow(unused_imports)]
+use crate::app::LdtkEntity;
+
+/// Layout and metadata of a single tileset, along with the handle of its loaded image.
+///
+/// Carries everything needed to index into the tileset image without re-parsing its
+/// [`TilesetDefinition`].
+/// Stored for every loaded project in the [`TilesetMap`] resource, and passed to
+/// [`LdtkEntity::bundle_entity`] for the tileset of the entity's tile.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct TilesetInfo {
+    /// Unique identifier of the tileset.
+    pub uid: i32,
+    /// User-defined identifier of the tileset.
+    pub identifier: String,
+    /// Handle of the tileset image, if it has one that was loaded.
+    pub image: Option<Handle<Image>>,
+    /// Width and height of the tiles in pixels.
+    pub tile_size: i32,
+    /// Grid-based width and height of the tileset.
+    pub grid_size: IVec2,
+    /// Space in pixels between tiles.
+    pub spacing: i32,
+    /// Distance in pixels from the image borders to the first tiles.
+    pub padding: i32,
+    /// Enum tags of the tileset, by tile id.
+    pub enum_tags: HashMap<i32, Vec<String>>,
+}
+
+impl TilesetInfo {
+    /// Creates a [`TilesetInfo`] from a tileset definition and its image.
+    pub fn new(tileset_definition: &TilesetDefinition, image: Option<Handle<Image>>) -> Self {
+        let mut enum_tags: HashMap<i32, Vec<String>> = HashMap::new();
+        for enum_tag in &tileset_definition.enum_tags {
+            for tile_id in &enum_tag.tile_ids {
+                enum_tags
+                    .entry(*tile_id)
+                    .or_default()
+                    .push(enum_tag.enum_value_id.clone());
+            }
+        }
+
+        TilesetInfo {
+            uid: tileset_definition.uid,
+            identifier: tileset_definition.identifier.clone(),
+            image,
+            tile_size: tileset_definition.tile_grid_size,
+            grid_size: IVec2::new(tileset_definition.c_wid, tileset_definition.c_hei),
+            spacing: tileset_definition.spacing,
+            padding: tileset_definition.padding,
+            enum_tags,
+        }
+    }
+
+    /// Returns the enum tags of the tile with the given id.
+    pub fn tile_enum_tags(&self, tile_id: i32) -> &[String] {
+        self.enum_tags.get(&tile_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the pixel coordinates of the top-left corner of the tile with the given id in the
+    /// tileset image.
+    ///
+    /// Returns `None` if the id is outside of the tileset.
+    pub fn tile_px(&self, tile_id: i32) -> Option<IVec2> {
+        if tile_id < 0 || self.grid_size.x <= 0 || tile_id >= self.grid_size.x * self.grid_size.y {
+            return None;
+        }
+
+        let grid = IVec2::new(tile_id % self.grid_size.x, tile_id / self.grid_size.x);
+
+        Some(IVec2::splat(self.padding) + grid * (self.tile_size + self.spacing))
+    }
+}
+
+impl LdtkProject {
+    /// Creates a [`TilesetInfo`] for every tileset in the project, by tileset uid.
+    pub fn tileset_infos(&self) -> HashMap<i32, TilesetInfo> {
+        self.json_data()
+            .defs
+            .tilesets
+            .iter()
+            .map(|tileset_definition| {
+                (
+                    tileset_definition.uid,
+                    TilesetInfo::new(
+                        tileset_definition,
+                        self.tileset_map().get(&tileset_definition.uid).cloned(),
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// [`Resource`] storing the [`TilesetInfo`] of every tileset of every loaded [`LdtkProject`].
+///
+/// Updated whenever a project is added, finishes loading, is modified, or is removed.
+///
+/// [`Resource`]: https://docs.rs/bevy/latest/bevy/ecs/prelude/trait.Resource.html
+#[derive(Clone, Eq, PartialEq, Debug, Default, Resource)]
+pub struct TilesetMap {
+    projects: HashMap<AssetId<LdtkProject>, HashMap<i32, TilesetInfo>>,
+}
+
+impl TilesetMap {
+    /// Returns the tilesets of the given project, by tileset uid.
+    pub fn tilesets(
+        &self,
+        project: impl Into<AssetId<LdtkProject>>,
+    ) -> Option<&HashMap<i32, TilesetInfo>> {
+        self.projects.get(&project.into())
+    }
+
+    /// Returns the tileset with the given uid in the given project.
+    pub fn get(&self, project: impl Into<AssetId<LdtkProject>>, uid: i32) -> Option<&TilesetInfo> {
+        self.tilesets(project)?.get(&uid)
+    }
+
+    /// Returns the tileset with the given identifier in the given project.
+    pub fn get_by_identifier(
+        &self,
+        project: impl Into<AssetId<LdtkProject>>,
+        identifier: &str,
+    ) -> Option<&TilesetInfo> {
+        self.tilesets(project)?
+            .values()
+            .find(|tileset_info| tileset_info.identifier == identifier)
+    }
+
+    pub(crate) fn insert(&mut self, project_id: AssetId<LdtkProject>, project: &LdtkProject) {
+        self.projects.insert(project_id, project.tileset_infos());
+    }
+
+    pub(crate) fn remove(&mut self, project_id: AssetId<LdtkProject>) {
+        self.projects.remove(&project_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldtk::EnumTagValue;
+
+    #[test]
+    fn tileset_info_describes_tileset_layout() {
+        let tileset_definition = TilesetDefinition {
+            uid: 3,
+            identifier: "Dungeon".to_string(),
+            tile_grid_size: 16,
+            c_wid: 4,
+            c_hei: 2,
+            spacing: 2,
+            padding: 1,
+            enum_tags: vec![
+                EnumTagValue {
+                    enum_value_id: "Solid".to_string(),
+                    tile_ids: vec![0, 5],
+                },
+                EnumTagValue {
+                    enum_value_id: "Wet".to_string(),
+                    tile_ids: vec![5],
+                },
+            ],
+            ..default()
+        };
+
+        let tileset_info = TilesetInfo::new(&tileset_definition, None);
+
+        assert_eq!(tileset_info.grid_size, IVec2::new(4, 2));
+        assert_eq!(tileset_info.tile_enum_tags(5), &["Solid", "Wet"]);
+        assert!(tileset_info.tile_enum_tags(1).is_empty());
+
+        assert_eq!(tileset_info.tile_px(0), Some(IVec2::new(1, 1)));
+        assert_eq!(tileset_info.tile_px(5), Some(IVec2::new(19, 19)));
+        assert_eq!(tileset_info.tile_px(8), None);
+    }
+}
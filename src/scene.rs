@@ -0,0 +1,126 @@
+//! Provides [level_to_dynamic_scene] for baking spawned levels into bevy [DynamicScene]s.
+//!
+//! Requires the `scene` feature to be enabled.
+use bevy::prelude::*;
+
+/// Creates a [DynamicScene] from a spawned level entity and all of its descendants.
+///
+/// The scene can be serialized into a scene file, so levels can be shipped or diffed without
+/// loading LDtk projects at runtime.
+///
+/// Only components registered in the world's [AppTypeRegistry] with `#[reflect(Component)]` are
+/// included, so make sure to register your own components if they should be part of the scene.
+/// Note that this excludes the level's tilemap storage, so tile layers need to be rebuilt from the
+/// [IntGridCsv] and tile components in the scene if they should be rendered.
+///
+/// The level entity's [Parent] is not included, so the scene can be spawned anywhere.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::{prelude::*, scene::level_to_dynamic_scene};
+/// fn bake_level(world: &mut World) {
+///     let mut level_query = world.query_filtered::<Entity, With<LevelIid>>();
+///
+///     for level_entity in level_query.iter(world).collect::<Vec<_>>() {
+///         let scene = level_to_dynamic_scene(world, level_entity);
+///         let type_registry = world.resource::<AppTypeRegistry>().read();
+///         let _ron = scene.serialize(&type_registry);
+///     }
+/// }
+/// ```
+///
+/// [IntGridCsv]: crate::components::IntGridCsv
+pub fn level_to_dynamic_scene(world: &World, level_entity: Entity) -> DynamicScene {
+    let mut entities = vec![level_entity];
+    let mut unvisited = vec![level_entity];
+
+    while let Some(entity) = unvisited.pop() {
+        if let Some(children) = world.get::<Children>(entity) {
+            entities.extend(children.iter().copied());
+            unvisited.extend(children.iter().copied());
+        }
+    }
+
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.into_iter())
+        .build();
+
+    if let Some(level) = scene
+        .entities
+        .iter_mut()
+        .find(|dynamic_entity| dynamic_entity.entity == level_entity)
+    {
+        level
+            .components
+            .retain(|component| !component.represents::<Parent>());
+    }
+
+    scene
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{GridCoords, LevelIid};
+
+    #[test]
+    fn level_hierarchy_is_extracted_without_level_parent() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<LevelIid>();
+            type_registry.register::<GridCoords>();
+            type_registry.register::<Parent>();
+            type_registry.register::<Children>();
+        }
+
+        let world_entity = world.spawn_empty().id();
+        let level_entity = world.spawn(LevelIid::new("level")).id();
+        let layer_entity = world.spawn_empty().id();
+        let tile_entity = world.spawn(GridCoords::new(1, 2)).id();
+        let unrelated_entity = world.spawn(GridCoords::new(3, 4)).id();
+
+        world.entity_mut(world_entity).add_child(level_entity);
+        world.entity_mut(level_entity).add_child(layer_entity);
+        world.entity_mut(layer_entity).add_child(tile_entity);
+
+        let scene = level_to_dynamic_scene(&world, level_entity);
+
+        let scene_entities: Vec<Entity> = scene
+            .entities
+            .iter()
+            .map(|dynamic_entity| dynamic_entity.entity)
+            .collect();
+
+        assert_eq!(scene_entities.len(), 3);
+        assert!(scene_entities.contains(&tile_entity));
+        assert!(!scene_entities.contains(&unrelated_entity));
+
+        let level = scene
+            .entities
+            .iter()
+            .find(|dynamic_entity| dynamic_entity.entity == level_entity)
+            .unwrap();
+
+        assert!(level
+            .components
+            .iter()
+            .any(|component| component.represents::<LevelIid>()));
+        assert!(!level
+            .components
+            .iter()
+            .any(|component| component.represents::<Parent>()));
+
+        let tile = scene
+            .entities
+            .iter()
+            .find(|dynamic_entity| dynamic_entity.entity == tile_entity)
+            .unwrap();
+
+        assert!(tile
+            .components
+            .iter()
+            .any(|component| component.represents::<Parent>()));
+    }
+}
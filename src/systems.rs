@@ -3,20 +3,35 @@
 #[cfg(feature = "render")]
 use crate::resources::SetClearColor;
 use crate::{
-    app::{LdtkEntityMap, LdtkIntCellMap},
-    assets::{LdtkProject, LdtkProjectData, LevelMetadataAccessor},
+    app::{LdtkEntityHooks, LdtkEntityMap, LdtkIntCellMap},
+    assets::{LdtkLevel, LdtkProject, LdtkProjectData, LevelMetadataAccessor},
     components::*,
-    ldtk::{Level, TilesetDefinition},
+    ldtk::{entity_ref_graph::EntityRefGraph, Level, TilesetDefinition},
     level::spawn_level,
-    resources::{LdtkSettings, LevelEvent, LevelSelection, LevelSpawnBehavior},
+    resources::{
+        EntityRefGraphs, LdtkEntityIndex, LdtkLoadError, LdtkLoadErrorKind, LdtkLoadProgress,
+        LdtkSettings, LevelEvent, LevelPatches, LevelSelection, LevelSpawnBehavior,
+        LevelSpawnGenerations, LevelSpawnMode, LevelTransition, SpawnPointEvent, TileAnimations,
+        TilesetMap, YSorting,
+    },
     utils::*,
 };
 
 #[cfg(feature = "external_levels")]
 use crate::assets::LdtkExternalLevel;
 
-use bevy::{asset::RecursiveDependencyLoadState, ecs::system::SystemState, prelude::*};
-use std::collections::{HashMap, HashSet};
+use bevy::{
+    asset::{
+        AssetLoadFailedEvent, AssetPath, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+    },
+    ecs::system::SystemState,
+    prelude::*,
+};
+use bevy_ecs_tilemap::{map::TilemapTexture, tiles::TileStorage};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 
 /// Detects [LdtkProject] events and spawns levels as children of the [LdtkWorldBundle].
 #[allow(clippy::too_many_arguments)]
@@ -24,6 +39,7 @@ pub fn process_ldtk_assets(
     mut commands: Commands,
     mut ldtk_project_events: EventReader<AssetEvent<LdtkProject>>,
     ldtk_world_query: Query<(Entity, &Handle<LdtkProject>)>,
+    level_project_query: Query<(Entity, &LevelProject)>,
     #[cfg(feature = "render")] ldtk_settings: Res<LdtkSettings>,
     #[cfg(feature = "render")] mut clear_color: ResMut<ClearColor>,
     #[cfg(feature = "render")] ldtk_project_assets: Res<Assets<LdtkProject>>,
@@ -61,53 +77,256 @@ pub fn process_ldtk_assets(
         }
     }
 
-    for (entity, handle) in ldtk_world_query.iter() {
+    let standalone_levels = level_project_query
+        .iter()
+        .map(|(entity, LevelProject(handle))| (entity, handle));
+
+    for (entity, handle) in ldtk_world_query.iter().chain(standalone_levels) {
         if ldtk_handles_to_respawn.contains(&handle.id()) {
             commands.entity(entity).insert(Respawn);
         }
     }
 }
 
+/// Keeps the [TilesetMap] resource in sync with loaded [LdtkProject]s.
+pub fn update_tileset_map(
+    mut ldtk_project_events: EventReader<AssetEvent<LdtkProject>>,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+    mut tileset_map: ResMut<TilesetMap>,
+) {
+    for event in ldtk_project_events.read() {
+        match event {
+            AssetEvent::Added { id }
+            | AssetEvent::LoadedWithDependencies { id }
+            | AssetEvent::Modified { id } => {
+                if let Some(project) = ldtk_project_assets.get(*id) {
+                    tileset_map.insert(*id, project);
+                }
+            }
+            AssetEvent::Removed { id } => tileset_map.remove(*id),
+            _ => (),
+        }
+    }
+}
+
+/// Builds the [EntityRefGraph]s of projects in [EntityRefGraphs] as they are added, and rebuilds
+/// them as they finish loading or are modified.
+pub fn update_entity_ref_graphs(
+    mut ldtk_project_events: EventReader<AssetEvent<LdtkProject>>,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+    #[cfg(feature = "external_levels")] level_assets: Res<Assets<LdtkExternalLevel>>,
+    mut entity_ref_graphs: ResMut<EntityRefGraphs>,
+) {
+    for event in ldtk_project_events.read() {
+        match event {
+            AssetEvent::Added { id }
+            | AssetEvent::LoadedWithDependencies { id }
+            | AssetEvent::Modified { id } => {
+                let Some(ldtk_project) = ldtk_project_assets.get(*id) else {
+                    continue;
+                };
+
+                let graph = match ldtk_project.data() {
+                    #[cfg(feature = "internal_levels")]
+                    LdtkProjectData::Standalone(project) => EntityRefGraph::from_project(project),
+                    #[cfg(feature = "external_levels")]
+                    LdtkProjectData::Parent(project) => EntityRefGraph::from_levels(
+                        project
+                            .iter_external_levels(&level_assets)
+                            .map(|level| level.raw()),
+                    ),
+                };
+
+                entity_ref_graphs.insert(*id, graph);
+            }
+            AssetEvent::Removed { id } => entity_ref_graphs.remove(*id),
+            _ => (),
+        }
+    }
+}
+
+/// Fires [LdtkLoadError] events for projects, and assets they depend on, that fail to load.
+///
+/// Dependencies can fail before their project has finished loading, so these are also checked
+/// when the project is added.
+#[allow(clippy::too_many_arguments)]
+pub fn fire_ldtk_load_errors(
+    mut ldtk_project_events: EventReader<AssetEvent<LdtkProject>>,
+    mut project_failures: EventReader<AssetLoadFailedEvent<LdtkProject>>,
+    mut image_failures: EventReader<AssetLoadFailedEvent<Image>>,
+    #[cfg(feature = "external_levels")] mut external_level_failures: EventReader<
+        AssetLoadFailedEvent<LdtkExternalLevel>,
+    >,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+    asset_server: Res<AssetServer>,
+    mut load_errors: EventWriter<LdtkLoadError>,
+) {
+    for failure in project_failures.read() {
+        load_errors.send(LdtkLoadError {
+            project: failure.id,
+            path: failure.path.clone(),
+            kind: LdtkLoadErrorKind::Project,
+            error: failure.error.to_string(),
+        });
+    }
+
+    #[cfg_attr(not(feature = "external_levels"), allow(unused_mut))]
+    let mut failed_dependencies: HashMap<UntypedAssetId, (AssetPath<'static>, String)> =
+        image_failures
+            .read()
+            .map(|failure| {
+                (
+                    failure.id.untyped(),
+                    (failure.path.clone(), failure.error.to_string()),
+                )
+            })
+            .collect();
+
+    #[cfg(feature = "external_levels")]
+    failed_dependencies.extend(external_level_failures.read().map(|failure| {
+        (
+            failure.id.untyped(),
+            (failure.path.clone(), failure.error.to_string()),
+        )
+    }));
+
+    let mut reported = HashSet::new();
+
+    for (project_id, project) in ldtk_project_assets.iter() {
+        for (dependency_id, (path, error)) in failed_dependencies.iter() {
+            if let Some(kind) = project.dependency_kind(*dependency_id) {
+                reported.insert((project_id, *dependency_id));
+                load_errors.send(LdtkLoadError {
+                    project: project_id,
+                    path: path.clone(),
+                    kind,
+                    error: error.clone(),
+                });
+            }
+        }
+    }
+
+    for event in ldtk_project_events.read() {
+        let AssetEvent::Added { id } = event else {
+            continue;
+        };
+
+        let Some(project) = ldtk_project_assets.get(*id) else {
+            continue;
+        };
+
+        for dependency_id in project.dependency_ids() {
+            if reported.contains(&(*id, dependency_id)) {
+                continue;
+            }
+
+            if let (Some(LoadState::Failed(error)), Some(path), Some(kind)) = (
+                asset_server.get_load_state(dependency_id),
+                asset_server.get_path(dependency_id),
+                project.dependency_kind(dependency_id),
+            ) {
+                load_errors.send(LdtkLoadError {
+                    project: *id,
+                    path: path.into_owned(),
+                    kind,
+                    error: error.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Updates the [LdtkLoadProgress] resource with the load states of projects and their
+/// dependencies.
+pub fn update_ldtk_load_progress(
+    mut load_progress: ResMut<LdtkLoadProgress>,
+    ldtk_project_query: Query<&Handle<LdtkProject>>,
+    ldtk_project_assets: Res<Assets<LdtkProject>>,
+    asset_server: Res<AssetServer>,
+) {
+    let project_ids: HashSet<AssetId<LdtkProject>> = ldtk_project_query
+        .iter()
+        .map(Handle::id)
+        .chain(ldtk_project_assets.ids())
+        .collect();
+
+    let mut progress = LdtkLoadProgress::default();
+
+    for project_id in project_ids {
+        progress.total += 1;
+
+        let Some(project) = ldtk_project_assets.get(project_id) else {
+            match asset_server.get_load_state(project_id) {
+                Some(LoadState::Failed(_)) => progress.failed += 1,
+                None => progress.untracked += 1,
+                _ => (),
+            }
+            continue;
+        };
+
+        progress.loaded += 1;
+
+        for dependency_id in project.dependency_ids() {
+            progress.total += 1;
+
+            match asset_server.get_load_state(dependency_id) {
+                Some(LoadState::Loaded) => progress.loaded += 1,
+                Some(LoadState::Failed(_)) => progress.failed += 1,
+                // Assets that aren't tracked by the asset server were added directly, or never
+                // loaded at all.
+                None => progress.untracked += 1,
+                _ => (),
+            }
+        }
+    }
+
+    load_progress.set_if_neq(progress);
+}
+
 /// Updates all LevelSet components according to the LevelSelection
+///
+/// Worlds with their own LevelSelection component use it instead of the resource.
 pub fn apply_level_selection(
     level_selection: Option<Res<LevelSelection>>,
     ldtk_settings: Res<LdtkSettings>,
     ldtk_project_assets: Res<Assets<LdtkProject>>,
-    mut level_set_query: Query<(&Handle<LdtkProject>, &mut LevelSet)>,
+    mut level_set_query: Query<(&Handle<LdtkProject>, &mut LevelSet, Option<&LevelSelection>)>,
     #[cfg(feature = "render")] mut clear_color: ResMut<ClearColor>,
 ) {
-    if let Some(level_selection) = level_selection {
-        for (ldtk_handle, mut level_set) in level_set_query.iter_mut() {
-            if let Some(project) = &ldtk_project_assets.get(ldtk_handle) {
-                if let Some(level) = project.find_raw_level_by_level_selection(&level_selection) {
-                    let new_level_set = {
-                        let mut iids = HashSet::new();
-                        iids.insert(LevelIid::new(level.iid.clone()));
-
-                        if let LevelSpawnBehavior::UseWorldTranslation {
-                            load_level_neighbors,
-                        } = ldtk_settings.level_spawn_behavior
-                        {
-                            if load_level_neighbors {
-                                iids.extend(
-                                    level
-                                        .neighbours
-                                        .iter()
-                                        .map(|n| LevelIid::new(n.level_iid.clone())),
-                                );
-                            }
+    for (ldtk_handle, mut level_set, world_level_selection) in level_set_query.iter_mut() {
+        let Some(level_selection) = world_level_selection.or(level_selection.as_deref()) else {
+            continue;
+        };
+
+        if let Some(project) = &ldtk_project_assets.get(ldtk_handle) {
+            if let Some(level) = project.find_raw_level_by_level_selection(level_selection) {
+                let new_level_set = {
+                    let mut iids = HashSet::new();
+                    iids.insert(LevelIid::new(level.iid.clone()));
+
+                    if let LevelSpawnBehavior::UseWorldTranslation {
+                        load_level_neighbors,
+                    } = ldtk_settings.level_spawn_behavior
+                    {
+                        if load_level_neighbors {
+                            iids.extend(
+                                level
+                                    .neighbours
+                                    .iter()
+                                    .map(|n| LevelIid::new(n.level_iid.clone())),
+                            );
                         }
+                    }
 
-                        LevelSet { iids }
-                    };
+                    LevelSet { iids }
+                };
 
-                    if *level_set != new_level_set {
-                        *level_set = new_level_set;
+                if *level_set != new_level_set {
+                    *level_set = new_level_set;
 
-                        #[cfg(feature = "render")]
-                        if ldtk_settings.set_clear_color == SetClearColor::FromLevelBackground {
-                            clear_color.0 = level.bg_color;
-                        }
+                    #[cfg(feature = "render")]
+                    if ldtk_settings.set_clear_color == SetClearColor::FromLevelBackground {
+                        clear_color.0 = level.bg_color;
                     }
                 }
             }
@@ -125,6 +344,7 @@ pub fn apply_level_set(
         Option<&Children>,
         &Handle<LdtkProject>,
         Option<&Respawn>,
+        Option<&LevelOffsets>,
     )>,
     ldtk_level_query: Query<(&LevelIid, Entity)>,
     ldtk_project_assets: Res<Assets<LdtkProject>>,
@@ -132,7 +352,9 @@ pub fn apply_level_set(
     asset_server: Res<AssetServer>,
     mut level_events: EventWriter<LevelEvent>,
 ) {
-    for (world_entity, level_set, children, ldtk_asset_handle, respawn) in ldtk_world_query.iter() {
+    for (world_entity, level_set, children, ldtk_asset_handle, respawn, level_offsets) in
+        ldtk_world_query.iter()
+    {
         // Only apply level set if the asset has finished loading
         if let Some(project) = ldtk_project_assets.get(ldtk_asset_handle) {
             if let Some(load_state) =
@@ -160,7 +382,11 @@ pub fn apply_level_set(
                 .filter_map(|&iid| project.get_raw_level_by_iid(iid.get()))
                 .map(|level| {
                     level_events.send(LevelEvent::SpawnTriggered(LevelIid::new(level.iid.clone())));
-                    pre_spawn_level(&mut commands, level, &ldtk_settings)
+                    let offset = level_offsets
+                        .map(|level_offsets| level_offsets.get(&LevelIid::new(level.iid.clone())))
+                        .unwrap_or(Vec3::ZERO);
+
+                    pre_spawn_level(&mut commands, level, &ldtk_settings, offset)
                 })
                 .collect::<Vec<_>>();
 
@@ -187,16 +413,96 @@ pub fn apply_level_set(
     }
 }
 
-fn pre_spawn_level(commands: &mut Commands, level: &Level, ldtk_settings: &LdtkSettings) -> Entity {
-    let mut translation = Vec3::ZERO;
+/// Triggers the spawning of levels for [LdtkLevelBundle]s once their project has loaded.
+///
+/// The level entity gets the [LevelIid] of its level, and the [LevelProject] that it belongs to,
+/// so that it is processed and respawned like the levels of an [LdtkWorldBundle].
+#[allow(clippy::type_complexity)]
+pub fn apply_ldtk_level_handles(
+    mut commands: Commands,
+    level_query: Query<(
+        Entity,
+        &Handle<LdtkLevel>,
+        Option<&LevelIid>,
+        Option<&LevelProject>,
+        Option<&Name>,
+    )>,
+    ldtk_level_assets: Res<Assets<LdtkLevel>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (level_entity, level_handle, level_iid, level_project, name) in level_query.iter() {
+        let Some(ldtk_level) = ldtk_level_assets.get(level_handle) else {
+            continue;
+        };
+
+        if level_iid == Some(ldtk_level.iid()) {
+            continue;
+        }
+
+        let Some(project_path) = asset_server
+            .get_path(level_handle.id())
+            .map(|path| path.without_label().into_owned())
+        else {
+            continue;
+        };
+
+        // Loading only a level doesn't keep a handle to its project, so the project is loaded by
+        // path, which reuses it if it is still loaded.
+        // The level keeps the handle while the project loads, so it isn't dropped in between.
+        let project_handle = match level_project {
+            Some(LevelProject(project_handle))
+                if asset_server.get_path(project_handle.id()).as_ref() == Some(&project_path) =>
+            {
+                project_handle.clone()
+            }
+            _ => {
+                let project_handle = asset_server.load::<LdtkProject>(project_path);
+                commands
+                    .entity(level_entity)
+                    .insert(LevelProject(project_handle.clone()));
+                project_handle
+            }
+        };
+
+        if let Some(load_state) = asset_server.get_recursive_dependency_load_state(&project_handle)
+        {
+            if load_state != RecursiveDependencyLoadState::Loaded {
+                continue;
+            }
+        }
+
+        let mut entity_commands = commands.entity(level_entity);
+
+        entity_commands.insert(ldtk_level.iid().clone());
+
+        if name.is_none() {
+            entity_commands.insert(ldtk_name(ldtk_level.identifier(), ldtk_level.iid().get()));
+        }
+
+        if level_iid.is_some() {
+            entity_commands.insert(Respawn);
+        }
+    }
+}
+
+fn pre_spawn_level(
+    commands: &mut Commands,
+    level: &Level,
+    ldtk_settings: &LdtkSettings,
+    offset: Vec3,
+) -> Entity {
+    let mut translation = offset + ldtk_settings.world_depth.offset(level.world_depth);
 
     if let LevelSpawnBehavior::UseWorldTranslation { .. } = ldtk_settings.level_spawn_behavior {
         let level_coords = ldtk_pixel_coords_to_translation(
-            IVec2::new(level.world_x, level.world_y + level.px_hei),
+            IVec2::new(
+                level.world_x,
+                level.world_y + ldtk_settings.level_origin.ldtk_pixel_height(level.px_hei),
+            ),
             0,
         );
-        translation.x = level_coords.x;
-        translation.y = level_coords.y;
+        translation.x += level_coords.x;
+        translation.y += level_coords.y;
     }
 
     commands
@@ -205,51 +511,110 @@ fn pre_spawn_level(commands: &mut Commands, level: &Level, ldtk_settings: &LdtkS
             transform: Transform::from_translation(translation),
             ..default()
         })
-        .insert(Name::new(level.identifier.clone()))
+        .insert(ldtk_name(&level.identifier, &level.iid))
         .id()
 }
 
+/// Sets the samplers of tileset images according to [LdtkSettings::tileset_sampler].
+///
+/// Images are only accessed mutably if their sampler needs to change, so they aren't re-uploaded
+/// every time a level spawns.
+#[cfg(feature = "render")]
+fn apply_tileset_samplers(
+    images: &mut Assets<Image>,
+    tileset_map: &HashMap<i32, Handle<Image>>,
+    tileset_definition_map: &HashMap<i32, &TilesetDefinition>,
+    ldtk_settings: &LdtkSettings,
+) {
+    for (uid, handle) in tileset_map {
+        let Some(filter) = tileset_definition_map
+            .get(uid)
+            .and_then(|tileset_definition| {
+                ldtk_settings
+                    .tileset_sampler
+                    .filter_for(&tileset_definition.identifier)
+            })
+        else {
+            continue;
+        };
+
+        if images
+            .get(handle)
+            .is_some_and(|image| !filter.is_used_by(&image.sampler))
+        {
+            if let Some(image) = images.get_mut(handle) {
+                image.sampler = filter.sampler();
+            }
+        }
+    }
+}
+
 /// Performs all the spawning of levels, layers, chunks, bundles, entities, tiles, etc. when a
 /// LevelIid is added or respawned.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn process_ldtk_levels(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    images: ResMut<Assets<Image>>,
+    #[cfg_attr(not(feature = "render"), allow(unused_mut))] mut images: ResMut<Assets<Image>>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     ldtk_project_assets: Res<Assets<LdtkProject>>,
     #[cfg(feature = "external_levels")] level_assets: Res<Assets<LdtkExternalLevel>>,
     ldtk_entity_map: NonSend<LdtkEntityMap>,
     ldtk_int_cell_map: NonSend<LdtkIntCellMap>,
-    ldtk_query: Query<&Handle<LdtkProject>>,
+    ldtk_entity_hooks: Res<LdtkEntityHooks>,
+    ldtk_query: Query<(&Handle<LdtkProject>, Option<&LevelSpawnMode>)>,
     level_query: Query<
         (
             Entity,
             &LevelIid,
-            &Parent,
+            Option<&Parent>,
             Option<&Respawn>,
-            Option<&Children>,
+            Option<&PendingCompositeImage>,
+            Option<&LevelSpawnMode>,
+            Option<&LevelProject>,
         ),
-        Or<(Added<LevelIid>, With<Respawn>)>,
+        Or<(Added<LevelIid>, With<Respawn>, With<PendingCompositeImage>)>,
     >,
-    worldly_query: Query<&Worldly>,
+    worldly_query: Query<(&Worldly, &WorldlyOf)>,
     mut level_events: EventWriter<LevelEvent>,
-    ldtk_settings: Res<LdtkSettings>,
+    // Grouped to stay within the system parameter limit
+    (ldtk_settings, level_patches, tileset_map): (
+        Res<LdtkSettings>,
+        Res<LevelPatches>,
+        Res<TilesetMap>,
+    ),
+    mut level_spawn_generations: ResMut<LevelSpawnGenerations>,
 ) {
-    for (ldtk_entity, level_iid, parent, respawn, children) in level_query.iter() {
-        // Checking if the level has any children is an okay method of checking whether it has
-        // already been processed.
-        // Users will most likely not be adding children to the level entity betwen its creation
-        // and its processing.
-        //
-        // Furthermore, there are no circumstances where an already-processed level entity needs to
-        // be processed again.
+    for (
+        ldtk_entity,
+        level_iid,
+        parent,
+        respawn,
+        pending_composite_image,
+        level_spawn_mode,
+        level_project,
+    ) in level_query.iter()
+    {
+        // Levels are only processed once per generation, so repeated spawn requests coalesce.
         // In the case of respawning levels, the level entity will have its descendants *despawned*
-        // first, by a separate system.
-        let already_processed = matches!(children, Some(children) if !children.is_empty());
+        // first, and its generation incremented, by a separate system.
+        let already_processed = level_spawn_generations.is_spawned(ldtk_entity, level_iid);
 
         if !already_processed {
-            if let Ok(ldtk_handle) = ldtk_query.get(parent.get()) {
+            let world = parent.and_then(|parent| ldtk_query.get(parent.get()).ok());
+
+            // Levels spawned by an LdtkLevelBundle have their own project
+            let ldtk_handle = level_project
+                .map(|LevelProject(ldtk_handle)| ldtk_handle)
+                .or(world.map(|(ldtk_handle, _)| ldtk_handle));
+
+            // Levels and worlds can override the spawn mode of the settings
+            let level_spawn_mode = level_spawn_mode
+                .or(world.and_then(|(_, world_spawn_mode)| world_spawn_mode))
+                .copied()
+                .unwrap_or(ldtk_settings.level_spawn_mode);
+
+            if let Some(ldtk_handle) = ldtk_handle {
                 if let Some(ldtk_project) = ldtk_project_assets.get(ldtk_handle) {
                     // Commence the spawning
                     let tileset_definition_map: HashMap<i32, &TilesetDefinition> = ldtk_project
@@ -268,7 +633,35 @@ pub fn process_ldtk_levels(
 
                     let int_grid_image_handle = &ldtk_project.int_grid_image_handle();
 
-                    let worldly_set = worldly_query.iter().cloned().collect();
+                    // Tileset infos are cached in the TilesetMap, unless the project was only
+                    // just added.
+                    let tileset_infos = match tileset_map.tilesets(ldtk_handle) {
+                        Some(tileset_infos) => Cow::Borrowed(tileset_infos),
+                        None => Cow::Owned(ldtk_project.tileset_infos()),
+                    };
+
+                    #[cfg(feature = "render")]
+                    apply_tileset_samplers(
+                        &mut images,
+                        ldtk_project.tileset_map(),
+                        &tileset_definition_map,
+                        &ldtk_settings,
+                    );
+
+                    // Only the worldly entities belonging to this level's world are considered.
+                    // This allows the same project to be spawned by multiple worlds at once.
+                    let world_entity = if level_project.is_some() {
+                        Some(ldtk_entity)
+                    } else {
+                        parent.map(Parent::get)
+                    };
+                    let worldly_set = worldly_query
+                        .iter()
+                        .filter(|(_, WorldlyOf(worldly_world))| {
+                            Some(*worldly_world) == world_entity
+                        })
+                        .map(|(worldly, _)| worldly.clone())
+                        .collect();
 
                     let maybe_level_data = match ldtk_project.data() {
                         #[cfg(feature = "internal_levels")]
@@ -296,24 +689,77 @@ pub fn process_ldtk_levels(
                     };
 
                     if let Some((level_metadata, loaded_level)) = maybe_level_data {
+                        // Only rendered levels can use the composite image.
+                        // Levels waiting for it reuse their handle, since loading a failed asset
+                        // again would restart the load.
+                        let composite_image = match pending_composite_image {
+                            Some(PendingCompositeImage(composite_image)) => {
+                                Some(composite_image.clone())
+                            }
+                            None => (cfg!(feature = "render")
+                                && level_spawn_mode == LevelSpawnMode::Full
+                                && ldtk_project.json_data().simplified_export
+                                && ldtk_settings
+                                    .simplified_level_rendering
+                                    .renders_composite(loaded_level.identifier()))
+                            .then(|| asset_server.get_path(ldtk_handle))
+                            .flatten()
+                            .map(|project_path| {
+                                asset_server.load::<Image>(
+                                    AssetPath::from(simplified_composite_image_path(
+                                        project_path.path(),
+                                        loaded_level.identifier(),
+                                    ))
+                                    .with_source(project_path.source().clone_owned()),
+                                )
+                            }),
+                        };
+
+                        // The composite image replaces the tile layers, so the level waits for it
+                        // to load rather than spawning without either.
+                        // Failures are reported by spawn_level, which then spawns the tile layers.
+                        if let Some(composite_image) = &composite_image {
+                            if matches!(
+                                asset_server.load_state(composite_image),
+                                LoadState::NotLoaded | LoadState::Loading
+                            ) {
+                                commands
+                                    .entity(ldtk_entity)
+                                    .insert(PendingCompositeImage(composite_image.clone()));
+                                continue;
+                            }
+                        }
+
+                        if pending_composite_image.is_some() {
+                            commands
+                                .entity(ldtk_entity)
+                                .remove::<PendingCompositeImage>();
+                        }
+
                         spawn_level(
                             loaded_level,
                             level_metadata.bg_image(),
+                            composite_image.as_ref(),
                             &mut commands,
                             &asset_server,
                             &images,
                             &mut texture_atlases,
                             &ldtk_entity_map,
                             &ldtk_int_cell_map,
+                            &ldtk_entity_hooks,
                             &entity_definition_map,
                             &layer_definition_map,
                             ldtk_project.tileset_map(),
                             &tileset_definition_map,
+                            &tileset_infos,
                             int_grid_image_handle,
                             worldly_set,
                             ldtk_entity,
                             &ldtk_settings,
+                            level_spawn_mode,
+                            level_patches.get(level_iid),
                         );
+                        level_spawn_generations.mark_spawned(ldtk_entity, level_iid);
                         level_events.send(LevelEvent::Spawned(LevelIid::new(
                             loaded_level.iid().clone(),
                         )));
@@ -381,26 +827,75 @@ pub fn clean_respawn_entities(world: &mut World) {
 
     for entity in entities_to_despawn_descendants {
         world.entity_mut(entity).despawn_descendants();
+
+        if let Some(mut level_spawn_generations) = world.get_resource_mut::<LevelSpawnGenerations>()
+        {
+            level_spawn_generations.respawn(entity);
+        }
+    }
+}
+
+/// Forgets the spawn generations of despawned levels.
+pub fn forget_despawned_levels(
+    mut removed_levels: RemovedComponents<LevelIid>,
+    mut level_spawn_generations: ResMut<LevelSpawnGenerations>,
+) {
+    for level_entity in removed_levels.read() {
+        level_spawn_generations.remove(level_entity);
     }
 }
 
 /// Implements the functionality for `Worldly` components.
+///
+/// Worldly entities are adopted by the parent of their level, which is the world for levels of an
+/// [LdtkWorldBundle].
+/// Worldly entities of levels without a parent become root entities.
+///
+/// Either way, they get a [WorldlyOf] component holding their world.
+#[allow(clippy::type_complexity)]
 pub fn worldly_adoption(
     mut commands: Commands,
     ancestors: Query<&Parent>,
+    level_query: Query<(Option<&Parent>, Has<LevelProject>), With<LevelIid>>,
+    world_query: Query<(), With<Handle<LdtkProject>>>,
     worldly_query: Query<Entity, Added<Worldly>>,
 ) {
     for worldly_entity in worldly_query.iter() {
-        // world entity for this worldly entity is its third ancestor...
-        // - first ancestor is the layer entity
-        // - second ancestor is the level entity
-        // - third ancestor is the world entity
-        if let Some(world_entity) = ancestors.iter_ancestors(worldly_entity).nth(2) {
+        let level = ancestors
+            .iter_ancestors(worldly_entity)
+            .find_map(|ancestor| Some((ancestor, level_query.get(ancestor).ok()?)));
+
+        let world_entity = match level {
+            Some((level_entity, (level_parent, standalone))) => {
+                match level_parent {
+                    Some(level_parent) => {
+                        commands
+                            .entity(worldly_entity)
+                            .set_parent_in_place(level_parent.get());
+                    }
+                    None => {
+                        commands.entity(worldly_entity).remove_parent_in_place();
+                    }
+                }
+
+                // Levels spawned on their own stand in for the world
+                if standalone {
+                    Some(level_entity)
+                } else {
+                    level_parent.map(Parent::get)
+                }
+            }
+            // Entities spawned outside of a level, like with LdtkCommandsExt, are left where they
+            // are
+            None => ancestors
+                .iter_ancestors(worldly_entity)
+                .find(|ancestor| world_query.contains(*ancestor)),
+        };
+
+        if let Some(world_entity) = world_entity {
             commands
                 .entity(worldly_entity)
-                .set_parent_in_place(world_entity);
-        } else {
-            commands.entity(worldly_entity).remove_parent_in_place();
+                .insert(WorldlyOf(world_entity));
         }
     }
 }
@@ -430,3 +925,255 @@ pub fn fire_level_transformed_events(
         writer.send(LevelEvent::Transformed(id));
     }
 }
+
+/// Inserts [AnimatedTile]s on tiles whose [TileMetadata] describes a [TileAnimation], if enabled
+/// by [LdtkSettings::tile_animations].
+///
+/// [AnimatedTile]: bevy_ecs_tilemap::tiles::AnimatedTile
+pub fn insert_tile_animations(
+    mut commands: Commands,
+    ldtk_settings: Res<LdtkSettings>,
+    tile_query: Query<(Entity, &TileMetadata), Added<TileMetadata>>,
+) {
+    if ldtk_settings.tile_animations != TileAnimations::FromCustomData {
+        return;
+    }
+
+    for (entity, tile_metadata) in tile_query.iter() {
+        let Some(tile_animation) = TileAnimation::from_custom_data(&tile_metadata.data) else {
+            continue;
+        };
+
+        match tile_animation.animated_tile() {
+            Some(animated_tile) => {
+                commands.entity(entity).insert(animated_tile);
+            }
+            None => warn!(
+                "tile animation frames {:?} are not consecutive tile ids, skipping",
+                tile_animation.frames
+            ),
+        }
+    }
+}
+
+/// Inserts [TileCollisionShape]s on tiles whose [TileMetadata] describes one.
+pub fn insert_tile_collision_shapes(
+    mut commands: Commands,
+    tile_query: Query<(Entity, &TileMetadata), Added<TileMetadata>>,
+) {
+    for (entity, tile_metadata) in tile_query.iter() {
+        if let Some(collision_shape) = TileCollisionShape::from_custom_data(&tile_metadata.data) {
+            commands.entity(entity).insert(collision_shape);
+        }
+    }
+}
+
+/// Cycles the texture atlas index of entities with a [SpriteSheetAnimation].
+pub fn animate_sprite_sheets(
+    time: Res<Time>,
+    mut sprite_query: Query<(&SpriteSheetAnimation, &mut TextureAtlas)>,
+) {
+    let elapsed_seconds = time.elapsed_seconds();
+
+    for (animation, mut texture_atlas) in sprite_query.iter_mut() {
+        if let Some(frame) = animation.frame_at(elapsed_seconds) {
+            if texture_atlas.index != frame {
+                texture_atlas.index = frame;
+            }
+        }
+    }
+}
+
+/// Applies newly-added [TransformFromFields] to their entity's [Transform].
+pub fn apply_transform_from_fields(
+    mut entity_query: Query<(&TransformFromFields, &mut Transform), Added<TransformFromFields>>,
+) {
+    for (transform_from_fields, mut transform) in entity_query.iter_mut() {
+        transform_from_fields.apply(&mut transform);
+    }
+}
+
+/// Updates the z translation of [YSort] entities from their y translation, if [YSorting] is
+/// enabled.
+#[allow(clippy::type_complexity)]
+pub fn y_sort(
+    ldtk_settings: Res<LdtkSettings>,
+    mut entity_query: Query<(&YSort, &mut Transform), Or<(Changed<Transform>, Changed<YSort>)>>,
+) {
+    if ldtk_settings.y_sorting == YSorting::Disabled {
+        return;
+    }
+
+    for (y_sort, mut transform) in entity_query.iter_mut() {
+        if let Some(z) = ldtk_settings
+            .y_sorting
+            .z(transform.translation.y + y_sort.y_offset)
+        {
+            // Avoid triggering change detection when the z is already correct
+            if transform.translation.z != z {
+                transform.translation.z = z;
+            }
+        }
+    }
+}
+
+/// Returns the world transform of an entity from the [Transform]s of it and its ancestors.
+///
+/// Unlike its [GlobalTransform], this is accurate before transforms have been propagated.
+fn compose_ancestor_transforms(
+    entity: Entity,
+    transform_query: &Query<(&Transform, Option<&Parent>), Without<LevelTraveler>>,
+) -> Option<GlobalTransform> {
+    let mut global_transform = GlobalTransform::IDENTITY;
+    let mut next = Some(entity);
+
+    while let Some(entity) = next {
+        let (transform, parent) = transform_query.get(entity).ok()?;
+        global_transform = GlobalTransform::from(*transform) * global_transform;
+        next = parent.map(Parent::get);
+    }
+
+    Some(global_transform)
+}
+
+/// Moves [LevelTraveler] entities to the spawn point of a pending [LevelTransition] once it has
+/// spawned.
+///
+/// The spawn point is the entity with the transition's `pending_spawn_point` iid, or otherwise the
+/// [SpawnPoint] of its `pending_level`.
+/// Runs before transform propagation, so travelers are never drawn at their old position in the
+/// new level.
+#[allow(clippy::type_complexity)]
+pub fn apply_level_transition_spawn_points(
+    mut level_transition: ResMut<LevelTransition>,
+    spawn_point_query: Query<(Entity, &EntityIid, Option<&SpawnPoint>)>,
+    transform_query: Query<(&Transform, Option<&Parent>), Without<LevelTraveler>>,
+    mut traveler_query: Query<(&mut Transform, Option<&Parent>), With<LevelTraveler>>,
+) {
+    let Some(pending_level) = &level_transition.pending_level else {
+        return;
+    };
+
+    let Some((spawn_point_entity, ..)) =
+        spawn_point_query
+            .iter()
+            .find(
+                |(_, entity_iid, spawn_point)| match &level_transition.pending_spawn_point {
+                    Some(pending_spawn_point) => *entity_iid == pending_spawn_point,
+                    None => spawn_point
+                        .is_some_and(|spawn_point| spawn_point.level_iid == *pending_level),
+                },
+            )
+    else {
+        return;
+    };
+
+    let Some(spawn_point_transform) =
+        compose_ancestor_transforms(spawn_point_entity, &transform_query)
+    else {
+        return;
+    };
+
+    let spawn_point_translation = spawn_point_transform.translation();
+
+    for (mut transform, parent) in traveler_query.iter_mut() {
+        let translation = match parent
+            .and_then(|parent| compose_ancestor_transforms(parent.get(), &transform_query))
+        {
+            Some(parent_transform) => parent_transform
+                .affine()
+                .inverse()
+                .transform_point3(spawn_point_translation),
+            None => spawn_point_translation,
+        };
+
+        transform.translation.x = translation.x;
+        transform.translation.y = translation.y;
+    }
+
+    *level_transition = LevelTransition::default();
+}
+
+/// Fires [SpawnPointEvent]s for the [SpawnPoint]s of levels that were transformed this update.
+pub fn fire_spawn_point_events(
+    mut level_events: EventReader<LevelEvent>,
+    mut spawn_point_events: EventWriter<SpawnPointEvent>,
+    spawn_points: SpawnPointQuery,
+) {
+    for level_event in level_events.read() {
+        let LevelEvent::Transformed(level_iid) = level_event else {
+            continue;
+        };
+
+        if let (Some(entity), Some(translation)) = (
+            spawn_points.entity(level_iid),
+            spawn_points.translation(level_iid),
+        ) {
+            spawn_point_events.send(SpawnPointEvent {
+                level_iid: level_iid.clone(),
+                entity,
+                translation,
+            });
+        }
+    }
+}
+
+/// Despawns the tiles of an LDtk layer whose [TileStorage] is being removed, e.g. because the
+/// layer entity is despawning.
+///
+/// Tile entities are usually children of their layer, but this also catches tiles that have been
+/// re-parented or were never parented, so no tile data outlives its tilemap.
+/// If only the storage was removed, the layer's texture and material are removed as well, so their
+/// assets are released.
+/// The render data of the tilemap's chunks is released by `bevy_ecs_tilemap` itself.
+///
+/// Tilemaps that weren't spawned by this plugin are left alone.
+pub fn despawn_tilemap_tiles(
+    trigger: Trigger<OnRemove, TileStorage>,
+    mut commands: Commands,
+    storage_query: Query<&TileStorage, With<LayerMetadata>>,
+) {
+    let Ok(storage) = storage_query.get(trigger.entity()) else {
+        return;
+    };
+
+    let layer_entity = trigger.entity();
+    let tile_entities = storage.iter().flatten().copied().collect::<Vec<_>>();
+
+    commands.add(move |world: &mut World| {
+        for tile_entity in tile_entities {
+            if let Some(tile_entity) = world.get_entity_mut(tile_entity) {
+                tile_entity.despawn_recursive();
+            }
+        }
+
+        if let Some(mut layer_entity) = world.get_entity_mut(layer_entity) {
+            layer_entity.remove::<TilemapTexture>();
+
+            #[cfg(feature = "render")]
+            layer_entity.remove::<Handle<bevy_ecs_tilemap::prelude::StandardTilemapMaterial>>();
+        }
+    });
+}
+
+/// Adds LDtk entities to the [LdtkEntityIndex] when their [EntityIid] is inserted.
+///
+/// This also runs when an existing [EntityIid] is replaced, moving the entity to its new iid.
+pub fn index_ldtk_entity(
+    trigger: Trigger<OnInsert, EntityIid>,
+    mut entity_index: ResMut<LdtkEntityIndex>,
+    entity_iid_query: Query<&EntityIid>,
+) {
+    if let Ok(entity_iid) = entity_iid_query.get(trigger.entity()) {
+        entity_index.insert(entity_iid.clone(), trigger.entity());
+    }
+}
+
+/// Removes LDtk entities from the [LdtkEntityIndex] when their [EntityIid] is removed, e.g.
+/// because they are despawning.
+pub fn unindex_ldtk_entity(
+    trigger: Trigger<OnRemove, EntityIid>,
+    mut entity_index: ResMut<LdtkEntityIndex>,
+) {
+    entity_index.remove(trigger.entity());
+}
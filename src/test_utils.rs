@@ -0,0 +1,256 @@
+//! Helpers for testing level spawning in a headless bevy [App].
+//!
+//! Requires the `test-utils` feature to be enabled.
+//!
+//! These are intended for integration tests, both in this repository and in crates that depend on
+//! `bevy_ecs_ldtk`.
+//! A typical test creates an app with [headless_app], spawns a project with [spawn_project], waits
+//! for a level with [update_until_level_spawned], and then makes assertions about the spawned
+//! entities, possibly with [snapshot_hierarchy].
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_ecs_ldtk::{prelude::*, test_utils::*};
+//! use std::time::Duration;
+//!
+//! let mut app = headless_app("tests/fixtures");
+//! app.insert_resource(LevelSelection::index(0));
+//!
+//! let world_entity = spawn_project(&mut app, "simple.ldtk");
+//! update_until_level_spawned(&mut app, Duration::from_secs(5)).expect("level should spawn");
+//!
+//! println!("{}", snapshot_hierarchy(app.world(), world_entity));
+//! ```
+use crate::{
+    components::{LdtkWorldBundle, LevelIid},
+    plugin::LdtkPlugin,
+    resources::LevelEvent,
+};
+use bevy::{
+    asset::AssetPath,
+    ecs::event::ManualEventReader,
+    prelude::*,
+    utils::{Duration, Instant},
+};
+use std::fmt::Write;
+
+#[cfg(feature = "internal_levels")]
+use crate::{assets::LdtkProject, ldtk::LdtkJson};
+#[cfg(feature = "internal_levels")]
+use std::collections::HashMap;
+
+/// Creates a headless [App] with [LdtkPlugin] and the minimal set of plugins it needs.
+///
+/// Assets are loaded from `asset_folder`, which is relative to the crate root when run by cargo,
+/// like the `assets` folder usually is.
+///
+/// No window or renderer is created, so levels can be spawned in tests and CI.
+pub fn headless_app(asset_folder: impl Into<String>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: asset_folder.into(),
+            ..default()
+        },
+        HierarchyPlugin,
+        TransformPlugin,
+    ));
+
+    // Usually added by the render plugins, but needed by LdtkPlugin's tilemap rendering.
+    // The empty render sub-app never extracts anything, so nothing is actually rendered.
+    #[cfg(feature = "render")]
+    app.init_asset::<Shader>()
+        .init_asset::<Image>()
+        .init_asset::<TextureAtlasLayout>()
+        .init_resource::<ClearColor>()
+        .insert_sub_app(bevy::render::RenderApp, SubApp::new());
+
+    app.add_plugins(LdtkPlugin);
+
+    app
+}
+
+/// Loads the LDtk project at the given asset path, and spawns an [LdtkWorldBundle] for it.
+///
+/// Returns the world entity.
+/// Levels spawn once the project and its dependencies have loaded, according to the
+/// [LevelSelection] resource or the world's [LevelSet].
+///
+/// Tileset and background images are loaded as dependencies of the project, so fixtures should
+/// avoid them unless an image loader is registered to the app.
+///
+/// [LevelSelection]: crate::resources::LevelSelection
+/// [LevelSet]: crate::components::LevelSet
+pub fn spawn_project(app: &mut App, path: impl Into<AssetPath<'static>>) -> Entity {
+    let ldtk_handle = app.world().resource::<AssetServer>().load(path.into());
+
+    app.world_mut()
+        .spawn(LdtkWorldBundle {
+            ldtk_handle,
+            ..default()
+        })
+        .id()
+}
+
+/// Adds an [LdtkProject] built from the given project data, and spawns an [LdtkWorldBundle]
+/// for it.
+///
+/// Useful for generating projects in tests instead of maintaining fixture files.
+/// Since there is no asset path to resolve them against, tileset images are not loaded.
+///
+/// Returns the world entity.
+///
+/// # Panics
+/// Panics if the project data uses external levels, or can't be loaded for any other reason.
+#[cfg(feature = "internal_levels")]
+pub fn spawn_project_from_json(app: &mut App, ldtk_json: &LdtkJson) -> Entity {
+    let bytes = serde_json::to_vec(ldtk_json).expect("project data should serialize");
+
+    let project = {
+        let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+        LdtkProject::from_bytes(&bytes, &HashMap::new(), &mut images)
+            .expect("project data should be loadable")
+    };
+
+    let ldtk_handle = app
+        .world_mut()
+        .resource_mut::<Assets<LdtkProject>>()
+        .add(project);
+
+    app.world_mut()
+        .spawn(LdtkWorldBundle {
+            ldtk_handle,
+            ..default()
+        })
+        .id()
+}
+
+/// Updates the app until any [LevelEvent::Spawned] is sent, returning the iid of the level.
+///
+/// Returns `None` if no level spawns before the `timeout`.
+/// Since assets are loaded on other threads, this keeps updating for wall-clock time rather than
+/// for a fixed number of updates.
+pub fn update_until_level_spawned(app: &mut App, timeout: Duration) -> Option<LevelIid> {
+    update_until_levels_spawned(app, 1, timeout).and_then(|mut level_iids| level_iids.pop())
+}
+
+/// Updates the app until `count` [LevelEvent::Spawned] events are sent, returning the iids of the
+/// levels in the order they spawned.
+///
+/// Returns `None` if fewer levels spawn before the `timeout`.
+pub fn update_until_levels_spawned(
+    app: &mut App,
+    count: usize,
+    timeout: Duration,
+) -> Option<Vec<LevelIid>> {
+    let mut reader: ManualEventReader<LevelEvent> = app
+        .world()
+        .resource::<Events<LevelEvent>>()
+        .get_reader_current();
+
+    let mut spawned = Vec::new();
+    let start = Instant::now();
+
+    while start.elapsed() < timeout {
+        app.update();
+
+        let events = app.world().resource::<Events<LevelEvent>>();
+        spawned.extend(reader.read(events).filter_map(|event| match event {
+            LevelEvent::Spawned(level_iid) => Some(level_iid.clone()),
+            _ => None,
+        }));
+
+        if spawned.len() >= count {
+            return Some(spawned);
+        }
+
+        std::thread::yield_now();
+    }
+
+    None
+}
+
+fn snapshot_label(world: &World, entity: Entity) -> String {
+    world
+        .get::<Name>(entity)
+        .map(|name| name.as_str().to_string())
+        .unwrap_or_else(|| "<unnamed>".to_string())
+}
+
+fn write_snapshot(world: &World, entity: Entity, depth: usize, snapshot: &mut String) {
+    let indent = "  ".repeat(depth);
+    writeln!(snapshot, "{indent}{}", snapshot_label(world, entity)).unwrap();
+
+    let Some(children) = world.get::<Children>(entity) else {
+        return;
+    };
+
+    // Leaf children with the same label, like tiles, are collapsed into a single line.
+    let mut leaf_counts: Vec<(String, usize)> = Vec::new();
+
+    for &child in children.iter() {
+        if world.get::<Children>(child).is_some() {
+            write_snapshot(world, child, depth + 1, snapshot);
+            continue;
+        }
+
+        let label = snapshot_label(world, child);
+        match leaf_counts
+            .iter_mut()
+            .find(|(existing, _)| *existing == label)
+        {
+            Some((_, count)) => *count += 1,
+            None => leaf_counts.push((label, 1)),
+        }
+    }
+
+    for (label, count) in leaf_counts {
+        let indent = "  ".repeat(depth + 1);
+        match count {
+            1 => writeln!(snapshot, "{indent}{label}").unwrap(),
+            _ => writeln!(snapshot, "{indent}{label} x{count}").unwrap(),
+        }
+    }
+}
+
+/// Returns a text snapshot of the hierarchy below `root`, with one line per entity showing its
+/// [Name].
+///
+/// Children are indented below their parent.
+/// Leaf entities with the same name under the same parent, like the tiles of a layer, are
+/// collapsed into one line with a count, and listed after the children that have their own
+/// children.
+/// The snapshot is deterministic for a given project, so it can be compared against an expected
+/// string to catch regressions in spawning.
+pub fn snapshot_hierarchy(world: &World, root: Entity) -> String {
+    let mut snapshot = String::new();
+    write_snapshot(world, root, 0, &mut snapshot);
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_collapses_identical_leaves() {
+        let mut world = World::new();
+
+        let root = world.spawn(Name::new("World")).id();
+        let level = world.spawn(Name::new("Level_0")).id();
+        let layer = world.spawn(Name::new("Collision")).id();
+        let tiles: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+        let player = world.spawn(Name::new("Player")).id();
+
+        world.entity_mut(root).add_child(level);
+        world.entity_mut(level).add_child(layer);
+        world.entity_mut(layer).push_children(&tiles);
+        world.entity_mut(level).add_child(player);
+
+        assert_eq!(
+            snapshot_hierarchy(&world, root),
+            "World\n  Level_0\n    Collision\n      <unnamed> x3\n    Player\n"
+        );
+    }
+}
@@ -236,6 +236,33 @@ pub(crate) fn tile_pos_to_transparent_tile_maker(
     }
 }
 
+/// Returns a tile maker that multiplies the color of the provided tile maker's tiles with the
+/// given tint.
+///
+/// Used for applying layer tints to Tile, AutoTile, and IntGrid layers.
+pub(crate) fn tile_pos_to_tinted_tile_maker(
+    mut tile_maker: impl FnMut(TilePos) -> Option<TileBundle>,
+    tint: Option<Color>,
+) -> impl FnMut(TilePos) -> Option<TileBundle> {
+    move |tile_pos: TilePos| -> Option<TileBundle> {
+        match tint {
+            Some(tint) => tile_maker(tile_pos).map(|mut tile| {
+                let color = tile.color.0.to_srgba();
+                let tint = tint.to_srgba();
+
+                tile.color.0 = Color::srgba(
+                    color.red * tint.red,
+                    color.green * tint.green,
+                    color.blue * tint.blue,
+                    color.alpha * tint.alpha,
+                );
+                tile
+            }),
+            None => tile_maker(tile_pos),
+        }
+    }
+}
+
 /// Returns a tile bundle maker that returns the bundled result of the provided tile maker.
 ///
 /// Used for spawning Tile, AutoTile, and IntGrid layers.
@@ -470,4 +497,29 @@ mod tests {
             css::RED.into()
         );
     }
+
+    #[test]
+    fn test_tile_pos_to_tinted_tile_maker() {
+        let tile_maker = |_: TilePos| {
+            Some(TileBundle {
+                color: TileColor(Color::srgba(1., 0.5, 1., 0.5)),
+                ..default()
+            })
+        };
+
+        let mut tinted_tile_maker =
+            tile_pos_to_tinted_tile_maker(tile_maker, Some(Color::srgba(0.5, 0.5, 0., 1.)));
+
+        assert_eq!(
+            tinted_tile_maker(TilePos { x: 0, y: 0 }).unwrap().color.0,
+            Color::srgba(0.5, 0.25, 0., 0.5)
+        );
+
+        let mut untinted_tile_maker = tile_pos_to_tinted_tile_maker(tile_maker, None);
+
+        assert_eq!(
+            untinted_tile_maker(TilePos { x: 0, y: 0 }).unwrap().color.0,
+            Color::srgba(1., 0.5, 1., 0.5)
+        );
+    }
 }
@@ -7,16 +7,20 @@ use crate::{
 };
 
 use crate::{
-    components::{LdtkSpriteSheetBundle, TileGridBundle},
+    components::{LdtkSpriteSheetBundle, LevelBounds, TileGridBundle},
     ldtk::*,
 };
-use bevy::prelude::*;
+use bevy::{ecs::query::QueryFilter, prelude::*};
 use bevy_ecs_tilemap::{
     map::{TilemapId, TilemapSize},
     tiles::{TilePos, TileStorage},
 };
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    path::{Path, PathBuf},
+};
 
 /// The `int_grid_csv` field of a [LayerInstance] is a 1-dimensional [`Vec<i32>`].
 /// This function can map the indices of this [Vec] to a corresponding [GridCoords].
@@ -108,11 +112,20 @@ fn ldtk_coord_conversion_origin_adjusted(coords: IVec2, height: i32) -> IVec2 {
 }
 
 /// Performs LDtk pixel coordinate to translation conversion.
+///
+/// To get translations relative to a spawned level entity, pass the level's height through
+/// [`LevelOrigin::ldtk_pixel_height`] first, so [`LdtkSettings::level_origin`] is respected.
+///
+/// [`LevelOrigin::ldtk_pixel_height`]: crate::resources::LevelOrigin::ldtk_pixel_height
+/// [`LdtkSettings::level_origin`]: crate::resources::LdtkSettings::level_origin
 pub fn ldtk_pixel_coords_to_translation(ldtk_coords: IVec2, ldtk_pixel_height: i32) -> Vec2 {
     ldtk_coord_conversion(ldtk_coords, ldtk_pixel_height).as_vec2()
 }
 
 /// Performs translation to LDtk pixel coordinate conversion.
+///
+/// See [`ldtk_pixel_coords_to_translation`] for converting translations relative to a spawned
+/// level entity.
 pub fn translation_to_ldtk_pixel_coords(translation: Vec2, ldtk_pixel_height: i32) -> IVec2 {
     ldtk_coord_conversion(translation.as_ivec2(), ldtk_pixel_height)
 }
@@ -308,6 +321,62 @@ where
     try_each_optional_permutation(a, b, |x, y| map.get(&(x, y))).unwrap_or(default)
 }
 
+/// Finds the spawned level containing the given point in world space.
+///
+/// If several levels overlap at the point, e.g. when levels are stacked with
+/// [`WorldDepth`](crate::resources::WorldDepth), the one with the greatest z translation is
+/// returned.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_ecs_ldtk::{prelude::*, utils::level_containing_point};
+/// # #[derive(Component)]
+/// # struct Player;
+/// fn follow_player_into_levels(
+///     player_query: Query<&GlobalTransform, With<Player>>,
+///     level_query: Query<(Entity, &LevelBounds, &GlobalTransform)>,
+///     iid_query: Query<&LevelIid>,
+///     mut level_selection: ResMut<LevelSelection>,
+/// ) {
+///     let Ok(player_transform) = player_query.get_single() else {
+///         return;
+///     };
+///
+///     if let Some(level_entity) =
+///         level_containing_point(&level_query, player_transform.translation().truncate())
+///     {
+///         if let Ok(level_iid) = iid_query.get(level_entity) {
+///             level_selection.set_if_neq(LevelSelection::Iid(level_iid.clone()));
+///         }
+///     }
+/// }
+/// ```
+pub fn level_containing_point<F: QueryFilter>(
+    level_query: &Query<(Entity, &LevelBounds, &GlobalTransform), F>,
+    point: Vec2,
+) -> Option<Entity> {
+    level_query
+        .iter()
+        .filter(|(_, level_bounds, level_transform)| {
+            level_bounds.contains_point(level_transform, point)
+        })
+        .max_by(|(_, _, a), (_, _, b)| a.translation().z.total_cmp(&b.translation().z))
+        .map(|(level_entity, ..)| level_entity)
+}
+
+/// Creates the [Name] given to spawned levels and entities, e.g. `Player (8cb8fd70-...)`.
+///
+/// Identifiers aren't unique, so the `iid` is included to tell instances apart in inspectors.
+/// If the `iid` is empty, only the identifier is used.
+pub(crate) fn ldtk_name(identifier: &str, iid: &str) -> Name {
+    if iid.is_empty() {
+        Name::new(identifier.to_owned())
+    } else {
+        Name::new(format!("{identifier} ({iid})"))
+    }
+}
+
 /// Creates a [`LdtkSpriteSheetBundle`] from the entity information available to the
 /// [LdtkEntity::bundle_entity] method.
 ///
@@ -364,6 +433,8 @@ pub fn sprite_sheet_bundle_from_entity_info(
             }
         }
         _ => {
+            // Tileset images aren't loaded without rendering, so this is expected
+            #[cfg(feature = "render")]
             warn!("EntityInstance needs a tile, an associated tileset, and an associated tileset definition to be bundled as a LdtkSpriteSheetBundle");
             LdtkSpriteSheetBundle::default()
         }
@@ -379,6 +450,8 @@ pub fn sprite_bundle_from_entity_info(tileset: Option<&Handle<Image>>) -> Sprite
     let tileset = match tileset {
         Some(tileset) => tileset.clone(),
         None => {
+            // Tileset images aren't loaded without rendering, so this is expected
+            #[cfg(feature = "render")]
             warn!("EntityInstance needs a tileset to be bundled as a SpriteBundle");
             return SpriteBundle::default();
         }
@@ -390,10 +463,129 @@ pub fn sprite_bundle_from_entity_info(tileset: Option<&Handle<Image>>) -> Sprite
     }
 }
 
+/// Creates a [SpriteBundle] tinted with the entity's smart color from the entity information
+/// available to the [LdtkEntity::bundle_entity] method.
+///
+/// If the entity has no tileset, a placeholder rectangle the size of the entity is created in its
+/// smart color instead.
+///
+/// Used for the `#[sprite_bundle(use_smart_color)]` attribute macro for `#[derive(LdtkEntity)]`.
+/// See [LdtkEntity#sprite_bundle] for more info.
+pub fn smart_color_sprite_bundle_from_entity_info(
+    entity_instance: &EntityInstance,
+    tileset: Option<&Handle<Image>>,
+) -> SpriteBundle {
+    match tileset {
+        Some(tileset) => SpriteBundle {
+            texture: tileset.clone(),
+            sprite: Sprite {
+                color: entity_instance.smart_color,
+                ..default()
+            },
+            ..default()
+        },
+        None => SpriteBundle {
+            sprite: Sprite {
+                color: entity_instance.smart_color,
+                custom_size: Some(Vec2::new(
+                    entity_instance.width as f32,
+                    entity_instance.height as f32,
+                )),
+                ..default()
+            },
+            ..default()
+        },
+    }
+}
+
+/// Loads an image for an entity's sprite.
+///
+/// Without the `render` feature, nothing is loaded and a default handle is returned instead.
+///
+/// Used for the `#[sprite_bundle("asset/path.png")]` attribute macro for `#[derive(LdtkEntity)]`.
+/// See [LdtkEntity#sprite_bundle] for more info.
+#[cfg_attr(not(feature = "render"), allow(unused_variables))]
+pub fn load_entity_image(asset_server: &AssetServer, asset_path: &'static str) -> Handle<Image> {
+    #[cfg(feature = "render")]
+    return asset_server.load(asset_path);
+
+    #[cfg(not(feature = "render"))]
+    return Handle::default();
+}
+
+/// Creates a [`LdtkSpriteSheetBundle`] from an image with a grid of sprites.
+///
+/// Without the `render` feature, nothing is loaded and a default bundle is returned instead.
+///
+/// Used for the `#[sprite_sheet_bundle("asset/path.png", ...)]` attribute macro for
+/// `#[derive(LdtkEntity)]`.
+/// See [LdtkEntity#sprite_sheet_bundle] for more info.
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "render"), allow(unused_variables))]
+pub fn grid_sprite_sheet_bundle(
+    asset_server: &AssetServer,
+    texture_atlases: &mut Assets<TextureAtlasLayout>,
+    asset_path: &'static str,
+    tile_size: UVec2,
+    columns: u32,
+    rows: u32,
+    padding: u32,
+    offset: u32,
+    index: usize,
+) -> LdtkSpriteSheetBundle {
+    #[cfg(feature = "render")]
+    return LdtkSpriteSheetBundle {
+        sprite_bundle: SpriteBundle {
+            texture: asset_server.load(asset_path),
+            ..default()
+        },
+        texture_atlas: TextureAtlas {
+            layout: texture_atlases.add(TextureAtlasLayout::from_grid(
+                tile_size,
+                columns,
+                rows,
+                Some(UVec2::splat(padding)),
+                Some(UVec2::splat(offset)),
+            )),
+            index,
+        },
+    };
+
+    #[cfg(not(feature = "render"))]
+    return LdtkSpriteSheetBundle::default();
+}
+
+/// Returns the path of a level's composite image from LDtk's "super simple export", given the
+/// path of its project file.
+///
+/// LDtk saves these in a directory named after the project file, next to it.
+pub fn simplified_composite_image_path(project_path: &Path, level_identifier: &str) -> PathBuf {
+    let project_dir = project_path.parent().unwrap_or(Path::new(""));
+    let project_name = project_path.file_stem().unwrap_or_default();
+
+    project_dir
+        .join(project_name)
+        .join("simplified")
+        .join(level_identifier)
+        .join("_composite.png")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_simplified_composite_image_path() {
+        assert_eq!(
+            simplified_composite_image_path(Path::new("maps/world.ldtk"), "Level_0"),
+            PathBuf::from("maps/world/simplified/Level_0/_composite.png")
+        );
+        assert_eq!(
+            simplified_composite_image_path(Path::new("world.ldtk"), "Level_1"),
+            PathBuf::from("world/simplified/Level_1/_composite.png")
+        );
+    }
+
     #[test]
     fn test_int_grid_index_to_tile_pos() {
         assert_eq!(
@@ -695,4 +887,67 @@ mod tests {
         assert_eq!(try_each_optional_permutation(4, 4, test_func), Some(4));
         assert_eq!(try_each_optional_permutation(5, 5, test_func), Some(4));
     }
+
+    #[test]
+    fn ldtk_name_includes_iid_if_present() {
+        assert_eq!(ldtk_name("Player", "a1b2c3").as_str(), "Player (a1b2c3)");
+        assert_eq!(ldtk_name("Player", "").as_str(), "Player");
+    }
+
+    #[test]
+    fn sprite_sheet_bundle_without_grid_uses_exact_tile_rect() {
+        let mut texture_atlases = Assets::<TextureAtlasLayout>::default();
+        let tileset = Handle::weak_from_u128(0);
+        let tileset_definition = TilesetDefinition {
+            px_wid: 64,
+            px_hei: 64,
+            c_wid: 4,
+            c_hei: 4,
+            tile_grid_size: 16,
+            ..default()
+        };
+        // Not aligned to the tileset's grid
+        let entity_instance = EntityInstance {
+            tile: Some(TilesetRectangle {
+                x: 5,
+                y: 3,
+                w: 20,
+                h: 12,
+                ..default()
+            }),
+            ..default()
+        };
+
+        let bundle = sprite_sheet_bundle_from_entity_info(
+            &entity_instance,
+            Some(&tileset),
+            Some(&tileset_definition),
+            &mut texture_atlases,
+            false,
+        );
+
+        let layout = texture_atlases
+            .get(&bundle.texture_atlas.layout)
+            .expect("layout should have been added");
+
+        assert_eq!(bundle.texture_atlas.index, 0);
+        assert_eq!(layout.size, UVec2::new(64, 64));
+        assert_eq!(layout.textures, vec![URect::new(5, 3, 25, 15)]);
+    }
+
+    #[test]
+    fn smart_color_sprite_without_tileset_is_entity_sized_placeholder() {
+        let entity_instance = EntityInstance {
+            width: 16,
+            height: 24,
+            smart_color: Color::srgb(1., 0., 0.),
+            ..default()
+        };
+
+        let sprite_bundle = smart_color_sprite_bundle_from_entity_info(&entity_instance, None);
+
+        assert_eq!(sprite_bundle.sprite.color, Color::srgb(1., 0., 0.));
+        assert_eq!(sprite_bundle.sprite.custom_size, Some(Vec2::new(16., 24.)));
+        assert_eq!(sprite_bundle.texture, Handle::default());
+    }
 }
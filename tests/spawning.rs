@@ -0,0 +1,575 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::{prelude::*, test_utils::*};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+const LEVEL_0_IID: &str = "6a4e3b11-1f3c-11ef-9a51-5b1c0d7e2a01";
+const LEVEL_1_IID: &str = "6a4e3b17-1f3c-11ef-9a51-5b1c0d7e2a01";
+
+#[test]
+fn selected_level_spawns_with_entities_and_layers() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID));
+
+    let world_entity = spawn_project(&mut app, "simple.ldtk");
+
+    assert_eq!(
+        update_until_level_spawned(&mut app, TIMEOUT),
+        Some(LevelIid::new(LEVEL_0_IID))
+    );
+
+    let snapshot = snapshot_hierarchy(app.world(), world_entity);
+
+    for line in [
+        format!("Level_0 ({LEVEL_0_IID})"),
+        "Entities".to_string(),
+        "Player (6a4e3b14-1f3c-11ef-9a51-5b1c0d7e2a01)".to_string(),
+        "Coin (6a4e3b15-1f3c-11ef-9a51-5b1c0d7e2a01)".to_string(),
+        "Coin (6a4e3b16-1f3c-11ef-9a51-5b1c0d7e2a01)".to_string(),
+        "Collision".to_string(),
+    ] {
+        assert!(
+            snapshot.lines().any(|l| l.trim() == line),
+            "missing {line:?} in snapshot:\n{snapshot}"
+        );
+    }
+
+    assert!(!snapshot.contains("Level_1"), "snapshot:\n{snapshot}");
+
+    let walls = app
+        .world_mut()
+        .query::<&IntGridCell>()
+        .iter(app.world())
+        .filter(|cell| cell.value == 1)
+        .count();
+    assert_eq!(walls, 8);
+}
+
+#[test]
+fn changing_level_selection_spawns_the_new_level() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID));
+
+    let world_entity = spawn_project(&mut app, "simple.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("first level should spawn");
+
+    app.insert_resource(LevelSelection::iid(LEVEL_1_IID));
+
+    assert_eq!(
+        update_until_level_spawned(&mut app, TIMEOUT),
+        Some(LevelIid::new(LEVEL_1_IID))
+    );
+
+    // Despawning the previous level is deferred to the end of the frame.
+    app.update();
+
+    let snapshot = snapshot_hierarchy(app.world(), world_entity);
+    assert!(
+        snapshot.contains(&format!("Level_1 ({LEVEL_1_IID})")),
+        "snapshot:\n{snapshot}"
+    );
+    assert!(
+        snapshot.contains("Coin (6a4e3b1a-1f3c-11ef-9a51-5b1c0d7e2a01)"),
+        "snapshot:\n{snapshot}"
+    );
+    assert!(!snapshot.contains("Level_0"), "snapshot:\n{snapshot}");
+}
+
+#[test]
+fn worldly_entities_belong_to_their_own_world() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID))
+        .register_ldtk_entity::<Worldly>("Player");
+
+    let world_a = spawn_project(&mut app, "simple.ldtk");
+    let world_b = spawn_project(&mut app, "simple.ldtk");
+    update_until_levels_spawned(&mut app, 2, TIMEOUT).expect("both levels should spawn");
+
+    let worldly_of = |app: &mut App| {
+        let mut worlds: Vec<(Entity, Entity)> = app
+            .world_mut()
+            .query_filtered::<(&WorldlyOf, &Parent), With<Worldly>>()
+            .iter(app.world())
+            .map(|(WorldlyOf(world), parent)| (*world, parent.get()))
+            .collect();
+        worlds.sort();
+        worlds
+    };
+
+    let mut expected = vec![(world_a, world_a), (world_b, world_b)];
+    expected.sort();
+    assert_eq!(worldly_of(&mut app), expected);
+
+    // Respawning a level doesn't spawn its worldly entities again, even though the other world
+    // has a worldly entity with the same iid.
+    let level_a = app.world().get::<Children>(world_a).unwrap()[0];
+    app.world_mut().entity_mut(level_a).insert(Respawn);
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should respawn");
+
+    assert_eq!(worldly_of(&mut app), expected);
+}
+
+#[test]
+fn level_transitions_move_travelers_to_the_spawn_point() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID));
+
+    spawn_project(&mut app, "simple.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("first level should spawn");
+
+    let traveler = app
+        .world_mut()
+        .spawn((LevelTraveler, SpatialBundle::default()))
+        .id();
+    let bystander = app
+        .world_mut()
+        .spawn((Worldly::default(), SpatialBundle::default()))
+        .id();
+
+    let spawn_point_iid = EntityIid::new("6a4e3b1a-1f3c-11ef-9a51-5b1c0d7e2a01");
+    app.world_mut()
+        .commands()
+        .transition_to_level(&LevelExit::new(
+            LevelIid::new(LEVEL_1_IID),
+            Some(spawn_point_iid.clone()),
+        ));
+    app.world_mut().flush();
+
+    assert_eq!(
+        update_until_level_spawned(&mut app, TIMEOUT),
+        Some(LevelIid::new(LEVEL_1_IID))
+    );
+
+    let spawn_point_translation = app
+        .world_mut()
+        .query::<(&EntityIid, &GlobalTransform)>()
+        .iter(app.world())
+        .find(|(entity_iid, _)| **entity_iid == spawn_point_iid)
+        .map(|(_, transform)| transform.translation().truncate())
+        .expect("spawn point should spawn");
+    assert_ne!(spawn_point_translation, Vec2::ZERO);
+
+    // Travelers are moved before transforms are propagated in the same update
+    let global_translation = |entity: Entity| {
+        app.world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation()
+    };
+    assert_eq!(
+        global_translation(traveler).truncate(),
+        spawn_point_translation
+    );
+    assert_eq!(global_translation(bystander), Vec3::ZERO);
+    assert_eq!(
+        *app.world().resource::<LevelTransition>(),
+        LevelTransition::default()
+    );
+}
+
+#[test]
+fn standalone_level_spawns_without_a_world() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_1_IID));
+
+    let level = app
+        .world()
+        .resource::<AssetServer>()
+        .load("simple.ldtk#Level_0");
+    let level_entity = app
+        .world_mut()
+        .spawn(LdtkLevelBundle { level, ..default() })
+        .id();
+
+    assert_eq!(
+        update_until_level_spawned(&mut app, TIMEOUT),
+        Some(LevelIid::new(LEVEL_0_IID))
+    );
+
+    assert!(app.world().get::<LevelProject>(level_entity).is_some());
+    assert!(app
+        .world()
+        .get::<Handle<LdtkProject>>(level_entity)
+        .is_none());
+
+    let snapshot = snapshot_hierarchy(app.world(), level_entity);
+    assert!(snapshot.contains("Collision"), "snapshot:\n{snapshot}");
+    assert!(!snapshot.contains("Level_1"), "snapshot:\n{snapshot}");
+}
+
+#[test]
+fn spawn_mode_can_be_overridden_per_world_and_level() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID));
+
+    let full_world = spawn_project(&mut app, "simple.ldtk");
+    let data_only_world = spawn_project(&mut app, "simple.ldtk");
+    app.world_mut()
+        .entity_mut(data_only_world)
+        .insert(LevelSpawnMode::DataOnly);
+
+    update_until_levels_spawned(&mut app, 2, TIMEOUT).expect("both levels should spawn");
+
+    let full_snapshot = snapshot_hierarchy(app.world(), full_world);
+    assert!(
+        full_snapshot.contains("Collision"),
+        "snapshot:\n{full_snapshot}"
+    );
+
+    let data_only_snapshot = snapshot_hierarchy(app.world(), data_only_world);
+    assert!(
+        !data_only_snapshot.contains("Collision"),
+        "snapshot:\n{data_only_snapshot}"
+    );
+    assert!(
+        data_only_snapshot.contains("Player"),
+        "snapshot:\n{data_only_snapshot}"
+    );
+
+    // The level's own spawn mode takes precedence over its world's
+    let level = app.world().get::<Children>(data_only_world).unwrap()[0];
+    app.world_mut()
+        .entity_mut(level)
+        .insert((LevelSpawnMode::Full, Respawn));
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should respawn");
+
+    let respawned_snapshot = snapshot_hierarchy(app.world(), data_only_world);
+    assert!(
+        respawned_snapshot.contains("Collision"),
+        "snapshot:\n{respawned_snapshot}"
+    );
+}
+
+#[derive(Component, Clone, Default)]
+struct PooledCoin;
+
+static POOLED_COIN_CONSTRUCTIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Bundle, Clone, Default)]
+struct PooledCoinBundle {
+    coin: PooledCoin,
+}
+
+impl LdtkEntity for PooledCoinBundle {
+    const POOLABLE: bool = true;
+
+    fn bundle_entity(
+        _: &EntityInstance,
+        _: &EntityDefinition,
+        _: &LayerInstance,
+        _: Option<&TilesetInfo>,
+        _: Option<&TilesetDefinition>,
+        _: &AssetServer,
+        _: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        POOLED_COIN_CONSTRUCTIONS.fetch_add(1, Ordering::Relaxed);
+        PooledCoinBundle::default()
+    }
+}
+
+#[test]
+fn pooled_entities_clone_one_bundle_onto_every_instance() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID))
+        .register_pooled_ldtk_entity::<PooledCoinBundle>("Coin");
+
+    spawn_project(&mut app, "simple.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should spawn");
+
+    let mut coins: Vec<(EntityIid, Vec3)> = app
+        .world_mut()
+        .query_filtered::<(&EntityIid, &Transform), With<PooledCoin>>()
+        .iter(app.world())
+        .map(|(iid, transform)| (iid.clone(), transform.translation))
+        .collect();
+    coins.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+    assert_eq!(POOLED_COIN_CONSTRUCTIONS.load(Ordering::Relaxed), 1);
+    assert_eq!(
+        coins
+            .iter()
+            .map(|(iid, _)| iid.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "6a4e3b15-1f3c-11ef-9a51-5b1c0d7e2a01",
+            "6a4e3b16-1f3c-11ef-9a51-5b1c0d7e2a01"
+        ]
+    );
+    assert_ne!(coins[0].1, coins[1].1);
+}
+
+#[derive(Component, Clone, Default)]
+struct Decoration;
+
+#[derive(Bundle, LdtkEntity, Clone, Default)]
+struct DecorationBundle {
+    decoration: Decoration,
+}
+
+#[test]
+fn entity_instance_refs_are_used_on_every_spawn_path_and_follow_patches() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID))
+        .insert_resource(LdtkSettings {
+            entity_instance_storage: EntityInstanceStorage::Reference,
+            ..default()
+        })
+        .register_pooled_ldtk_entity::<DecorationBundle>("Coin");
+
+    let world_entity = spawn_project(&mut app, "simple.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should spawn");
+
+    let prefab =
+        app.world_mut()
+            .commands()
+            .spawn_ldtk_entity(world_entity, "Coin", Transform::default());
+    app.world_mut().flush();
+
+    assert!(app.world().get::<EntityInstanceRef>(prefab).is_some());
+    assert!(app.world().get::<EntityInstance>(prefab).is_none());
+
+    let resolve = |app: &App, entity_instance_ref: &EntityInstanceRef| {
+        let project = app
+            .world()
+            .get::<Handle<LdtkProject>>(world_entity)
+            .and_then(|handle| app.world().resource::<Assets<LdtkProject>>().get(handle))
+            .expect("project should be loaded");
+
+        entity_instance_ref
+            .resolve(project, app.world().resource::<LevelPatches>())
+            .map(|entity_instance| entity_instance.iid.clone())
+    };
+
+    let mut coins: Vec<EntityInstanceRef> = app
+        .world_mut()
+        .query_filtered::<&EntityInstanceRef, With<Decoration>>()
+        .iter(app.world())
+        .cloned()
+        .collect();
+    coins.sort_by(|a, b| a.entity_iid.cmp(&b.entity_iid));
+
+    // Pooled entities get their refs too, while the prefab's ref doesn't resolve
+    assert_eq!(
+        coins
+            .iter()
+            .map(|coin| resolve(&app, coin))
+            .collect::<Vec<_>>(),
+        [
+            None,
+            Some("6a4e3b15-1f3c-11ef-9a51-5b1c0d7e2a01".to_string()),
+            Some("6a4e3b16-1f3c-11ef-9a51-5b1c0d7e2a01".to_string())
+        ]
+    );
+
+    // Refs to despawned entities no longer resolve, since their removal is patched into the level
+    let coin = app
+        .world_mut()
+        .query::<(Entity, &EntityIid)>()
+        .iter(app.world())
+        .find(|(_, entity_iid)| entity_iid.as_str() == coins[1].entity_iid)
+        .map(|(entity, _)| entity)
+        .unwrap();
+    app.world_mut().commands().despawn_ldtk_entity(coin);
+    app.world_mut().flush();
+
+    assert_eq!(resolve(&app, &coins[1]), None);
+    assert!(resolve(&app, &coins[2]).is_some());
+}
+
+#[derive(Bundle, Clone, Default)]
+struct ScaledBundle {
+    transform: Transform,
+}
+
+impl LdtkEntity for ScaledBundle {
+    fn bundle_entity(
+        _: &EntityInstance,
+        _: &EntityDefinition,
+        _: &LayerInstance,
+        _: Option<&TilesetInfo>,
+        _: Option<&TilesetDefinition>,
+        _: &AssetServer,
+        _: &mut Assets<TextureAtlasLayout>,
+    ) -> Self {
+        ScaledBundle {
+            transform: Transform::from_scale(Vec3::splat(2.)),
+        }
+    }
+}
+
+#[test]
+fn spawned_ldtk_entities_use_the_requested_transform() {
+    let mut app = headless_app("tests/fixtures");
+    app.insert_resource(LevelSelection::iid(LEVEL_0_IID))
+        .register_ldtk_entity::<ScaledBundle>("Coin");
+
+    let world_entity = spawn_project(&mut app, "simple.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should spawn");
+
+    let transform = Transform::from_xyz(32., 64., 5.);
+    let prefab = app
+        .world_mut()
+        .commands()
+        .spawn_ldtk_entity(world_entity, "Coin", transform);
+    app.world_mut().flush();
+
+    assert_eq!(app.world().get::<Transform>(prefab), Some(&transform));
+}
+
+/// Lets png loads fail instead of waiting for a loader to be registered.
+#[cfg(feature = "render")]
+struct FailingImageLoader;
+
+#[cfg(feature = "render")]
+impl bevy::asset::AssetLoader for FailingImageLoader {
+    type Asset = Image;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load<'a>(
+        &'a self,
+        _reader: &'a mut bevy::asset::io::Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut bevy::asset::LoadContext<'_>,
+    ) -> Result<Image, Self::Error> {
+        Err(std::io::Error::other("images are not loaded in tests"))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["png"]
+    }
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn missing_composite_image_falls_back_to_tile_layers() {
+    let mut app = headless_app("tests/fixtures");
+    app.register_asset_loader(FailingImageLoader)
+        .insert_resource(LevelSelection::iid(LEVEL_0_IID))
+        .insert_resource(LdtkSettings {
+            simplified_level_rendering: SimplifiedLevelRendering::All,
+            strict: StrictMode::Events,
+            ..default()
+        });
+
+    let mut reader = app
+        .world()
+        .resource::<Events<LdtkSpawnError>>()
+        .get_reader_current();
+
+    let world_entity = spawn_project(&mut app, "simplified.ldtk");
+
+    assert_eq!(
+        update_until_level_spawned(&mut app, TIMEOUT),
+        Some(LevelIid::new(LEVEL_0_IID))
+    );
+
+    let events = app.world().resource::<Events<LdtkSpawnError>>();
+    assert!(reader
+        .read(events)
+        .any(|error| error.kind == LdtkSpawnErrorKind::MissingCompositeImage));
+
+    let snapshot = snapshot_hierarchy(app.world(), world_entity);
+    assert!(snapshot.contains("Collision"), "snapshot:\n{snapshot}");
+    assert!(!snapshot.contains("_composite"), "snapshot:\n{snapshot}");
+}
+
+/// Loads every png as a blank image, for fixtures whose tilesets have to be loaded.
+#[cfg(feature = "render")]
+struct BlankImageLoader;
+
+#[cfg(feature = "render")]
+impl bevy::asset::AssetLoader for BlankImageLoader {
+    type Asset = Image;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load<'a>(
+        &'a self,
+        _reader: &'a mut bevy::asset::io::Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut bevy::asset::LoadContext<'_>,
+    ) -> Result<Image, Self::Error> {
+        Ok(Image::default())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["png"]
+    }
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn editing_int_grid_cells_refreshes_neighbouring_auto_tiles() {
+    use bevy_ecs_tilemap::tiles::{TilePos, TileTextureIndex, TileVisible};
+
+    let mut app = headless_app("tests/fixtures");
+    app.register_asset_loader(BlankImageLoader)
+        .insert_resource(LevelSelection::iid(LEVEL_0_IID));
+
+    spawn_project(&mut app, "auto_tiles.ldtk");
+    update_until_level_spawned(&mut app, TIMEOUT).expect("level should spawn");
+
+    let level_entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<LevelIid>>()
+        .single(app.world());
+
+    // Walls use tile 1 if the cell to their right is empty, and tile 0 otherwise.
+    let wall_tiles = |app: &mut App| -> Vec<(TilePos, u32)> {
+        let mut wall_tiles: Vec<(TilePos, u32)> = app
+            .world_mut()
+            .query_filtered::<(&TilePos, &TileTextureIndex, &TileVisible), With<IntGridCell>>()
+            .iter(app.world())
+            .filter(|(_, _, visible)| visible.0)
+            .map(|(tile_pos, texture_index, _)| (*tile_pos, texture_index.0))
+            .collect();
+        wall_tiles.sort_by_key(|(tile_pos, _)| tile_pos.x);
+        wall_tiles
+    };
+
+    let tile = |x: u32, texture_index: u32| (TilePos { x, y: 0 }, texture_index);
+
+    assert_eq!(
+        wall_tiles(&mut app),
+        (0..8).map(|x| tile(x, 0)).collect::<Vec<_>>()
+    );
+
+    app.world_mut().commands().set_int_grid_cell(
+        level_entity,
+        "Collision",
+        GridCoords::new(3, 0),
+        0,
+    );
+    app.world_mut().flush();
+
+    assert_eq!(
+        wall_tiles(&mut app),
+        vec![
+            tile(0, 0),
+            tile(1, 0),
+            tile(2, 1),
+            tile(4, 0),
+            tile(5, 0),
+            tile(6, 0),
+            tile(7, 0)
+        ]
+    );
+
+    app.world_mut().commands().set_int_grid_cell(
+        level_entity,
+        "Collision",
+        GridCoords::new(3, 0),
+        1,
+    );
+    app.world_mut().flush();
+
+    assert_eq!(
+        wall_tiles(&mut app),
+        (0..8).map(|x| tile(x, 0)).collect::<Vec<_>>()
+    );
+}